@@ -0,0 +1,199 @@
+//! Fp arithmetic: u128 remainder against Montgomery form, and inversion one by one against `batch_inverse`,
+//! alone and inside Lagrange interpolation
+//! Run with `cargo bench --bench field_arithmetic`.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use algebra::{
+    field::{
+        batch_inverse,
+        finite_field::{montgomery::FpMont, Fp},
+        FieldBase,
+    },
+    polynomial::{
+        lagrange::{lagrange_interpolation, lagrange_interpolation_fast},
+        Polynomial,
+    },
+    secret_sharing::{reconstruct_secret, Share},
+};
+
+/// Mersenne prime 2^61 - 1
+const P: u64 = 2305843009213693951;
+
+/// Deterministic xorshift generator
+struct XorShift(u64);
+
+impl XorShift {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    /// Non-zero residues
+    fn residues(&mut self, len: usize) -> Vec<u64> {
+        (0..len).map(|_| self.next() % (P - 1) + 1).collect()
+    }
+}
+
+/// Mean time per call over enough repetitions to fill about half a second
+fn time(mut f: impl FnMut()) -> Duration {
+    let start = Instant::now();
+    f();
+    let once = start.elapsed();
+    let runs =
+        (Duration::from_millis(500).as_nanos() / once.as_nanos().max(1)).clamp(1, 1000) as u32;
+    let start = Instant::now();
+    for _ in 0..runs {
+        f();
+    }
+    start.elapsed() / runs
+}
+
+fn report(name: &str, n: usize, fp: Duration, other_name: &str, other: Duration) {
+    let speedup = fp.as_secs_f64() / other.as_secs_f64();
+    println!("{name:>20} n = {n:>6}: Fp {fp:>12?}, {other_name} {other:>12?} ({speedup:.1}x)");
+}
+
+fn bench_products(rng: &mut XorShift) {
+    let n = 1_000_000;
+    let values = rng.residues(n);
+    let fp = values.iter().map(|v| Fp::<P>::new(*v)).collect::<Vec<_>>();
+    let mont = values
+        .iter()
+        .map(|v| FpMont::<P>::new(*v))
+        .collect::<Vec<_>>();
+    let fp_time = time(|| {
+        black_box(fp.iter().fold(Fp::new(1), |acc, a| acc * *a));
+    });
+    let mont_time = time(|| {
+        black_box(mont.iter().fold(FpMont::new(1), |acc, a| acc * *a));
+    });
+    report("product", n, fp_time, "FpMont", mont_time);
+}
+
+fn bench_inverses(rng: &mut XorShift) {
+    for n in [100, 10_000] {
+        let fp = rng
+            .residues(n)
+            .into_iter()
+            .map(Fp::<P>::new)
+            .collect::<Vec<_>>();
+        let one_by_one = time(|| {
+            black_box(fp.iter().map(|a| a.inverse()).collect::<Vec<_>>());
+        });
+        let batch = time(|| {
+            black_box(batch_inverse(&fp));
+        });
+        report("inverse", n, one_by_one, "batch_inverse", batch);
+    }
+}
+
+fn bench_interpolation(rng: &mut XorShift) {
+    for n in [50, 200] {
+        let points = rng.residues(2 * n);
+        let fp = points
+            .chunks(2)
+            .map(|c| (Fp::<P>::new(c[0]), Fp::new(c[1])))
+            .collect::<Vec<_>>();
+        let mont = points
+            .chunks(2)
+            .map(|c| (FpMont::<P>::new(c[0]), FpMont::new(c[1])))
+            .collect::<Vec<_>>();
+        let fp_time = time(|| {
+            black_box(lagrange_interpolation(&fp));
+        });
+        let mont_time = time(|| {
+            black_box(lagrange_interpolation(&mont));
+        });
+        report("lagrange", n, fp_time, "FpMont", mont_time);
+    }
+}
+
+/// `lagrange_interpolation_fast` with one inversion per basis polynomial instead of `batch_inverse`
+fn interpolation_one_by_one(points: &[(Fp<P>, Fp<P>)]) -> Polynomial<Fp<P>> {
+    let n = points.len();
+    let mut master = vec![Fp::new(1)];
+    for (x_j, _) in points {
+        master.insert(0, Fp::new(0));
+        for k in 0..master.len() - 1 {
+            master[k] = master[k] - *x_j * master[k + 1];
+        }
+    }
+    let mut coeffs = vec![Fp::new(0); n];
+    for (i, (x_i, y_i)) in points.iter().enumerate() {
+        let denom = points
+            .iter()
+            .enumerate()
+            .filter(|(j, _)| *j != i)
+            .fold(Fp::new(1), |acc, (_, (x_j, _))| acc * (*x_i - *x_j));
+        let weight = *y_i * denom.inverse();
+        let mut q = Fp::new(0);
+        for k in (0..n).rev() {
+            q = master[k + 1] + *x_i * q;
+            coeffs[k] += weight * q;
+        }
+    }
+    Polynomial::from_coeffs(coeffs)
+}
+
+fn bench_interpolation_inverses(rng: &mut XorShift) {
+    for n in [10, 50, 200] {
+        let points = rng.residues(2 * n);
+        let fp = points
+            .chunks(2)
+            .map(|c| (Fp::<P>::new(c[0]), Fp::new(c[1])))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            interpolation_one_by_one(&fp),
+            lagrange_interpolation_fast(&fp)
+        );
+        let one_by_one = time(|| {
+            black_box(interpolation_one_by_one(&fp));
+        });
+        let batch = time(|| {
+            black_box(lagrange_interpolation_fast(&fp));
+        });
+        report("lagrange inverses", n, one_by_one, "batch_inverse", batch);
+    }
+}
+
+fn bench_reconstruction(rng: &mut XorShift) {
+    for n in [100, 1_000] {
+        let points = rng.residues(2 * n);
+        let fp = points
+            .chunks(2)
+            .map(|c| Share {
+                x: Fp::<P>::new(c[0]),
+                y: Fp::new(c[1]),
+                label: (),
+            })
+            .collect::<Vec<_>>();
+        let mont = points
+            .chunks(2)
+            .map(|c| Share {
+                x: FpMont::<P>::new(c[0]),
+                y: FpMont::new(c[1]),
+                label: (),
+            })
+            .collect::<Vec<_>>();
+        let fp_time = time(|| {
+            black_box(reconstruct_secret(&fp).unwrap());
+        });
+        let mont_time = time(|| {
+            black_box(reconstruct_secret(&mont).unwrap());
+        });
+        report("reconstruct_secret", n, fp_time, "FpMont", mont_time);
+    }
+}
+
+fn main() {
+    let mut rng = XorShift(0x9e3779b97f4a7c15);
+    bench_products(&mut rng);
+    bench_inverses(&mut rng);
+    bench_interpolation(&mut rng);
+    bench_interpolation_inverses(&mut rng);
+    bench_reconstruction(&mut rng);
+}
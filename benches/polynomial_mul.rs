@@ -0,0 +1,66 @@
+//! Polynomial multiplication over Fp: schoolbook against `mul_fast`
+//! Run with `cargo bench --bench polynomial_mul`.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use algebra::{field::finite_field::Fp, polynomial::Polynomial};
+
+/// Deterministic xorshift generator
+struct XorShift(u64);
+
+impl XorShift {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn poly<const P: u64>(&mut self, len: usize) -> Polynomial<Fp<P>> {
+        Polynomial::from_coeffs((0..len).map(|_| Fp::new(self.next())).collect())
+    }
+}
+
+/// Mean time per call over enough repetitions to fill about half a second
+fn time(mut f: impl FnMut()) -> Duration {
+    let start = Instant::now();
+    f();
+    let once = start.elapsed();
+    let runs =
+        (Duration::from_millis(500).as_nanos() / once.as_nanos().max(1)).clamp(1, 1000) as u32;
+    let start = Instant::now();
+    for _ in 0..runs {
+        f();
+    }
+    start.elapsed() / runs
+}
+
+fn bench<const P: u64>(name: &str, rng: &mut XorShift, lengths: &[usize]) {
+    for &n in lengths {
+        let (f, g) = (rng.poly::<P>(n), rng.poly::<P>(n));
+        let fast = time(|| {
+            black_box(f.mul_fast(&g));
+        });
+        let schoolbook = if n <= 10_000 {
+            format!(
+                "{:?}",
+                time(|| {
+                    black_box(&f * &g);
+                })
+            )
+        } else {
+            "-".to_string()
+        };
+        println!("{name:>12} n = {n:>7}: mul_fast {fast:>12?}, schoolbook {schoolbook:>12}");
+    }
+}
+
+fn main() {
+    let mut rng = XorShift(0x9e3779b97f4a7c15);
+    let lengths = [100, 200, 400, 1_000, 10_000, 100_000];
+    // NTT friendly, 2^23 divides P - 1
+    bench::<998244353>("998244353", &mut rng, &lengths);
+    // P - 1 = 2 500000003, three-prime CRT
+    bench::<1_000_000_007>("1000000007", &mut rng, &lengths);
+}
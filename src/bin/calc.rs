@@ -0,0 +1,41 @@
+//! Calculator
+//! Evaluates expressions in x over a ring chosen per line, e.g.
+//! `(3x^2 + 1) * (x - 2) mod 17` or `inverse(5) in F101`.
+//! With arguments, evaluates them as a single expression, otherwise reads lines from stdin.
+
+use std::io::{BufRead, Write};
+
+use algebra::expression::parse;
+
+fn run(line: &str) {
+    match parse(line).and_then(|statement| {
+        let value = statement.evaluate()?;
+        Ok((value, statement.ring))
+    }) {
+        Ok((value, ring)) => println!("{value} in {ring}"),
+        Err(e) => println!("error: {e}"),
+    }
+}
+
+fn main() {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    if !args.is_empty() {
+        run(&args.join(" "));
+        return;
+    }
+
+    let stdin = std::io::stdin();
+    let mut lines = stdin.lock().lines();
+    loop {
+        print!("> ");
+        std::io::stdout().flush().unwrap();
+        let Some(Ok(line)) = lines.next() else {
+            break;
+        };
+        match line.trim() {
+            "" => continue,
+            "quit" | "exit" => break,
+            line => run(line),
+        }
+    }
+}
@@ -1,83 +1,13 @@
 //! Shamir Secret Sharing
 //! Shamir's Secret Sharing is a method for secret sharing based on polynomial interpolation.
 
-use std::fmt::Display;
-
 use algebra::{
     field::finite_field::Fp,
-    polynomial::{lagrange::lagrange_interpolation, Polynomial},
+    polynomial::lagrange::lagrange_interpolation,
+    ring::RingBase,
+    secret_sharing::{reconstruct_secret, split_secret, split_secret_at, Feldman, ShareError},
 };
-use rand::{seq::IteratorRandom, Rng, SeedableRng};
-
-/// Share
-#[derive(Debug, Clone, Copy)]
-pub struct Share<T> {
-    pub x: T,
-    pub y: T,
-}
-
-impl<const P: u64> Display for Share<Fp<P>> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "({x}, {y})", x = self.x, y = self.y)
-    }
-}
-
-impl<T> Share<T> {
-    pub fn into_pair(self) -> (T, T) {
-        (self.x, self.y)
-    }
-}
-
-/// Split a secret into shares
-pub fn split_secret<const P: u64, R: Rng + ?Sized>(
-    secret: Fp<P>,
-    share_threshold: usize,
-    number_of_shares: usize,
-    rng: &mut R,
-) -> Vec<Share<Fp<P>>> {
-    if share_threshold > number_of_shares {
-        panic!("Share threshold must be less than the number of shares");
-    }
-
-    // Construct a random polynomial
-    let poly = loop {
-        let poly = Polynomial::from_coeffs(
-            [secret]
-                .into_iter()
-                .chain((0..share_threshold - 1).map(|_| Fp::<P>::new(rng.random::<u64>())))
-                .collect(),
-        );
-        if poly
-            .degree()
-            .expect("Since the secret is non-zero, the degree should be defined")
-            == share_threshold - 1
-        {
-            break poly;
-        }
-    };
-
-    // Generate shares
-    let shares_abscissa = (1..=number_of_shares as u64).map(Fp::<P>::new);
-    shares_abscissa
-        .map(|x| Share {
-            x,
-            y: poly.evaluate(&x),
-        })
-        .collect()
-}
-
-/// Reconstruct a secret from shares
-pub fn reconstruct_secret<const P: u64>(shares: &[Share<Fp<P>>]) -> Fp<P> {
-    let poly = lagrange_interpolation(
-        &shares
-            .into_iter()
-            .map(|share| share.into_pair())
-            .collect::<Vec<_>>(),
-    );
-    *poly
-        .constant_term()
-        .expect("Since the degree is defined, the constant term should be defined")
-}
+use rand::{seq::IteratorRandom, SeedableRng};
 
 fn main() {
     // Field order
@@ -94,7 +24,8 @@ fn main() {
     // Split secret
     let share_threshold = 3;
     let number_of_shares = 5;
-    let shares = split_secret(secret, share_threshold, number_of_shares, &mut rng);
+    let shares = split_secret(secret, share_threshold, number_of_shares, &mut rng)
+        .expect("Threshold is at most the number of shares");
     println!("Share threshold: {share_threshold}");
     println!("Number of shares: {number_of_shares}");
     println!("Shares:");
@@ -115,7 +46,7 @@ fn main() {
     for share in shares_too_few.iter() {
         println!("{share}");
     }
-    let secret_reconstructed = reconstruct_secret(&shares_too_few);
+    let secret_reconstructed = reconstruct_secret(&shares_too_few).expect("Shares are distinct");
     println!("Reconstructed secret: {secret_reconstructed}");
     assert_ne!(secret, secret_reconstructed);
 
@@ -142,4 +73,65 @@ fn main() {
     let secret_reconstructed = poly.evaluate(&Field::new(0));
     println!("Reconstructed secret: {secret_reconstructed}");
     assert_eq!(secret, secret_reconstructed);
+
+    // Parties with their own fixed abscissas
+    println!("\nSplitting the secret among labelled parties.");
+    let parties = [
+        ("alice", 1009),
+        ("bob", 40213),
+        ("carol", 7),
+        ("dave", 999983),
+    ];
+    let shares = split_secret_at(
+        secret,
+        share_threshold,
+        parties.map(|(label, x)| (Field::new(x), label)),
+        &mut rng,
+    )
+    .expect("Abscissas are distinct and non-zero");
+    for share in shares.iter() {
+        println!("{}: {share}", share.label);
+    }
+    let secret_reconstructed = reconstruct_secret(&shares[1..]).expect("Shares are distinct");
+    println!("Reconstructed secret: {secret_reconstructed}");
+    assert_eq!(secret, secret_reconstructed);
+
+    let repeated = split_secret_at(
+        secret,
+        2,
+        [(Field::new(3), "eve"), (Field::new(3), "frank")],
+        &mut rng,
+    );
+    assert_eq!(repeated.unwrap_err(), ShareError::RepeatedAbscissa);
+
+    let no_threshold = split_secret_at(secret, 0, [(Field::new(5), "grace")], &mut rng);
+    assert_eq!(no_threshold.unwrap_err(), ShareError::ZeroThreshold);
+
+    // Verifiable sharing, committing in the subgroup of order PRIME of F_P^* for P = 46 PRIME + 1
+    println!("\nSplitting the secret with Feldman commitments.");
+    const GROUP_PRIME: u64 = 46 * PRIME + 1;
+    let generator = Fp::<GROUP_PRIME>::new(2).pow(46);
+    let feldman = Feldman::<GROUP_PRIME, PRIME>::new(generator).expect("2^46 has order PRIME");
+    let (mut shares, commitments) = feldman
+        .split_secret_at(
+            secret,
+            share_threshold,
+            parties.map(|(label, x)| (Field::new(x), label)),
+            &mut rng,
+        )
+        .expect("Abscissas are distinct and non-zero");
+    println!("Commitments:");
+    for commitment in commitments.iter() {
+        println!("{commitment}");
+    }
+    for share in shares.iter() {
+        let valid = feldman.verify(share, &commitments);
+        println!("{}: {share} valid: {valid}", share.label);
+        assert!(valid);
+    }
+
+    // A dealer handing out an inconsistent share is caught
+    shares[2].y += Field::one();
+    println!("Tampered share of {}: {}", shares[2].label, shares[2]);
+    assert!(!feldman.verify(&shares[2], &commitments));
 }
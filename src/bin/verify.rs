@@ -0,0 +1,69 @@
+//! Axiom Verifier
+//! Exhaustively checks the field axioms, inverses and powers of the small prime fields
+//! and binary extension fields, then shows the counterexample found when Z/12Z is checked as a field.
+
+use std::fmt::Debug;
+
+use algebra::{
+    field::finite_field::{binary::Gf2e, Fp},
+    verify::{verify_commutative_ring, verify_finite_field, Counterexample},
+};
+
+fn report<T: Debug>(name: &str, size: usize, result: Result<(), Counterexample<T>>) {
+    match result {
+        Ok(()) => println!("{name}: axioms hold for all {size} elements"),
+        Err(counterexample) => println!("{name}: {counterexample}"),
+    }
+}
+
+fn prime_field<const P: u64>() {
+    let elements = (0..P).map(Fp::<P>::new).collect::<Vec<_>>();
+    report(
+        &format!("F{P}"),
+        elements.len(),
+        verify_finite_field(&elements, Fp::pow),
+    );
+}
+
+fn binary_field<const N: u32>() {
+    let elements = Gf2e::<N>::elements().collect::<Vec<_>>();
+    report(
+        &format!("GF(2^{N})"),
+        elements.len(),
+        verify_finite_field(&elements, Gf2e::pow),
+    );
+}
+
+/// Fp<N> for composite N is still the ring Z/NZ, but Fermat inverses fail for the zero divisors
+fn composite_modulus<const N: u64>() {
+    let elements = (0..N).map(Fp::<N>::new).collect::<Vec<_>>();
+    report(
+        &format!("Z/{N} as a ring"),
+        elements.len(),
+        verify_commutative_ring(&elements),
+    );
+    report(
+        &format!("Z/{N} as a field"),
+        elements.len(),
+        verify_finite_field(&elements, Fp::pow),
+    );
+}
+
+fn main() {
+    prime_field::<2>();
+    prime_field::<3>();
+    prime_field::<5>();
+    prime_field::<7>();
+    prime_field::<13>();
+    prime_field::<31>();
+    prime_field::<101>();
+
+    binary_field::<1>();
+    binary_field::<2>();
+    binary_field::<3>();
+    binary_field::<4>();
+    binary_field::<5>();
+    binary_field::<6>();
+
+    composite_modulus::<12>();
+}
@@ -0,0 +1,283 @@
+//! Binary Goppa Codes
+//! https://en.wikipedia.org/wiki/Binary_Goppa_code
+//! Given an irreducible Goppa polynomial g(x) of degree t over GF(2^M) and a support
+//! L = (a_1, ..., a_n) of distinct field elements, the code consists of the words c in GF(2)^n with
+//! $$ \sum_{i=1}^{n} \frac{c_i}{x - a_i} = 0 \pmod{g(x)} $$
+//! It has length n, dimension k >= n - M t, and corrects up to t errors with Patterson's algorithm.
+
+use std::fmt::Display;
+
+use crate::{
+    field::{finite_field::binary::Gf2e, FieldBase},
+    matrix::gf2::BitMatrix,
+    polynomial::Polynomial,
+    ring::RingBase,
+};
+
+type Poly<const M: u32> = Polynomial<Gf2e<M>>;
+
+/// Invalid Goppa code parameters
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GoppaError {
+    /// The Goppa polynomial is constant or factors over GF(2^M)
+    ReduciblePolynomial,
+    /// A support element appears more than once
+    RepeatedSupport,
+    /// A support element is a root of the Goppa polynomial
+    SupportRoot,
+}
+
+impl Display for GoppaError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GoppaError::ReduciblePolynomial => write!(f, "Goppa polynomial is not irreducible"),
+            GoppaError::RepeatedSupport => write!(f, "Support elements must be distinct"),
+            GoppaError::SupportRoot => write!(f, "Support contains a root of the Goppa polynomial"),
+        }
+    }
+}
+
+impl std::error::Error for GoppaError {}
+
+/// Product a b modulo g
+fn mul_mod<const M: u32>(a: &Poly<M>, b: &Poly<M>, g: &Poly<M>) -> Poly<M> {
+    &a.mul_clmul(b) % g
+}
+
+/// Extended Euclidean algorithm on (g, a), stopped once the remainder has degree <= bound
+/// Returns (r, v) with r = v a (mod g).
+fn partial_euclid<const M: u32>(
+    g: &Poly<M>,
+    a: &Poly<M>,
+    bound: Option<usize>,
+) -> (Poly<M>, Poly<M>) {
+    let (mut r0, mut r1) = (g.clone(), a % g);
+    let (mut v0, mut v1) = (Poly::<M>::zero(), Poly::from_coeffs(vec![Gf2e::one()]));
+    while r1.degree() > bound {
+        let (q, r) = r0.div_rem(&r1);
+        let v = &v0 - &q.mul_clmul(&v1);
+        (r0, r1) = (r1, r);
+        (v0, v1) = (v1, v);
+    }
+    (r1, v1)
+}
+
+/// Inverse of a modulo an irreducible g, None if a = 0 (mod g)
+fn inverse_mod<const M: u32>(a: &Poly<M>, g: &Poly<M>) -> Option<Poly<M>> {
+    // Run to the end: the last non-zero remainder is a unit
+    let (r, v) = partial_euclid(g, a, Some(0));
+    let c = *r.coeffs().first()?;
+    Some(v.map(|x| *x * c.inverse()))
+}
+
+/// Square modulo g
+fn square_mod<const M: u32>(a: &Poly<M>, g: &Poly<M>) -> Poly<M> {
+    mul_mod(a, a, g)
+}
+
+/// Rabin's irreducibility test over GF(q), q = 2^M
+/// g of degree t is irreducible iff x^{q^t} = x (mod g) and gcd(x^{q^{t/r}} - x, g) = 1 for every prime r | t.
+fn is_irreducible<const M: u32>(g: &Poly<M>) -> bool {
+    let t = match g.degree() {
+        None | Some(0) => return false,
+        Some(t) => t,
+    };
+    let x = Poly::<M>::from_coeffs(vec![Gf2e::zero(), Gf2e::one()]);
+    // x^{q^k} mod g for k = 0, ..., t, raising to the q-th power is M squarings
+    let frobenius_powers = (0..t).fold(vec![&x % g], |mut powers, _| {
+        let next = (0..M).fold(powers.last().unwrap().clone(), |p, _| square_mod(&p, g));
+        powers.push(next);
+        powers
+    });
+    if frobenius_powers[t] != &x % g {
+        return false;
+    }
+    (2..=t)
+        .filter(|r| t.is_multiple_of(*r) && (2..*r).all(|d| !r.is_multiple_of(d)))
+        .all(|r| (&frobenius_powers[t / r] - &x).gcd(g).degree() == Some(0))
+}
+
+/// Binary Goppa code over GF(2^M)
+#[derive(Debug, Clone)]
+pub struct GoppaCode<const M: u32> {
+    goppa: Poly<M>,
+    support: Vec<Gf2e<M>>,
+    /// 1 / (x - a_i) mod g, the syndrome contribution of each position
+    inverses: Vec<Poly<M>>,
+    parity_check: BitMatrix,
+    /// Generator matrix in reduced row echelon form
+    generator: BitMatrix,
+    /// Pivot columns of the generator, where codewords carry the message bits
+    information_set: Vec<usize>,
+}
+
+impl<const M: u32> GoppaCode<M> {
+    /// Goppa code from a Goppa polynomial and a support
+    /// The polynomial is made monic. Fails if it is not irreducible or the support is invalid.
+    pub fn new(goppa: Polynomial<Gf2e<M>>, support: Vec<Gf2e<M>>) -> Result<Self, GoppaError> {
+        let goppa = goppa.monic();
+        if !is_irreducible(&goppa) {
+            return Err(GoppaError::ReduciblePolynomial);
+        }
+        let mut sorted = support.iter().map(|a| a.value()).collect::<Vec<_>>();
+        sorted.sort_unstable();
+        if sorted.windows(2).any(|w| w[0] == w[1]) {
+            return Err(GoppaError::RepeatedSupport);
+        }
+
+        // 1 / (x - a) = -(g(x) - g(a)) / ((x - a) g(a)) (mod g)
+        let mut inverses = Vec::with_capacity(support.len());
+        for &a in &support {
+            let g_a = goppa.evaluate(&a);
+            if g_a == Gf2e::zero() {
+                return Err(GoppaError::SupportRoot);
+            }
+            let shifted = &goppa - &Poly::from_coeffs(vec![g_a]);
+            let (quotient, _) = shifted.div_rem(&Poly::from_coeffs(vec![-a, Gf2e::one()]));
+            let scale = -g_a.inverse();
+            inverses.push(quotient.map(|c| *c * scale));
+        }
+
+        // Expand each coefficient of 1 / (x - a_i) into M bits to get a binary parity-check matrix
+        let t = goppa.degree().unwrap();
+        let parity_check = BitMatrix::from_fn(M as usize * t, support.len(), |row, i| {
+            let (j, bit) = (row / M as usize, row % M as usize);
+            let coeff = inverses[i].coeffs().get(j).map_or(0, |c| c.value());
+            (coeff >> bit) & 1 == 1
+        });
+        let mut generator = parity_check.null_space();
+        let information_set = generator.row_reduce();
+
+        Ok(Self {
+            goppa,
+            support,
+            inverses,
+            parity_check,
+            generator,
+            information_set,
+        })
+    }
+
+    /// Goppa code supported on every element of GF(2^M)
+    /// An irreducible polynomial of degree > 1 has no roots in GF(2^M), so the code has length 2^M.
+    pub fn full_support(goppa: Polynomial<Gf2e<M>>) -> Result<Self, GoppaError> {
+        Self::new(goppa, Gf2e::elements().collect())
+    }
+
+    /// Monic Goppa polynomial g
+    pub fn goppa_polynomial(&self) -> &Polynomial<Gf2e<M>> {
+        &self.goppa
+    }
+
+    /// Support (a_1, ..., a_n)
+    pub fn support(&self) -> &[Gf2e<M>] {
+        &self.support
+    }
+
+    /// Code length n
+    pub fn length(&self) -> usize {
+        self.support.len()
+    }
+
+    /// Code dimension k
+    pub fn dimension(&self) -> usize {
+        self.generator.rows()
+    }
+
+    /// Number of correctable errors t = deg g
+    pub fn correctable_errors(&self) -> usize {
+        self.goppa.degree().unwrap()
+    }
+
+    /// Binary parity-check matrix H, with c H^T = 0 for codewords c
+    pub fn parity_check_matrix(&self) -> &BitMatrix {
+        &self.parity_check
+    }
+
+    /// Generator matrix in reduced row echelon form, its rows span the code
+    pub fn generator_matrix(&self) -> &BitMatrix {
+        &self.generator
+    }
+
+    /// Encode k message bits as the codeword m G
+    /// The message reappears unchanged at the pivot positions of G.
+    pub fn encode(&self, message: &[bool]) -> Vec<bool> {
+        assert_eq!(
+            message.len(),
+            self.dimension(),
+            "Message length must equal the code dimension"
+        );
+        let mut codeword = vec![false; self.length()];
+        for (k, _) in message.iter().enumerate().filter(|(_, &bit)| bit) {
+            for j in self.generator.row_ones(k) {
+                codeword[j] ^= true;
+            }
+        }
+        codeword
+    }
+
+    /// Syndrome polynomial $$ S(x) = \sum_i \frac{c_i}{x - a_i} \bmod g(x) $$
+    pub fn syndrome(&self, word: &[bool]) -> Polynomial<Gf2e<M>> {
+        assert_eq!(
+            word.len(),
+            self.length(),
+            "Word length must equal the code length"
+        );
+        word.iter()
+            .zip(&self.inverses)
+            .filter(|(&bit, _)| bit)
+            .fold(Poly::zero(), |acc, (_, inv)| &acc + inv)
+    }
+
+    /// Positions of the errors in a received word, by Patterson's algorithm
+    /// https://en.wikipedia.org/wiki/Binary_Goppa_code#Decoding
+    /// Returns `None` when more than t errors are detected.
+    pub fn error_positions(&self, received: &[bool]) -> Option<Vec<usize>> {
+        let g = &self.goppa;
+        let t = self.correctable_errors();
+        let s = self.syndrome(received);
+        if s.degree().is_none() {
+            return Some(vec![]);
+        }
+
+        // Error locator sigma(x) = a(x)^2 + x b(x)^2 with a = b tau (mod g), tau = sqrt(1/S + x)
+        let x = Poly::<M>::from_coeffs(vec![Gf2e::zero(), Gf2e::one()]);
+        let t_inv = inverse_mod(&s, g)?;
+        let sigma = if &t_inv % g == &x % g {
+            x.clone()
+        } else {
+            // Square root in GF(2^{Mt}) = GF(2^M)[x] / (g) is the (2^{Mt - 1})-th power
+            let target = &(&t_inv + &x) % g;
+            let tau = (1..M as usize * t).fold(target, |p, _| square_mod(&p, g));
+            let (a, b) = partial_euclid(g, &tau, Some(t / 2));
+            &(&a * &a) + &(&x * &(&b * &b))
+        };
+
+        let positions = self
+            .support
+            .iter()
+            .enumerate()
+            .filter(|(_, a)| sigma.evaluate(a) == Gf2e::zero())
+            .map(|(i, _)| i)
+            .collect::<Vec<_>>();
+        (Some(positions.len()) == sigma.degree()).then_some(positions)
+    }
+
+    /// Corrected codeword, or `None` if the received word is not within distance t of a codeword
+    pub fn decode(&self, received: &[bool]) -> Option<Vec<bool>> {
+        let mut corrected = received.to_vec();
+        for i in self.error_positions(received)? {
+            corrected[i] ^= true;
+        }
+        self.syndrome(&corrected)
+            .degree()
+            .is_none()
+            .then_some(corrected)
+    }
+
+    /// Message bits of the corrected codeword
+    pub fn decode_message(&self, received: &[bool]) -> Option<Vec<bool>> {
+        let codeword = self.decode(received)?;
+        Some(self.information_set.iter().map(|&j| codeword[j]).collect())
+    }
+}
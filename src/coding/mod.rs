@@ -0,0 +1,7 @@
+//! Coding Theory
+//! https://en.wikipedia.org/wiki/Coding_theory
+//! Binary error-correcting codes. Words are slices of bits, `true` standing for 1.
+
+pub mod goppa;
+
+pub mod reed_muller;
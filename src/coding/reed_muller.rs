@@ -0,0 +1,287 @@
+//! Reed-Muller Codes
+//! https://en.wikipedia.org/wiki/Reed%E2%80%93Muller_code
+//! RM(r, m) is the code of truth tables of boolean polynomials in m variables of degree at most r:
+//! a polynomial f is encoded as (f(x))_x for x running over GF(2)^m, where point j has x_i = bit i of j.
+//! It has length 2^m, dimension sum_{i <= r} C(m, i), and minimum distance 2^{m - r}.
+
+use crate::{
+    field::finite_field::Fp,
+    polynomial::multivariate::{monomial::Monomial, ordering::MonomialOrder, MultiPoly},
+    ring::RingBase,
+};
+
+/// Largest supported number of variables
+const MAX_VARIABLES: usize = 24;
+
+/// Reed-Muller code RM(r, m) over GF(2)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReedMullerCode {
+    order: usize,
+    variables: usize,
+    /// Message basis: the squarefree monomials of degree <= r as variable subsets,
+    /// by increasing degree then lexicographically
+    basis: Vec<u32>,
+}
+
+/// Subsets of {0, ..., m - 1} of size d, as bit masks in lexicographic order of their elements
+fn subsets(m: usize, d: usize) -> Vec<u32> {
+    fn extend(start: usize, m: usize, d: usize, mask: u32, out: &mut Vec<u32>) {
+        if d == 0 {
+            out.push(mask);
+            return;
+        }
+        for i in start..=m - d {
+            extend(i + 1, m, d - 1, mask | 1 << i, out);
+        }
+    }
+    let mut out = vec![];
+    extend(0, m, d, 0, &mut out);
+    out
+}
+
+/// Truth table from the coefficients of a boolean polynomial indexed by variable subsets
+/// Zeta transform over the subset lattice: f(x) = sum_{S subset of x} a_S
+fn truth_table(coeffs: &mut [bool]) {
+    let n = coeffs.len();
+    let mut half = 1;
+    while half < n {
+        for j in 0..n {
+            if j & half != 0 {
+                coeffs[j] ^= coeffs[j ^ half];
+            }
+        }
+        half <<= 1;
+    }
+}
+
+impl ReedMullerCode {
+    /// Code RM(r, m)
+    /// Panics if r > m or m > 24.
+    pub fn new(r: usize, m: usize) -> Self {
+        assert!(
+            r <= m,
+            "Reed-Muller order must not exceed the number of variables"
+        );
+        assert!(
+            m <= MAX_VARIABLES,
+            "Too many variables for a Reed-Muller code"
+        );
+        let basis = (0..=r).flat_map(|d| subsets(m, d)).collect();
+        Self {
+            order: r,
+            variables: m,
+            basis,
+        }
+    }
+
+    /// Order r, the largest degree of the message polynomials
+    pub fn order(&self) -> usize {
+        self.order
+    }
+
+    /// Number of variables m
+    pub fn variables(&self) -> usize {
+        self.variables
+    }
+
+    /// Code length 2^m
+    pub fn length(&self) -> usize {
+        1 << self.variables
+    }
+
+    /// Code dimension sum_{i <= r} C(m, i)
+    pub fn dimension(&self) -> usize {
+        self.basis.len()
+    }
+
+    /// Minimum distance 2^{m - r}
+    pub fn minimum_distance(&self) -> usize {
+        1 << (self.variables - self.order)
+    }
+
+    /// Number of errors corrected by the decoders, 2^{m - r - 1} - 1
+    pub fn correctable_errors(&self) -> usize {
+        (self.minimum_distance() / 2).saturating_sub(1)
+    }
+
+    /// Message basis monomials, by increasing degree then lexicographically in the variables
+    pub fn basis(&self) -> Vec<Monomial> {
+        self.basis
+            .iter()
+            .map(|&mask| Monomial::new((0..self.variables).map(|i| (mask >> i) & 1).collect()))
+            .collect()
+    }
+
+    /// Encode message bits, the coefficients of the basis monomials
+    pub fn encode(&self, message: &[bool]) -> Vec<bool> {
+        assert_eq!(
+            message.len(),
+            self.dimension(),
+            "Message length must equal the code dimension"
+        );
+        let mut coeffs = vec![false; self.length()];
+        for (&mask, &bit) in self.basis.iter().zip(message) {
+            coeffs[mask as usize] = bit;
+        }
+        truth_table(&mut coeffs);
+        coeffs
+    }
+
+    /// Message polynomial $$ \sum_k m_k x^{S_k} $$ over GF(2)
+    pub fn message_polynomial<O: MonomialOrder>(
+        &self,
+        message: &[bool],
+        order: O,
+    ) -> MultiPoly<Fp<2>, O> {
+        assert_eq!(
+            message.len(),
+            self.dimension(),
+            "Message length must equal the code dimension"
+        );
+        let terms = self
+            .basis()
+            .into_iter()
+            .zip(message)
+            .filter(|(_, &bit)| bit)
+            .map(|(monomial, _)| (monomial, Fp::one()))
+            .collect();
+        MultiPoly::from_terms(self.variables, terms, order)
+    }
+
+    /// Message bits of a boolean polynomial
+    /// Exponents are reduced with x^2 = x, as the polynomial is only evaluated on GF(2)^m.
+    /// Panics if the reduced polynomial has degree above r.
+    pub fn polynomial_message<O: MonomialOrder>(&self, poly: &MultiPoly<Fp<2>, O>) -> Vec<bool> {
+        assert_eq!(
+            poly.nvars(),
+            self.variables,
+            "Polynomial must have m variables"
+        );
+        let mut coeffs = vec![false; self.length()];
+        for (monomial, c) in poly.terms() {
+            let mask = monomial
+                .exponents()
+                .iter()
+                .enumerate()
+                .filter(|(_, &e)| e > 0)
+                .fold(0_usize, |mask, (i, _)| mask | 1 << i);
+            coeffs[mask] ^= c.value() == 1;
+        }
+        let message = self
+            .basis
+            .iter()
+            .map(|&mask| coeffs[mask as usize])
+            .collect();
+        assert!(
+            coeffs
+                .iter()
+                .enumerate()
+                .all(|(mask, &c)| !c || mask.count_ones() as usize <= self.order),
+            "Polynomial degree exceeds the code order"
+        );
+        message
+    }
+
+    /// Encode a boolean polynomial of degree <= r as its truth table
+    pub fn encode_polynomial<O: MonomialOrder>(&self, poly: &MultiPoly<Fp<2>, O>) -> Vec<bool> {
+        self.encode(&self.polynomial_message(poly))
+    }
+
+    /// Majority-logic decoding (Reed's algorithm)
+    /// Recovers the coefficients from the highest degree down: the coefficient of x^S is the sum of the
+    /// received bits over each coset of the subcube spanned by S, and the majority of these 2^{m - |S|}
+    /// votes wins. Corrects up to 2^{m - r - 1} - 1 errors.
+    pub fn decode_majority(&self, received: &[bool]) -> Vec<bool> {
+        assert_eq!(
+            received.len(),
+            self.length(),
+            "Word length must equal the code length"
+        );
+        let m = self.variables;
+        let full = (1_u32 << m) - 1;
+        let mut word = received.to_vec();
+        let mut message = vec![false; self.dimension()];
+
+        for d in (0..=self.order).rev() {
+            let mut layer = vec![false; self.length()];
+            for (k, &mask) in self.basis.iter().enumerate() {
+                if mask.count_ones() as usize != d {
+                    continue;
+                }
+                // Cosets are indexed by the values of the variables outside S
+                let complement = full & !mask;
+                let votes = submasks(complement)
+                    .filter(|&t| submasks(mask).fold(false, |acc, s| acc ^ word[(s | t) as usize]))
+                    .count();
+                let bit = 2 * votes > 1 << (m - d);
+                message[k] = bit;
+                layer[mask as usize] = bit;
+            }
+            // Remove the degree d part before looking at lower degrees
+            truth_table(&mut layer);
+            for (w, l) in word.iter_mut().zip(layer) {
+                *w ^= l;
+            }
+        }
+        message
+    }
+
+    /// Maximum-likelihood decoding of first-order codes with the fast Hadamard transform
+    /// https://en.wikipedia.org/wiki/Hadamard_transform
+    /// The transform of (-1)^y correlates the received word with every affine function at once;
+    /// the largest absolute correlation picks the linear part and its sign the constant term.
+    /// Panics unless r = 1.
+    pub fn decode_hadamard(&self, received: &[bool]) -> Vec<bool> {
+        assert_eq!(
+            self.order, 1,
+            "Hadamard decoding requires a first-order code"
+        );
+        assert_eq!(
+            received.len(),
+            self.length(),
+            "Word length must equal the code length"
+        );
+        let mut spectrum = received
+            .iter()
+            .map(|&bit| if bit { -1_i64 } else { 1 })
+            .collect::<Vec<_>>();
+        let n = spectrum.len();
+        let mut half = 1;
+        while half < n {
+            for j in 0..n {
+                if j & half == 0 {
+                    let (a, b) = (spectrum[j], spectrum[j | half]);
+                    spectrum[j] = a + b;
+                    spectrum[j | half] = a - b;
+                }
+            }
+            half <<= 1;
+        }
+        let (best, &correlation) = spectrum
+            .iter()
+            .enumerate()
+            .max_by_key(|(_, c)| c.abs())
+            .unwrap();
+        std::iter::once(correlation < 0)
+            .chain((0..self.variables).map(|i| (best >> i) & 1 == 1))
+            .collect()
+    }
+
+    /// Decode to message bits, with the fast Hadamard transform for first-order codes and majority logic otherwise
+    pub fn decode(&self, received: &[bool]) -> Vec<bool> {
+        match self.order {
+            1 => self.decode_hadamard(received),
+            _ => self.decode_majority(received),
+        }
+    }
+}
+
+/// Every submask of a mask
+fn submasks(mask: u32) -> impl Iterator<Item = u32> {
+    let mut next = Some(mask);
+    std::iter::from_fn(move || {
+        let current = next?;
+        next = (current != 0).then(|| (current - 1) & mask);
+        Some(current)
+    })
+}
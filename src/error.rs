@@ -0,0 +1,31 @@
+//! Errors
+//! Failures of the fallible counterparts of panicking constructors and operations,
+//! for inputs that come from users or other untrusted sources.
+
+use std::fmt::Display;
+
+/// Invalid input to a checked constructor or operation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AlgebraError {
+    /// Division by the zero element
+    DivisionByZero,
+    /// The element has no multiplicative inverse
+    NotInvertible,
+    /// Fraction with a zero denominator
+    ZeroDenominator,
+    /// Residues modulo zero
+    ZeroModulus,
+}
+
+impl Display for AlgebraError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AlgebraError::DivisionByZero => write!(f, "Division by zero"),
+            AlgebraError::NotInvertible => write!(f, "Element is not invertible"),
+            AlgebraError::ZeroDenominator => write!(f, "Denominator must be non-zero"),
+            AlgebraError::ZeroModulus => write!(f, "Modulus must be positive"),
+        }
+    }
+}
+
+impl std::error::Error for AlgebraError {}
@@ -0,0 +1,572 @@
+//! Expression Parser
+//! https://en.wikipedia.org/wiki/Recursive_descent_parser
+//! Arithmetic expressions in one variable x over a ring chosen at runtime, such as
+//! `(3x^2 + 1) * (x - 2) mod 17` or `inverse(5) in F101`.
+//! Expressions are parsed once into an `Expr` tree and evaluated over any `DynRing` to a polynomial in x.
+//! Grammar, with juxtaposition as multiplication:
+//! statement := expr [ "mod" n | "in" ring ]
+//! expr := term (("+" | "-") term)*
+//! term := unary (("*" | "/")? unary)*
+//! unary := "-" unary | power
+//! power := atom ("^" "-"? n)?
+//! atom := number | "x" | name "(" expr ("," expr)* ")" | "(" expr ")"
+
+use std::fmt::Display;
+
+use crate::{
+    error::AlgebraError,
+    ring::dynamic::{
+        Complexes, DynRing, DynRingError, Element, Integers, IntegersMod, Rationals, Reals,
+    },
+};
+
+/// Largest degree in x of an intermediate result
+/// Bounds the memory of inputs such as `x^100000000`, whose coefficient vector would otherwise be allocated.
+pub const MAX_DEGREE: usize = 1 << 12;
+
+/// Failed parse or evaluation
+#[derive(Debug, Clone, PartialEq)]
+pub enum ExpressionError {
+    /// Character that starts no token
+    UnexpectedCharacter(char),
+    /// Token where another was expected
+    UnexpectedToken(String),
+    /// Input ended in the middle of an expression
+    UnexpectedEnd,
+    /// Number literal too large to represent, or a decimal outside a field
+    InvalidNumber(String),
+    /// Variable other than x
+    UnknownVariable(String),
+    /// Function name, or wrong number of arguments
+    UnknownFunction(String),
+    /// Ring name that is not one of Z, Q, R, C, Fp or Z/n
+    UnknownRing(String),
+    /// Operation only defined on constants applied to a polynomial in x
+    NonConstant,
+    /// Polynomial in x of degree above `MAX_DEGREE`
+    DegreeTooLarge(usize),
+    /// Error from the ring arithmetic, such as a non-invertible divisor or overflow
+    Ring(DynRingError),
+}
+
+impl Display for ExpressionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ExpressionError::UnexpectedCharacter(c) => write!(f, "Unexpected character '{c}'"),
+            ExpressionError::UnexpectedToken(t) => write!(f, "Unexpected '{t}'"),
+            ExpressionError::UnexpectedEnd => write!(f, "Unexpected end of input"),
+            ExpressionError::InvalidNumber(n) => write!(f, "Invalid number {n}"),
+            ExpressionError::UnknownVariable(v) => {
+                write!(f, "Unknown variable {v}, only x is supported")
+            }
+            ExpressionError::UnknownFunction(name) => write!(f, "Unknown function {name}"),
+            ExpressionError::UnknownRing(name) => write!(f, "Unknown ring {name}"),
+            ExpressionError::NonConstant => write!(f, "Operation requires a constant operand"),
+            ExpressionError::DegreeTooLarge(d) => {
+                write!(f, "Degree {d} exceeds the limit of {MAX_DEGREE}")
+            }
+            ExpressionError::Ring(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl std::error::Error for ExpressionError {}
+
+impl From<DynRingError> for ExpressionError {
+    fn from(e: DynRingError) -> Self {
+        ExpressionError::Ring(e)
+    }
+}
+
+impl From<AlgebraError> for ExpressionError {
+    fn from(e: AlgebraError) -> Self {
+        ExpressionError::Ring(e.into())
+    }
+}
+
+/// Expression tree
+#[derive(Debug, Clone, PartialEq)]
+pub enum Expr {
+    /// Decimal literal mantissa / 10^scale
+    Number {
+        mantissa: i128,
+        scale: u32,
+    },
+    /// The variable x
+    Variable,
+    Neg(Box<Expr>),
+    Add(Box<Expr>, Box<Expr>),
+    Sub(Box<Expr>, Box<Expr>),
+    Mul(Box<Expr>, Box<Expr>),
+    Div(Box<Expr>, Box<Expr>),
+    Pow(Box<Expr>, i64),
+    Call(String, Vec<Expr>),
+}
+
+/// Parsed input: an expression and the ring to evaluate it in, Q unless given
+#[derive(Debug)]
+pub struct Statement {
+    pub expr: Expr,
+    pub ring: Box<dyn DynRing>,
+}
+
+impl Statement {
+    pub fn evaluate(&self) -> Result<Value, ExpressionError> {
+        self.expr.evaluate(self.ring.as_ref())
+    }
+}
+
+/// Polynomial in x with coefficients in a dynamic ring, lowest degree first
+/// Always holds at least the constant term, so the ring is known even for zero.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Value {
+    coeffs: Vec<Element>,
+}
+
+impl Value {
+    pub fn constant(c: Element) -> Self {
+        Value { coeffs: vec![c] }
+    }
+
+    fn from_coeffs(mut coeffs: Vec<Element>) -> Self {
+        while coeffs.len() > 1 && coeffs.last().unwrap().is_zero() {
+            coeffs.pop();
+        }
+        Value { coeffs }
+    }
+
+    pub fn coeffs(&self) -> &[Element] {
+        &self.coeffs
+    }
+
+    pub fn ring(&self) -> Box<dyn DynRing> {
+        self.coeffs[0].ring()
+    }
+
+    /// Degree in x, None for zero
+    pub fn degree(&self) -> Option<usize> {
+        match self.as_constant() {
+            Some(c) if c.is_zero() => None,
+            _ => Some(self.coeffs.len() - 1),
+        }
+    }
+
+    /// The value as a ring element, if it does not depend on x
+    pub fn as_constant(&self) -> Option<&Element> {
+        (self.coeffs.len() == 1).then(|| &self.coeffs[0])
+    }
+
+    fn try_add(&self, other: &Self) -> Result<Self, DynRingError> {
+        self.zip_with(other, Element::try_add)
+    }
+
+    fn try_sub(&self, other: &Self) -> Result<Self, DynRingError> {
+        self.zip_with(other, Element::try_sub)
+    }
+
+    /// Coefficient-wise operation, missing coefficients taken as zero
+    fn zip_with(
+        &self,
+        other: &Self,
+        op: fn(&Element, &Element) -> Result<Element, DynRingError>,
+    ) -> Result<Self, DynRingError> {
+        let zero = self.ring().zero();
+        let n = self.coeffs.len().max(other.coeffs.len());
+        let coeffs = (0..n)
+            .map(|k| {
+                op(
+                    self.coeffs.get(k).unwrap_or(&zero),
+                    other.coeffs.get(k).unwrap_or(&zero),
+                )
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Value::from_coeffs(coeffs))
+    }
+
+    fn try_mul(&self, other: &Self) -> Result<Self, ExpressionError> {
+        let degree = self.coeffs.len() + other.coeffs.len() - 2;
+        if degree > MAX_DEGREE {
+            return Err(ExpressionError::DegreeTooLarge(degree));
+        }
+        let zero = self.ring().zero();
+        let mut coeffs = vec![zero; self.coeffs.len() + other.coeffs.len() - 1];
+        for (i, a) in self.coeffs.iter().enumerate() {
+            for (j, b) in other.coeffs.iter().enumerate() {
+                coeffs[i + j] = coeffs[i + j].try_add(&a.try_mul(b)?)?;
+            }
+        }
+        Ok(Value::from_coeffs(coeffs))
+    }
+
+    /// Division by a constant
+    fn try_div(&self, other: &Self) -> Result<Self, ExpressionError> {
+        let inverse = other
+            .as_constant()
+            .ok_or(ExpressionError::NonConstant)?
+            .try_inverse()?;
+        let coeffs = self
+            .coeffs
+            .iter()
+            .map(|c| c.try_mul(&inverse))
+            .collect::<Result<_, _>>()?;
+        Ok(Value::from_coeffs(coeffs))
+    }
+
+    /// Power by squaring, negative exponents only for invertible constants
+    /// Fails before multiplying if the result would exceed `MAX_DEGREE`.
+    fn try_pow(&self, exp: i64) -> Result<Self, ExpressionError> {
+        if let Some(c) = self.as_constant() {
+            return Ok(Value::constant(c.try_pow(exp)?));
+        }
+        if exp < 0 {
+            return Err(ExpressionError::NonConstant);
+        }
+        let degree = (self.coeffs.len() - 1).saturating_mul(exp as usize);
+        if degree > MAX_DEGREE {
+            return Err(ExpressionError::DegreeTooLarge(degree));
+        }
+        let mut result = Value::constant(self.ring().one());
+        let mut base = self.clone();
+        let mut exp = exp as u64;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = result.try_mul(&base)?;
+            }
+            exp >>= 1;
+            if exp > 0 {
+                base = base.try_mul(&base)?;
+            }
+        }
+        Ok(result)
+    }
+}
+
+impl Display for Value {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let repr = self
+            .coeffs
+            .iter()
+            .enumerate()
+            .filter(|(_, c)| !c.is_zero())
+            .map(|(deg, c)| {
+                // Compound coefficients such as complex numbers are bracketed
+                let c = match c.to_string() {
+                    c if deg > 0 && c.contains(' ') => format!("({c})"),
+                    c => c,
+                };
+                match deg {
+                    0 => c,
+                    1 => format!("{c} x"),
+                    _ => format!("{c} x^{deg}"),
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(" + ");
+
+        if repr.is_empty() {
+            write!(f, "0")
+        } else {
+            write!(f, "{repr}")
+        }
+    }
+}
+
+impl Expr {
+    /// Value of the expression over a ring
+    pub fn evaluate(&self, ring: &dyn DynRing) -> Result<Value, ExpressionError> {
+        match self {
+            Expr::Number { mantissa, scale: 0 } => Ok(Value::constant(ring.integer(*mantissa))),
+            Expr::Number { mantissa, scale } => {
+                // Decimals need 10 to be invertible, which rules out Z and Z/n with 2 or 5 dividing n
+                let invalid = || ExpressionError::InvalidNumber(format!("{mantissa}e-{scale}"));
+                let denominator = 10i128.checked_pow(*scale).ok_or_else(invalid)?;
+                let value = ring
+                    .integer(*mantissa)
+                    .try_div(&ring.integer(denominator))
+                    .map_err(|_| invalid())?;
+                Ok(Value::constant(value))
+            }
+            Expr::Variable => Ok(Value::from_coeffs(vec![ring.zero(), ring.one()])),
+            Expr::Neg(a) => Ok(Value::constant(ring.zero()).try_sub(&a.evaluate(ring)?)?),
+            Expr::Add(a, b) => Ok(a.evaluate(ring)?.try_add(&b.evaluate(ring)?)?),
+            Expr::Sub(a, b) => Ok(a.evaluate(ring)?.try_sub(&b.evaluate(ring)?)?),
+            Expr::Mul(a, b) => Ok(a.evaluate(ring)?.try_mul(&b.evaluate(ring)?)?),
+            Expr::Div(a, b) => a.evaluate(ring)?.try_div(&b.evaluate(ring)?),
+            Expr::Pow(a, exp) => a.evaluate(ring)?.try_pow(*exp),
+            Expr::Call(name, args) => {
+                let args = args
+                    .iter()
+                    .map(|a| a.evaluate(ring))
+                    .collect::<Result<Vec<_>, _>>()?;
+                call(name, &args)
+            }
+        }
+    }
+}
+
+/// Built-in functions
+fn call(name: &str, args: &[Value]) -> Result<Value, ExpressionError> {
+    match (name, args) {
+        ("inverse", [a]) => {
+            let a = a.as_constant().ok_or(ExpressionError::NonConstant)?;
+            Ok(Value::constant(a.try_inverse()?))
+        }
+        _ => Err(ExpressionError::UnknownFunction(format!(
+            "{name}/{}",
+            args.len()
+        ))),
+    }
+}
+
+/// Ring from its name: Z, Q, R, C, Fp for a prime p, or Z/n
+pub fn parse_ring(name: &str) -> Result<Box<dyn DynRing>, ExpressionError> {
+    let unknown = || ExpressionError::UnknownRing(name.to_string());
+    let modulus = |n: &str| match n.trim().parse::<u64>() {
+        Ok(n) => Ok(IntegersMod::try_from(n)?),
+        Err(_) => Err(unknown()),
+    };
+    match name.trim() {
+        "Z" => Ok(Box::new(Integers)),
+        "Q" => Ok(Box::new(Rationals)),
+        "R" => Ok(Box::new(Reals)),
+        "C" => Ok(Box::new(Complexes)),
+        other => {
+            if let Some(n) = other.strip_prefix("Z/") {
+                Ok(Box::new(modulus(n)?))
+            } else if let Some(p) = other.strip_prefix('F') {
+                let ring = modulus(p)?;
+                if !ring.is_field() {
+                    return Err(unknown());
+                }
+                Ok(Box::new(ring))
+            } else {
+                Err(unknown())
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Number(String),
+    Name(String),
+    Symbol(char),
+    Mod,
+    /// The keyword in and the ring name following it
+    In(String),
+}
+
+impl Display for Token {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Token::Number(s) | Token::Name(s) => write!(f, "{s}"),
+            Token::Symbol(c) => write!(f, "{c}"),
+            Token::Mod => write!(f, "mod"),
+            Token::In(ring) => write!(f, "in {ring}"),
+        }
+    }
+}
+
+fn tokenize(input: &str) -> Result<Vec<Token>, ExpressionError> {
+    let mut tokens = vec![];
+    let mut chars = input.char_indices().peekable();
+    while let Some(&(start, c)) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+        } else if c.is_ascii_digit() || c == '.' {
+            let mut end = start;
+            while let Some(&(i, c)) = chars.peek() {
+                if !(c.is_ascii_digit() || c == '.') {
+                    break;
+                }
+                end = i + c.len_utf8();
+                chars.next();
+            }
+            tokens.push(Token::Number(input[start..end].to_string()));
+        } else if c.is_alphabetic() {
+            let mut end = start;
+            while let Some(&(i, c)) = chars.peek() {
+                if !(c.is_alphanumeric() || c == '_') {
+                    break;
+                }
+                end = i + c.len_utf8();
+                chars.next();
+            }
+            match &input[start..end] {
+                "mod" => tokens.push(Token::Mod),
+                "in" => {
+                    tokens.push(Token::In(input[end..].trim().to_string()));
+                    break;
+                }
+                name => tokens.push(Token::Name(name.to_string())),
+            }
+        } else if "+-*/^(),".contains(c) {
+            tokens.push(Token::Symbol(c));
+            chars.next();
+        } else {
+            return Err(ExpressionError::UnexpectedCharacter(c));
+        }
+    }
+    Ok(tokens)
+}
+
+/// Literal as mantissa / 10^scale
+fn parse_number(s: &str) -> Result<Expr, ExpressionError> {
+    let invalid = || ExpressionError::InvalidNumber(s.to_string());
+    let (int, frac) = s.split_once('.').unwrap_or((s, ""));
+    if int.is_empty() && frac.is_empty() || frac.contains('.') {
+        return Err(invalid());
+    }
+    let mantissa = format!("{int}{frac}")
+        .parse::<i128>()
+        .map_err(|_| invalid())?;
+    Ok(Expr::Number {
+        mantissa,
+        scale: frac.len() as u32,
+    })
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    position: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position)
+    }
+
+    fn next(&mut self) -> Result<Token, ExpressionError> {
+        let token = self
+            .tokens
+            .get(self.position)
+            .cloned()
+            .ok_or(ExpressionError::UnexpectedEnd)?;
+        self.position += 1;
+        Ok(token)
+    }
+
+    fn expect(&mut self, symbol: char) -> Result<(), ExpressionError> {
+        match self.next()? {
+            Token::Symbol(c) if c == symbol => Ok(()),
+            token => Err(ExpressionError::UnexpectedToken(token.to_string())),
+        }
+    }
+
+    fn eat(&mut self, symbol: char) -> bool {
+        let found = self.peek() == Some(&Token::Symbol(symbol));
+        if found {
+            self.position += 1;
+        }
+        found
+    }
+
+    fn expr(&mut self) -> Result<Expr, ExpressionError> {
+        let mut lhs = self.term()?;
+        loop {
+            if self.eat('+') {
+                lhs = Expr::Add(Box::new(lhs), Box::new(self.term()?));
+            } else if self.eat('-') {
+                lhs = Expr::Sub(Box::new(lhs), Box::new(self.term()?));
+            } else {
+                return Ok(lhs);
+            }
+        }
+    }
+
+    fn term(&mut self) -> Result<Expr, ExpressionError> {
+        let mut lhs = self.unary()?;
+        loop {
+            if self.eat('*') {
+                lhs = Expr::Mul(Box::new(lhs), Box::new(self.unary()?));
+            } else if self.eat('/') {
+                lhs = Expr::Div(Box::new(lhs), Box::new(self.unary()?));
+            } else if matches!(
+                self.peek(),
+                Some(Token::Number(_) | Token::Name(_) | Token::Symbol('('))
+            ) {
+                lhs = Expr::Mul(Box::new(lhs), Box::new(self.power()?));
+            } else {
+                return Ok(lhs);
+            }
+        }
+    }
+
+    fn unary(&mut self) -> Result<Expr, ExpressionError> {
+        if self.eat('-') {
+            Ok(Expr::Neg(Box::new(self.unary()?)))
+        } else {
+            self.power()
+        }
+    }
+
+    fn power(&mut self) -> Result<Expr, ExpressionError> {
+        let base = self.atom()?;
+        if !self.eat('^') {
+            return Ok(base);
+        }
+        let negative = self.eat('-');
+        let exp = match self.next()? {
+            Token::Number(n) => n
+                .parse::<i64>()
+                .map_err(|_| ExpressionError::InvalidNumber(n))?,
+            token => return Err(ExpressionError::UnexpectedToken(token.to_string())),
+        };
+        Ok(Expr::Pow(Box::new(base), if negative { -exp } else { exp }))
+    }
+
+    fn atom(&mut self) -> Result<Expr, ExpressionError> {
+        match self.next()? {
+            Token::Number(n) => parse_number(&n),
+            Token::Symbol('(') => {
+                let inner = self.expr()?;
+                self.expect(')')?;
+                Ok(inner)
+            }
+            Token::Name(name) if self.eat('(') => {
+                let mut args = vec![self.expr()?];
+                while self.eat(',') {
+                    args.push(self.expr()?);
+                }
+                self.expect(')')?;
+                Ok(Expr::Call(name, args))
+            }
+            Token::Name(name) if name == "x" => Ok(Expr::Variable),
+            Token::Name(name) => Err(ExpressionError::UnknownVariable(name)),
+            token => Err(ExpressionError::UnexpectedToken(token.to_string())),
+        }
+    }
+}
+
+/// Parse an expression with an optional `mod n` or `in ring` suffix
+pub fn parse(input: &str) -> Result<Statement, ExpressionError> {
+    let mut parser = Parser {
+        tokens: tokenize(input)?,
+        position: 0,
+    };
+    let expr = parser.expr()?;
+    let ring = match parser.peek().cloned() {
+        None => Box::new(Rationals) as Box<dyn DynRing>,
+        Some(Token::Mod) => {
+            parser.position += 1;
+            match parser.next()? {
+                Token::Number(n) => parse_ring(&format!("Z/{n}"))?,
+                token => return Err(ExpressionError::UnexpectedToken(token.to_string())),
+            }
+        }
+        Some(Token::In(name)) => {
+            parser.position += 1;
+            parse_ring(&name)?
+        }
+        Some(token) => return Err(ExpressionError::UnexpectedToken(token.to_string())),
+    };
+    match parser.peek() {
+        None => Ok(Statement { expr, ring }),
+        Some(token) => Err(ExpressionError::UnexpectedToken(token.to_string())),
+    }
+}
+
+/// Parse and evaluate
+pub fn evaluate(input: &str) -> Result<Value, ExpressionError> {
+    parse(input)?.evaluate()
+}
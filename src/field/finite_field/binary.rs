@@ -0,0 +1,259 @@
+//! Binary Extension Fields
+//! https://en.wikipedia.org/wiki/Finite_field_arithmetic
+//! The fields GF(2^N) = GF(2)[x] / (f) for 1 <= N <= 20, with elements stored as bit vectors of
+//! polynomial coefficients. Addition is XOR and multiplication is a carry-less product reduced modulo f.
+
+use std::{
+    fmt::Display,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+use rand::{
+    distr::{Distribution, StandardUniform},
+    Rng,
+};
+
+use crate::{field::FieldBase, ring::RingBase};
+
+/// Fixed irreducible polynomial defining GF(2^n), including the x^n term
+/// Sparse choices, the degree 8 one is the AES polynomial x^8 + x^4 + x^3 + x + 1.
+const fn modulus(n: u32) -> u32 {
+    match n {
+        1 => 0b11,
+        2 => 0x7,
+        3 => 0xB,
+        4 => 0x13,
+        5 => 0x25,
+        6 => 0x43,
+        7 => 0x83,
+        8 => 0x11B,
+        9 => 0x211,
+        10 => 0x409,
+        11 => 0x805,
+        12 => 0x1053,
+        13 => 0x201B,
+        14 => 0x4443,
+        15 => 0x8003,
+        16 => 0x1100B,
+        17 => 0x20009,
+        18 => 0x40081,
+        19 => 0x80027,
+        20 => 0x100009,
+        _ => panic!("GF(2^N) is only supported for 1 <= N <= 20"),
+    }
+}
+
+/// Element of the binary field GF(2^N)
+/// Bit k of the representation is the coefficient of x^k.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Gf2e<const N: u32>(pub(crate) u32);
+
+impl<const N: u32> Gf2e<N> {
+    /// Defining polynomial f, with f(x) = 0 in the field
+    pub const MODULUS: u32 = modulus(N);
+
+    /// Number of elements 2^N
+    pub const ORDER: u64 = 1 << N;
+
+    /// Create a new element from the coefficient bits of a polynomial, reduced modulo f
+    pub fn new(bits: u32) -> Self {
+        Self(reduce::<N>(bits as u64))
+    }
+
+    /// Coefficient bits
+    pub fn value(&self) -> u32 {
+        self.0
+    }
+
+    /// Generator x of the field as an extension of GF(2)
+    pub fn x() -> Self {
+        Self::new(0b10)
+    }
+
+    /// Every element of the field, ordered by representation
+    pub fn elements() -> impl Iterator<Item = Self> {
+        (0..1_u32 << N).map(Self)
+    }
+
+    /// Power self^exp using square and multiply
+    pub fn pow(&self, mut exp: u64) -> Self {
+        let mut base = *self;
+        let mut result = Self::one();
+        while exp > 0 {
+            if exp % 2 == 1 {
+                result *= base;
+            }
+            base *= base;
+            exp /= 2;
+        }
+        result
+    }
+
+    /// Square root, unique in characteristic 2: sqrt(a) = a^{2^{N-1}}
+    pub fn sqrt(&self) -> Self {
+        (1..N).fold(*self, |acc, _| acc * acc)
+    }
+
+    /// Frobenius automorphism a -> a^2
+    pub fn frobenius(&self) -> Self {
+        *self * *self
+    }
+
+    /// Absolute trace Tr(a) = a + a^2 + ... + a^{2^{N-1}} in GF(2)
+    pub fn trace(&self) -> bool {
+        let (trace, _) = (0..N).fold((Self::zero(), *self), |(sum, a), _| (sum + a, a * a));
+        trace == Self::one()
+    }
+}
+
+/// Carry-less product of two polynomials over GF(2) of degree < 64
+/// Uses the PCLMULQDQ instruction when the CPU supports it, a 4-bit window otherwise.
+#[inline]
+pub fn clmul64(a: u64, b: u64) -> u128 {
+    #[cfg(target_arch = "x86_64")]
+    if std::arch::is_x86_feature_detected!("pclmulqdq") {
+        // SAFETY: the required CPU feature was detected at runtime
+        return unsafe { clmul64_pclmul(a, b) };
+    }
+    clmul64_portable(a, b)
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "pclmulqdq")]
+unsafe fn clmul64_pclmul(a: u64, b: u64) -> u128 {
+    use std::arch::x86_64::{__m128i, _mm_clmulepi64_si128, _mm_set_epi64x};
+    let product =
+        _mm_clmulepi64_si128::<0>(_mm_set_epi64x(0, a as i64), _mm_set_epi64x(0, b as i64));
+    std::mem::transmute::<__m128i, u128>(product)
+}
+
+/// Table of a k for every 4-bit k, then one shift and XOR per nibble of b
+fn clmul64_portable(a: u64, b: u64) -> u128 {
+    let mut table = [0u128; 16];
+    for k in 1..16 {
+        table[k] = (table[k >> 1] << 1) ^ if k & 1 == 1 { a as u128 } else { 0 };
+    }
+    (0..16).rev().fold(0, |acc, i| {
+        (acc << 4) ^ table[((b >> (4 * i)) & 0xF) as usize]
+    })
+}
+
+/// Carry-less product of two polynomials over GF(2) of degree < 32
+#[inline(always)]
+pub(crate) fn clmul(a: u32, b: u32) -> u64 {
+    clmul64(a as u64, b as u64) as u64
+}
+
+/// Reduce a polynomial of degree < 2N modulo f
+#[inline(always)]
+pub(crate) fn reduce<const N: u32>(mut a: u64) -> u32 {
+    let f = Gf2e::<N>::MODULUS as u64;
+    while a >> N != 0 {
+        let shift = u64::BITS - 1 - a.leading_zeros() - N;
+        a ^= f << shift;
+    }
+    a as u32
+}
+
+impl<const N: u32> Display for Gf2e<N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "GF2^{N}({:#x})", self.0)
+    }
+}
+
+impl<const N: u32> Add for Gf2e<N> {
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn add(self, other: Self) -> Self {
+        Self(self.0 ^ other.0)
+    }
+}
+
+impl<const N: u32> Sub for Gf2e<N> {
+    type Output = Self;
+
+    /// Subtraction is addition in characteristic 2
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn sub(self, other: Self) -> Self {
+        Self(self.0 ^ other.0)
+    }
+}
+
+impl<const N: u32> Neg for Gf2e<N> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        self
+    }
+}
+
+impl<const N: u32> Mul for Gf2e<N> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        Self(reduce::<N>(clmul(self.0, other.0)))
+    }
+}
+
+/// Uniformly random element
+impl<const N: u32> Distribution<Gf2e<N>> for StandardUniform {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Gf2e<N> {
+        Gf2e(rng.random_range(0..1 << N))
+    }
+}
+
+impl<const N: u32> RingBase for Gf2e<N> {
+    fn zero() -> Self {
+        Self(0)
+    }
+
+    fn one() -> Self {
+        Self(1)
+    }
+}
+
+impl<const N: u32> FieldBase for Gf2e<N> {
+    /// Inverse a^{-1} = a^{2^N - 2}
+    fn inverse(&self) -> Self {
+        match self {
+            Self(0) => panic!("Inverse of zero is undefined"),
+            a => a.pow(Self::ORDER - 2),
+        }
+    }
+}
+
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<const N: u32> Div for Gf2e<N> {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        self * other.inverse()
+    }
+}
+
+macro_rules! impl_gf2e_ops {
+    ($trait:ident, $method:ident, $assignTrait:ident, $assignMethod:ident) => {
+        impl<'b, const N: u32> $trait<&'b Gf2e<N>> for &Gf2e<N> {
+            type Output = Gf2e<N>;
+            fn $method(self, other: &'b Gf2e<N>) -> Gf2e<N> {
+                (*self).$method(*other)
+            }
+        }
+        impl<const N: u32> $assignTrait for Gf2e<N> {
+            fn $assignMethod(&mut self, other: Gf2e<N>) {
+                *self = (*self).$method(other)
+            }
+        }
+        impl<'a, const N: u32> $assignTrait<&'a Gf2e<N>> for Gf2e<N> {
+            fn $assignMethod(&mut self, other: &'a Gf2e<N>) {
+                *self = (*self).$method(*other)
+            }
+        }
+    };
+}
+
+impl_gf2e_ops!(Add, add, AddAssign, add_assign);
+impl_gf2e_ops!(Sub, sub, SubAssign, sub_assign);
+impl_gf2e_ops!(Mul, mul, MulAssign, mul_assign);
+impl_gf2e_ops!(Div, div, DivAssign, div_assign);
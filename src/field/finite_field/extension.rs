@@ -0,0 +1,288 @@
+//! Extension Fields
+//! https://en.wikipedia.org/wiki/Finite_field#Explicit_construction
+//! The fields GF(p^n) = Fp[x] / (f) for a monic irreducible f of degree n, with elements stored as the
+//! n coefficients of their reduced representatives. The modulus is a type implementing `ExtensionModulus`,
+//! so elements are Copy and have `zero` and `one` like Fp.
+//! Inverses use the extended Euclidean algorithm against f, and the Frobenius map a -> a^p generates
+//! the Galois group of GF(p^n) over Fp.
+
+use std::{
+    fmt::{Debug, Display},
+    marker::PhantomData,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+use rand::{
+    distr::{Distribution, StandardUniform},
+    Rng,
+};
+
+use super::Fp;
+use crate::{
+    field::FieldBase,
+    polynomial::{factor::is_irreducible, Polynomial},
+    ring::RingBase,
+};
+
+/// Monic irreducible polynomial of degree N over Fp defining GF(p^N)
+pub trait ExtensionModulus<const P: u64, const N: usize>:
+    Debug + Clone + Copy + PartialEq + Eq
+{
+    /// Coefficients c_0, ..., c_{N-1} of f = x^N + c_{N-1} x^{N-1} + ... + c_0
+    const COEFFS: [u64; N];
+}
+
+/// AES modulus x^8 + x^4 + x^3 + x + 1 over F2
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Aes;
+
+impl ExtensionModulus<2, 8> for Aes {
+    const COEFFS: [u64; 8] = [1, 1, 0, 1, 1, 0, 0, 0];
+}
+
+/// Element of GF(p^N) = Fp[x] / (f)
+/// Coefficient k of the representation is the coefficient of x^k.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FpExt<const P: u64, const N: usize, M: ExtensionModulus<P, N>> {
+    coeffs: [Fp<P>; N],
+    modulus: PhantomData<M>,
+}
+
+impl<const P: u64, const N: usize, M: ExtensionModulus<P, N>> FpExt<P, N, M> {
+    /// Element with the given coefficients, lowest degree first
+    pub fn new(coeffs: [Fp<P>; N]) -> Self {
+        Self {
+            coeffs,
+            modulus: PhantomData,
+        }
+    }
+
+    /// Class of a polynomial modulo f
+    pub fn from_poly(p: &Polynomial<Fp<P>>) -> Self {
+        let r = p % &Self::modulus();
+        let mut coeffs = [Fp::zero(); N];
+        coeffs[..r.coeffs().len()].copy_from_slice(r.coeffs());
+        Self::new(coeffs)
+    }
+
+    /// Reduced representative, of degree less than N
+    pub fn to_poly(&self) -> Polynomial<Fp<P>> {
+        Polynomial::from_coeffs(self.coeffs.to_vec())
+    }
+
+    pub fn coeffs(&self) -> &[Fp<P>; N] {
+        &self.coeffs
+    }
+
+    /// Defining polynomial f
+    pub fn modulus() -> Polynomial<Fp<P>> {
+        let mut coeffs = M::COEFFS.map(Fp::new).to_vec();
+        coeffs.push(Fp::one());
+        Polynomial::from_coeffs(coeffs)
+    }
+
+    /// Whether f is irreducible, i.e. the quotient ring really is a field
+    pub fn modulus_is_irreducible() -> bool {
+        is_irreducible(&Self::modulus())
+    }
+
+    /// Class of x, a root of f
+    pub fn x() -> Self {
+        Self::from_poly(&Polynomial::from_coeffs(vec![Fp::zero(), Fp::one()]))
+    }
+
+    /// Every element of the field, the coefficients read as base P digits of 0, 1, ..., P^N - 1
+    pub fn elements() -> impl Iterator<Item = Self> {
+        (0..P.pow(N as u32)).map(|mut k| {
+            let mut coeffs = [Fp::zero(); N];
+            for c in coeffs.iter_mut() {
+                *c = Fp::new(k % P);
+                k /= P;
+            }
+            Self::new(coeffs)
+        })
+    }
+
+    /// Power self^exp using square and multiply
+    pub fn pow(&self, mut exp: u64) -> Self {
+        let mut base = *self;
+        let mut result = Self::one();
+        while exp > 0 {
+            if exp % 2 == 1 {
+                result *= base;
+            }
+            base *= base;
+            exp /= 2;
+        }
+        result
+    }
+
+    /// Frobenius automorphism a -> a^p, fixing exactly Fp
+    pub fn frobenius(&self) -> Self {
+        self.pow(P)
+    }
+
+    /// Conjugates a, a^p, ..., a^{p^{N-1}}
+    fn conjugates(&self) -> impl Iterator<Item = Self> {
+        std::iter::successors(Some(*self), |a| Some(a.frobenius())).take(N)
+    }
+
+    /// Trace Tr(a) = a + a^p + ... + a^{p^{N-1}} in Fp
+    pub fn trace(&self) -> Fp<P> {
+        self.conjugates()
+            .fold(Self::zero(), |acc, a| acc + a)
+            .coeffs[0]
+    }
+
+    /// Norm N(a) = a a^p ... a^{p^{N-1}} in Fp
+    pub fn norm(&self) -> Fp<P> {
+        self.conjugates().fold(Self::one(), |acc, a| acc * a).coeffs[0]
+    }
+}
+
+impl<const P: u64, const N: usize, M: ExtensionModulus<P, N>> From<Fp<P>> for FpExt<P, N, M> {
+    fn from(c: Fp<P>) -> Self {
+        let mut coeffs = [Fp::zero(); N];
+        coeffs[0] = c;
+        Self::new(coeffs)
+    }
+}
+
+impl<const P: u64, const N: usize, M: ExtensionModulus<P, N>> Display for FpExt<P, N, M> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let coeffs = self
+            .coeffs
+            .iter()
+            .map(|c| c.value().to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+        write!(f, "GF{P}^{N}[{coeffs}]")
+    }
+}
+
+impl<const P: u64, const N: usize, M: ExtensionModulus<P, N>> Add for FpExt<P, N, M> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        let mut coeffs = self.coeffs;
+        for (a, b) in coeffs.iter_mut().zip(other.coeffs) {
+            *a += b;
+        }
+        Self::new(coeffs)
+    }
+}
+
+impl<const P: u64, const N: usize, M: ExtensionModulus<P, N>> Sub for FpExt<P, N, M> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        let mut coeffs = self.coeffs;
+        for (a, b) in coeffs.iter_mut().zip(other.coeffs) {
+            *a -= b;
+        }
+        Self::new(coeffs)
+    }
+}
+
+impl<const P: u64, const N: usize, M: ExtensionModulus<P, N>> Neg for FpExt<P, N, M> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::zero() - self
+    }
+}
+
+impl<const P: u64, const N: usize, M: ExtensionModulus<P, N>> Mul for FpExt<P, N, M> {
+    type Output = Self;
+
+    /// Schoolbook product, then x^N = -(c_{N-1} x^{N-1} + ... + c_0) from the top degree down
+    fn mul(self, other: Self) -> Self {
+        let mut product = vec![Fp::<P>::zero(); 2 * N - 1];
+        for (i, a) in self.coeffs.iter().enumerate() {
+            for (j, b) in other.coeffs.iter().enumerate() {
+                product[i + j] += *a * *b;
+            }
+        }
+        for k in (N..2 * N - 1).rev() {
+            let top = product[k];
+            for (i, c) in M::COEFFS.iter().enumerate() {
+                product[k - N + i] -= top * Fp::new(*c);
+            }
+        }
+        let mut coeffs = [Fp::zero(); N];
+        coeffs.copy_from_slice(&product[..N]);
+        Self::new(coeffs)
+    }
+}
+
+/// Uniformly random element
+impl<const P: u64, const N: usize, M: ExtensionModulus<P, N>> Distribution<FpExt<P, N, M>>
+    for StandardUniform
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> FpExt<P, N, M> {
+        FpExt::new(std::array::from_fn(|_| rng.random()))
+    }
+}
+
+impl<const P: u64, const N: usize, M: ExtensionModulus<P, N>> RingBase for FpExt<P, N, M> {
+    fn zero() -> Self {
+        Self::new([Fp::zero(); N])
+    }
+
+    fn one() -> Self {
+        Fp::one().into()
+    }
+}
+
+impl<const P: u64, const N: usize, M: ExtensionModulus<P, N>> FieldBase for FpExt<P, N, M> {
+    /// Inverse from the Bezout identity s a + t f = 1
+    fn inverse(&self) -> Self {
+        if *self == Self::zero() {
+            panic!("Inverse of zero is undefined");
+        }
+        let (g, s, _) = self.to_poly().extended_gcd(&Self::modulus());
+        assert_eq!(g.degree(), Some(0), "Extension modulus is not irreducible");
+        Self::from_poly(&s)
+    }
+}
+
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<const P: u64, const N: usize, M: ExtensionModulus<P, N>> Div for FpExt<P, N, M> {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        self * other.inverse()
+    }
+}
+
+macro_rules! impl_fp_ext_ops {
+    ($trait:ident, $method:ident, $assignTrait:ident, $assignMethod:ident) => {
+        impl<'b, const P: u64, const N: usize, M: ExtensionModulus<P, N>> $trait<&'b FpExt<P, N, M>>
+            for &FpExt<P, N, M>
+        {
+            type Output = FpExt<P, N, M>;
+            fn $method(self, other: &'b FpExt<P, N, M>) -> FpExt<P, N, M> {
+                (*self).$method(*other)
+            }
+        }
+        impl<const P: u64, const N: usize, M: ExtensionModulus<P, N>> $assignTrait
+            for FpExt<P, N, M>
+        {
+            fn $assignMethod(&mut self, other: FpExt<P, N, M>) {
+                *self = (*self).$method(other)
+            }
+        }
+        impl<'a, const P: u64, const N: usize, M: ExtensionModulus<P, N>>
+            $assignTrait<&'a FpExt<P, N, M>> for FpExt<P, N, M>
+        {
+            fn $assignMethod(&mut self, other: &'a FpExt<P, N, M>) {
+                *self = (*self).$method(*other)
+            }
+        }
+    };
+}
+
+impl_fp_ext_ops!(Add, add, AddAssign, add_assign);
+impl_fp_ext_ops!(Sub, sub, SubAssign, sub_assign);
+impl_fp_ext_ops!(Mul, mul, MulAssign, mul_assign);
+impl_fp_ext_ops!(Div, div, DivAssign, div_assign);
@@ -0,0 +1,73 @@
+//! Factorial Tables
+//! Cached factorials and inverse factorials in Fp, for O(1) binomial coefficients.
+
+use super::Fp;
+use crate::{field::FieldBase, ring::RingBase};
+
+/// Table of k! and 1 / k! for 0 <= k <= n
+/// Built with O(n) multiplications and a single inversion.
+/// Requires n < P, since P! = 0 in Fp.
+#[derive(Debug, Clone)]
+pub struct Factorials<const P: u64> {
+    factorials: Vec<Fp<P>>,
+    inverse_factorials: Vec<Fp<P>>,
+}
+
+impl<const P: u64> Factorials<P> {
+    /// Tables up to n!
+    /// Panics if n >= P.
+    pub fn new(n: usize) -> Self {
+        assert!(
+            (n as u64) < P,
+            "Factorial table must stay below the characteristic"
+        );
+        let mut factorials = Vec::with_capacity(n + 1);
+        factorials.push(Fp::one());
+        for k in 1..=n {
+            factorials.push(factorials[k - 1] * Fp::new(k as u64));
+        }
+        // 1 / (k - 1)! = k / k!
+        let mut inverse_factorials = vec![Fp::one(); n + 1];
+        inverse_factorials[n] = factorials[n].inverse();
+        for k in (1..=n).rev() {
+            inverse_factorials[k - 1] = inverse_factorials[k] * Fp::new(k as u64);
+        }
+        Self {
+            factorials,
+            inverse_factorials,
+        }
+    }
+
+    /// Largest n such that n! is tabulated
+    pub fn len(&self) -> usize {
+        self.factorials.len() - 1
+    }
+
+    /// Always false, the table holds at least 0! = 1
+    pub fn is_empty(&self) -> bool {
+        false
+    }
+
+    /// Factorial k!
+    pub fn factorial(&self, k: usize) -> Fp<P> {
+        self.factorials[k]
+    }
+
+    /// Inverse factorial 1 / k!
+    pub fn inverse_factorial(&self, k: usize) -> Fp<P> {
+        self.inverse_factorials[k]
+    }
+
+    /// Inverse 1 / k for 1 <= k <= n, as (k - 1)! / k!
+    pub fn inverse(&self, k: usize) -> Fp<P> {
+        self.inverse_factorials[k] * self.factorials[k - 1]
+    }
+
+    /// Binomial coefficient C(n, k), zero when k > n
+    pub fn binomial(&self, n: usize, k: usize) -> Fp<P> {
+        if k > n {
+            return Fp::zero();
+        }
+        self.factorials[n] * self.inverse_factorials[k] * self.inverse_factorials[n - k]
+    }
+}
@@ -3,10 +3,27 @@ use std::{
     ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
 };
 
-use crate::{field::FieldBase, ring::RingBase};
+use rand::{
+    distr::{Distribution, StandardUniform},
+    Rng,
+};
+
+use crate::{field::FieldBase, number::sqrt_mod_prime, ring::RingBase};
+
+pub mod binary;
+
+pub mod extension;
+
+pub mod factorials;
+
+pub mod montgomery;
+
+pub mod reduce;
+
+pub mod zn;
 
 /// Finite field over P
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Fp<const P: u64>(pub(crate) u64);
 
 impl<const P: u64> Display for Fp<P> {
@@ -70,6 +87,13 @@ impl<const P: u64> Neg for Fp<P> {
     }
 }
 
+/// Uniformly random element
+impl<const P: u64> Distribution<Fp<P>> for StandardUniform {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Fp<P> {
+        Fp(rng.random_range(0..P))
+    }
+}
+
 impl<const P: u64> RingBase for Fp<P> {
     fn zero() -> Self {
         Fp(0)
@@ -103,6 +127,14 @@ impl<const P: u64> Fp<P> {
     }
 }
 
+impl<const P: u64> Fp<P> {
+    /// Square root using the Tonelli-Shanks algorithm
+    /// Returns one of the roots r, the other one being -r. Returns `None` for quadratic non-residues.
+    pub fn sqrt(&self) -> Option<Self> {
+        sqrt_mod_prime(self.0, P).map(Fp)
+    }
+}
+
 impl<const P: u64> FieldBase for Fp<P> {
     /// Inverse using Fermat's little theorem: x^{-1} = x^{P-2}
     fn inverse(&self) -> Self {
@@ -0,0 +1,210 @@
+//! Montgomery Form
+//! https://en.wikipedia.org/wiki/Montgomery_modular_multiplication
+//! Fp elements stored as a R mod P with R = 2^64, so a product needs a 128-bit multiplication and
+//! Montgomery's reduction
+//! $$ \mathrm{REDC}(t) = (t + m P) / R, \quad m = -t P^{-1} \bmod R $$
+//! instead of a 128-bit division. The reduction needs P odd, and P < 2^63 keeps t + m P below 2^128.
+//! Converting in and out costs one reduction each, so `FpMont` pays off for long chains of products
+//! like interpolation and exponentiation.
+
+use std::{
+    fmt::{Debug, Display},
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+use rand::{
+    distr::{Distribution, StandardUniform},
+    Rng,
+};
+
+use super::Fp;
+use crate::{field::FieldBase, ring::RingBase};
+
+/// Element of Fp in Montgomery form a R mod P, with R = 2^64
+/// Same values and arithmetic as Fp<P>.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct FpMont<const P: u64>(u64);
+
+impl<const P: u64> FpMont<P> {
+    /// -P^{-1} mod 2^64, by Newton iteration x <- x (2 - P x) doubling the correct low bits
+    const P_INV_NEG: u64 = {
+        assert!(
+            P % 2 == 1 && P < 1 << 63,
+            "Montgomery form needs an odd P < 2^63"
+        );
+        let mut inv: u64 = 1;
+        let mut i = 0;
+        while i < 6 {
+            inv = inv.wrapping_mul(2_u64.wrapping_sub(P.wrapping_mul(inv)));
+            i += 1;
+        }
+        inv.wrapping_neg()
+    };
+
+    /// R mod P, the form of one
+    const R1: u64 = ((1_u128 << 64) % P as u128) as u64;
+
+    /// R^2 mod P, for converting into the form
+    const R2: u64 = ((Self::R1 as u128 * Self::R1 as u128) % P as u128) as u64;
+
+    /// Montgomery reduction t R^{-1} mod P for t < P R
+    #[inline(always)]
+    fn redc(t: u128) -> u64 {
+        let m = (t as u64).wrapping_mul(Self::P_INV_NEG);
+        let u = ((t + m as u128 * P as u128) >> 64) as u64;
+        match u >= P {
+            true => u - P,
+            false => u,
+        }
+    }
+
+    /// Create a new element
+    pub fn new(value: u64) -> Self {
+        Self(Self::redc((value % P) as u128 * Self::R2 as u128))
+    }
+
+    /// Unwrap the inner value, out of Montgomery form
+    pub fn value(&self) -> u64 {
+        Self::redc(self.0 as u128)
+    }
+
+    /// Power self^exp using square and multiply
+    pub fn pow(&self, mut exp: u64) -> Self {
+        let mut base = *self;
+        let mut result = Self::one();
+        while exp > 0 {
+            if exp % 2 == 1 {
+                result *= base;
+            }
+            base *= base;
+            exp /= 2;
+        }
+        result
+    }
+}
+
+impl<const P: u64> From<Fp<P>> for FpMont<P> {
+    fn from(a: Fp<P>) -> Self {
+        Self::new(a.value())
+    }
+}
+
+impl<const P: u64> From<FpMont<P>> for Fp<P> {
+    fn from(a: FpMont<P>) -> Self {
+        Fp(a.value())
+    }
+}
+
+impl<const P: u64> Debug for FpMont<P> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "FpMont({})", self.value())
+    }
+}
+
+impl<const P: u64> Display for FpMont<P> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "F{P}({n})", n = self.value())
+    }
+}
+
+impl<const P: u64> Add for FpMont<P> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        let sum = self.0 + other.0;
+        match sum >= P {
+            true => Self(sum - P),
+            false => Self(sum),
+        }
+    }
+}
+
+impl<const P: u64> Sub for FpMont<P> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        match self.0 >= other.0 {
+            true => Self(self.0 - other.0),
+            false => Self(self.0 + P - other.0),
+        }
+    }
+}
+
+impl<const P: u64> Neg for FpMont<P> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::zero() - self
+    }
+}
+
+impl<const P: u64> Mul for FpMont<P> {
+    type Output = Self;
+
+    /// (a R)(b R) R^{-1} = (a b) R
+    fn mul(self, other: Self) -> Self {
+        Self(Self::redc(self.0 as u128 * other.0 as u128))
+    }
+}
+
+/// Uniformly random element
+impl<const P: u64> Distribution<FpMont<P>> for StandardUniform {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> FpMont<P> {
+        FpMont::new(rng.random_range(0..P))
+    }
+}
+
+impl<const P: u64> RingBase for FpMont<P> {
+    fn zero() -> Self {
+        Self(0)
+    }
+
+    fn one() -> Self {
+        Self(Self::R1)
+    }
+}
+
+impl<const P: u64> FieldBase for FpMont<P> {
+    /// Inverse using Fermat's little theorem: x^{-1} = x^{P-2}
+    fn inverse(&self) -> Self {
+        match *self == Self::zero() {
+            true => panic!("Inverse of zero is undefined"),
+            false => self.pow(P - 2),
+        }
+    }
+}
+
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<const P: u64> Div for FpMont<P> {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        self * other.inverse()
+    }
+}
+
+macro_rules! impl_fp_mont_ops {
+    ($trait:ident, $method:ident, $assignTrait:ident, $assignMethod:ident) => {
+        impl<'b, const P: u64> $trait<&'b FpMont<P>> for &FpMont<P> {
+            type Output = FpMont<P>;
+            fn $method(self, other: &'b FpMont<P>) -> FpMont<P> {
+                (*self).$method(*other)
+            }
+        }
+        impl<const P: u64> $assignTrait for FpMont<P> {
+            fn $assignMethod(&mut self, other: FpMont<P>) {
+                *self = (*self).$method(other)
+            }
+        }
+        impl<'a, const P: u64> $assignTrait<&'a FpMont<P>> for FpMont<P> {
+            fn $assignMethod(&mut self, other: &'a FpMont<P>) {
+                *self = (*self).$method(*other)
+            }
+        }
+    };
+}
+
+impl_fp_mont_ops!(Add, add, AddAssign, add_assign);
+impl_fp_mont_ops!(Sub, sub, SubAssign, sub_assign);
+impl_fp_mont_ops!(Mul, mul, MulAssign, mul_assign);
+impl_fp_mont_ops!(Div, div, DivAssign, div_assign);
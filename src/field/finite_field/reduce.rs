@@ -0,0 +1,99 @@
+//! Reduction modulo P
+//! The canonical ring homomorphism Z -> Z/PZ, extended to rationals with invertible denominators
+//! and coefficient-wise to structures built on them.
+
+use std::fmt::Display;
+
+use super::Fp;
+use crate::{field::FieldBase, number::Rational, polynomial::Polynomial, ring::RingBase};
+
+/// Reduction failure
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReductionError {
+    /// The denominator of a rational is not invertible modulo P
+    NonInvertibleDenominator { den: i128, modulus: u64 },
+}
+
+impl Display for ReductionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReductionError::NonInvertibleDenominator { den, modulus } => {
+                write!(f, "Denominator {den} is not invertible modulo {modulus}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ReductionError {}
+
+/// Scalars with a reduction map into Fp
+pub trait ReduceToFp {
+    /// Image in Fp<P>
+    fn to_fp<const P: u64>(&self) -> Result<Fp<P>, ReductionError>;
+}
+
+/// Structures that can be reduced modulo P
+pub trait ReduceMod {
+    /// The same structure over Fp<P>
+    type Output<const P: u64>;
+
+    /// Reduce modulo P
+    fn reduce_mod<const P: u64>(&self) -> Result<Self::Output<P>, ReductionError>;
+}
+
+macro_rules! impl_reduce_for_unsigned {
+    ($($t:ty),*) => {
+        $(
+            impl ReduceToFp for $t {
+                fn to_fp<const P: u64>(&self) -> Result<Fp<P>, ReductionError> {
+                    Ok(Fp((*self as u128 % P as u128) as u64))
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_reduce_for_signed {
+    ($($t:ty),*) => {
+        $(
+            impl ReduceToFp for $t {
+                fn to_fp<const P: u64>(&self) -> Result<Fp<P>, ReductionError> {
+                    Ok(Fp((*self as i128).rem_euclid(P as i128) as u64))
+                }
+            }
+        )*
+    };
+}
+
+impl_reduce_for_unsigned!(u8, u16, u32, u64, u128, usize);
+impl_reduce_for_signed!(i8, i16, i32, i64, i128, isize);
+
+/// n / d maps to n d^{-1}, which requires P not to divide d
+impl ReduceToFp for Rational {
+    fn to_fp<const P: u64>(&self) -> Result<Fp<P>, ReductionError> {
+        let num = self.num().to_fp::<P>()?;
+        let den = self.den().to_fp::<P>()?;
+        num.checked_div(&den)
+            .ok_or(ReductionError::NonInvertibleDenominator {
+                den: self.den(),
+                modulus: P,
+            })
+    }
+}
+
+impl<T: ReduceToFp> ReduceMod for T {
+    type Output<const P: u64> = Fp<P>;
+
+    fn reduce_mod<const P: u64>(&self) -> Result<Fp<P>, ReductionError> {
+        self.to_fp::<P>()
+    }
+}
+
+/// Coefficient-wise reduction, the degree drops when P divides the leading coefficients
+impl<T: ReduceToFp + RingBase> ReduceMod for Polynomial<T> {
+    type Output<const P: u64> = Polynomial<Fp<P>>;
+
+    fn reduce_mod<const P: u64>(&self) -> Result<Polynomial<Fp<P>>, ReductionError> {
+        self.try_map(|c| c.to_fp::<P>())
+    }
+}
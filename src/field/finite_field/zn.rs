@@ -0,0 +1,270 @@
+//! Integers Modulo a Runtime n
+//! https://en.wikipedia.org/wiki/Modular_arithmetic#Integers_modulo_m
+//! Residues in Z/nZ with the modulus stored in each element, for moduli only known at runtime
+//! (read from a key file or user input). The arithmetic mirrors Fp<P>, and Z/nZ is a field iff n is prime.
+//! `RingBase::zero` and `RingBase::one` have no modulus to read from, so they return integer constants,
+//! which are reduced modulo n when combined with a residue modulo n.
+//! Combining or comparing residues of different moduli panics.
+
+use std::{
+    fmt::Display,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+use crate::{
+    error::AlgebraError,
+    field::FieldBase,
+    number::{mod_inverse, mul_mod, sqrt_mod},
+    ring::RingBase,
+};
+
+/// Residue modulo n, with n = 0 marking the integer constants from `RingBase`
+#[derive(Debug, Clone, Copy, Eq)]
+pub struct Zn {
+    /// Representative in [0, n), or the value of a constant
+    value: i128,
+    modulus: u64,
+}
+
+impl Zn {
+    /// Residue class of value modulo n
+    /// Panics if the modulus is zero, see `TryFrom<(u64, u64)>`.
+    pub fn new(value: u64, modulus: u64) -> Self {
+        Self::from_integer(value as i128, modulus)
+    }
+
+    /// Residue class of a signed integer
+    /// Panics if the modulus is zero.
+    pub fn from_integer(n: i128, modulus: u64) -> Self {
+        assert!(modulus > 0, "Modulus must be positive");
+        Self {
+            value: n.rem_euclid(modulus as i128),
+            modulus,
+        }
+    }
+
+    /// Representative in [0, n)
+    /// Panics for negative constants without modulus.
+    pub fn value(&self) -> u64 {
+        u64::try_from(self.value).expect("Negative constant has no representative")
+    }
+
+    /// The modulus n, None for the constants from `RingBase`
+    pub fn modulus(&self) -> Option<u64> {
+        (self.modulus > 0).then_some(self.modulus)
+    }
+
+    /// Same residue reduced modulo n, fixing the modulus of a constant
+    fn with_modulus(&self, modulus: u64) -> Self {
+        match self.modulus {
+            0 => Self::from_integer(self.value, modulus),
+            _ => *self,
+        }
+    }
+
+    /// Common modulus of two operands
+    fn common_modulus(&self, other: &Self) -> u64 {
+        match (self.modulus, other.modulus) {
+            (m, n) if m == n => m,
+            (0, n) => n,
+            (m, 0) => m,
+            (m, n) => panic!("Residues modulo {m} and {n} cannot be combined"),
+        }
+    }
+
+    /// Operands brought to their common modulus, then combined
+    /// Constants without modulus are combined as integers.
+    fn combine(
+        self,
+        other: Self,
+        integer: impl Fn(i128, i128) -> Option<i128>,
+        modular: impl Fn(u64, u64, u64) -> u64,
+    ) -> Self {
+        match self.common_modulus(&other) {
+            0 => Self {
+                value: integer(self.value, other.value).expect("Constant overflow"),
+                modulus: 0,
+            },
+            n => Self {
+                value: modular(
+                    self.with_modulus(n).value(),
+                    other.with_modulus(n).value(),
+                    n,
+                ) as i128,
+                modulus: n,
+            },
+        }
+    }
+
+    /// Power self^exp using square and multiply
+    pub fn pow(&self, mut exp: u64) -> Self {
+        let mut base = *self;
+        let mut result = match self.modulus {
+            0 => Self::one(),
+            n => Self::new(1, n),
+        };
+        while exp > 0 {
+            if exp % 2 == 1 {
+                result *= base;
+            }
+            exp /= 2;
+            if exp > 0 {
+                base *= base;
+            }
+        }
+        result
+    }
+
+    /// Whether the residue is invertible
+    pub fn is_unit(&self) -> bool {
+        self.checked_inverse().is_some()
+    }
+
+    /// Square roots, every r with r^2 = self (mod n), in increasing order
+    /// Panics for constants without modulus.
+    pub fn sqrt(&self) -> Vec<Self> {
+        let n = self.modulus().expect("Square roots need a modulus");
+        sqrt_mod(self.value(), n)
+            .into_iter()
+            .map(|value| Self::new(value, n))
+            .collect()
+    }
+}
+
+impl PartialEq for Zn {
+    /// Constants without modulus equal the residues they reduce to
+    /// Panics for residues of different moduli.
+    fn eq(&self, other: &Self) -> bool {
+        match self.common_modulus(other) {
+            0 => self.value == other.value,
+            n => self.with_modulus(n).value == other.with_modulus(n).value,
+        }
+    }
+}
+
+impl Display for Zn {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.modulus {
+            0 => write!(f, "Z({})", self.value),
+            n => write!(f, "Z{n}({})", self.value),
+        }
+    }
+}
+
+impl Add for Zn {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        self.combine(other, i128::checked_add, |a, b, n| {
+            ((a as u128 + b as u128) % n as u128) as u64
+        })
+    }
+}
+
+impl Sub for Zn {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        self.combine(other, i128::checked_sub, |a, b, n| {
+            ((a as u128 + n as u128 - b as u128) % n as u128) as u64
+        })
+    }
+}
+
+impl Neg for Zn {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::zero() - self
+    }
+}
+
+impl Mul for Zn {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        self.combine(other, i128::checked_mul, mul_mod)
+    }
+}
+
+impl RingBase for Zn {
+    fn zero() -> Self {
+        Self {
+            value: 0,
+            modulus: 0,
+        }
+    }
+
+    fn one() -> Self {
+        Self {
+            value: 1,
+            modulus: 0,
+        }
+    }
+}
+
+impl FieldBase for Zn {
+    /// Inverse by the extended Euclidean algorithm
+    /// Panics for non-units, see `checked_inverse`.
+    fn inverse(&self) -> Self {
+        self.checked_inverse()
+            .unwrap_or_else(|| panic!("{self} is not invertible"))
+    }
+
+    /// Inverse by the extended Euclidean algorithm, None unless gcd(value, n) = 1
+    /// The constants ±1 are their own inverses.
+    fn checked_inverse(&self) -> Option<Self> {
+        match self.modulus {
+            0 => (self.value.abs() == 1).then_some(*self),
+            1 => None,
+            n => mod_inverse(self.value(), n).map(|value| Self::new(value, n)),
+        }
+    }
+}
+
+impl TryFrom<(u64, u64)> for Zn {
+    type Error = AlgebraError;
+
+    /// Residue class of (value, modulus), failing for a zero modulus
+    fn try_from((value, modulus): (u64, u64)) -> Result<Self, AlgebraError> {
+        match modulus {
+            0 => Err(AlgebraError::ZeroModulus),
+            n => Ok(Self::new(value, n)),
+        }
+    }
+}
+
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl Div for Zn {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        self * other.inverse()
+    }
+}
+
+macro_rules! impl_zn_ops {
+    ($trait:ident, $method:ident, $assignTrait:ident, $assignMethod:ident) => {
+        impl<'b> $trait<&'b Zn> for &Zn {
+            type Output = Zn;
+            fn $method(self, other: &'b Zn) -> Zn {
+                (*self).$method(*other)
+            }
+        }
+        impl $assignTrait for Zn {
+            fn $assignMethod(&mut self, other: Zn) {
+                *self = (*self).$method(other)
+            }
+        }
+        impl<'a> $assignTrait<&'a Zn> for Zn {
+            fn $assignMethod(&mut self, other: &'a Zn) {
+                *self = (*self).$method(*other)
+            }
+        }
+    };
+}
+
+impl_zn_ops!(Add, add, AddAssign, add_assign);
+impl_zn_ops!(Sub, sub, SubAssign, sub_assign);
+impl_zn_ops!(Mul, mul, MulAssign, mul_assign);
+impl_zn_ops!(Div, div, DivAssign, div_assign);
@@ -11,6 +11,24 @@ pub trait FieldBase: RingBase {
     /// Element inverse
     /// Panics for the zero element
     fn inverse(&self) -> Self;
+
+    /// Element inverse, None for the zero element
+    fn checked_inverse(&self) -> Option<Self> {
+        match *self == Self::zero() {
+            true => None,
+            false => Some(self.inverse()),
+        }
+    }
+
+    /// Quotient self / other, None if other is zero
+    fn checked_div(&self, other: &Self) -> Option<Self>
+    where
+        Self: Mul<Output = Self>,
+    {
+        other
+            .checked_inverse()
+            .map(|inverse| self.clone() * inverse)
+    }
 }
 
 macro_rules! impl_field_for_primitives {
@@ -33,6 +51,40 @@ impl_field_for_primitives!(
     u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, usize, isize, f32, f64
 );
 
+/// Inverses of many elements with a single field inversion, using Montgomery's trick
+/// With prefix products p_k = a_0 a_1 ... a_k, each inverse is a_k^{-1} = p_{k-1} p_k^{-1} and the next
+/// p_{k-1}^{-1} = a_k p_k^{-1}, so n inverses cost 3(n - 1) multiplications and one inversion.
+/// Panics if an element is zero, see `checked_batch_inverse`.
+pub fn batch_inverse<T>(elements: &[T]) -> Vec<T>
+where
+    T: FieldBase + Copy + Mul<Output = T>,
+{
+    checked_batch_inverse(elements).expect("Inverse of zero is undefined")
+}
+
+/// Inverses of many elements with a single field inversion, None if an element is zero
+pub fn checked_batch_inverse<T>(elements: &[T]) -> Option<Vec<T>>
+where
+    T: FieldBase + Copy + Mul<Output = T>,
+{
+    // prefix[k] = a_0 ... a_{k-1}
+    let mut prefix = Vec::with_capacity(elements.len());
+    let mut product = T::one();
+    for a in elements {
+        prefix.push(product);
+        product = product * *a;
+    }
+
+    // Walk back down with the inverse of the running product
+    let mut inverse = product.checked_inverse()?;
+    let mut inverses = prefix;
+    for (p, a) in inverses.iter_mut().zip(elements).rev() {
+        *p = *p * inverse;
+        inverse = inverse * *a;
+    }
+    Some(inverses)
+}
+
 /// Complete Field trait with arithmetic
 pub trait Field: FieldBase + Ring {
     // Design Decision:
@@ -0,0 +1,193 @@
+//! Group Actions
+//! https://ncatlab.org/nlab/show/action
+//! A (left) action of a group G on a set X is a map G x X -> X, (g, x) -> g · x, such that
+//! 1. e · x = x
+//! 2. (g h) · x = g · (h · x)
+//!
+//! Following the operation markers in `magma`, an action is identified by a marker type implementing `Action<G, X>`,
+//! so the same pair of sets may carry several actions.
+
+use std::ops::{Add, Mul};
+
+use super::permutation::Permutation;
+use crate::{
+    field::finite_field::{
+        binary::Gf2e,
+        extension::{ExtensionModulus, FpExt},
+    },
+    matrix::Matrix,
+    ring::RingBase,
+};
+
+/// Left action of G on X
+pub trait Action<G, X> {
+    /// g · x
+    fn act(g: &G, x: &X) -> X;
+}
+
+/// Permutations act on sequences by moving the entry at position i to position σ(i)
+pub struct Permute;
+
+impl<T: Clone> Action<Permutation, Vec<T>> for Permute {
+    fn act(g: &Permutation, x: &Vec<T>) -> Vec<T> {
+        assert_eq!(g.len(), x.len(), "Permutation and sequence sizes differ");
+        let mut result = x.clone();
+        for (i, item) in x.iter().enumerate() {
+            result[g.apply(i)] = item.clone();
+        }
+        result
+    }
+}
+
+/// Permutations act on points by evaluation
+pub struct Evaluate;
+
+impl Action<Permutation, usize> for Evaluate {
+    fn act(g: &Permutation, x: &usize) -> usize {
+        g.apply(*x)
+    }
+}
+
+/// Linear maps act on vectors
+/// The general linear group GL_n(R) acting on R^n, or more generally matrices acting on column vectors.
+pub struct Linear;
+
+/// Matrices act on column vectors by multiplication
+// Bounds on owned arithmetic: reference bounds here make trait selection recurse through Matrix<Matrix<...>>
+impl<T> Action<Matrix<T>, Vec<T>> for Linear
+where
+    T: RingBase + Add<Output = T> + Mul<Output = T>,
+{
+    fn act(g: &Matrix<T>, x: &Vec<T>) -> Vec<T> {
+        assert_eq!(x.len(), g.cols(), "Vector length must match the columns");
+        (0..g.rows())
+            .map(|i| {
+                g.row(i)
+                    .iter()
+                    .zip(x)
+                    .fold(T::zero(), |acc, (a, v)| acc + a.clone() * v.clone())
+            })
+            .collect()
+    }
+}
+
+/// A group acts on itself (or on a module) by left multiplication
+/// e.g. the units of Fp acting on Fp.
+pub struct LeftMultiplication;
+
+impl<G, X> Action<G, X> for LeftMultiplication
+where
+    G: Clone + Mul<X, Output = X>,
+    X: Clone,
+{
+    fn act(g: &G, x: &X) -> X {
+        g.clone() * x.clone()
+    }
+}
+
+/// Power σ^k of the Frobenius automorphism σ: a -> a^p of GF(p^n)
+/// The Galois group of GF(p^n) over Fp is cyclic of order n, generated by σ, so k is stored reduced modulo n.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Frobenius {
+    power: u32,
+    degree: u32,
+}
+
+impl Frobenius {
+    /// σ^k in the Galois group of an extension of degree n
+    /// Panics if the degree is zero.
+    pub fn new(power: u64, degree: u32) -> Self {
+        assert!(degree > 0, "Extension degree must be positive");
+        Self {
+            power: (power % degree as u64) as u32,
+            degree,
+        }
+    }
+
+    /// Exponent k in [0, n)
+    pub fn power(&self) -> u32 {
+        self.power
+    }
+
+    /// Extension degree n, the order of the Galois group
+    pub fn degree(&self) -> u32 {
+        self.degree
+    }
+
+    /// Every power of σ, the whole Galois group
+    pub fn elements(degree: u32) -> impl Iterator<Item = Self> {
+        (0..degree as u64).map(move |k| Self::new(k, degree))
+    }
+}
+
+/// σ^j σ^k = σ^{j+k mod n}
+/// Panics for powers in Galois groups of different degrees.
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl Mul for Frobenius {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        assert_eq!(
+            self.degree, other.degree,
+            "Frobenius powers of different extension degrees"
+        );
+        Self::new(self.power as u64 + other.power as u64, self.degree)
+    }
+}
+
+/// The Galois group acts on the field by its automorphisms, σ^k · a = a^{p^k}
+pub struct Galois;
+
+impl<const P: u64, const N: usize, M: ExtensionModulus<P, N>> Action<Frobenius, FpExt<P, N, M>>
+    for Galois
+{
+    fn act(g: &Frobenius, x: &FpExt<P, N, M>) -> FpExt<P, N, M> {
+        assert_eq!(
+            g.degree as usize, N,
+            "Frobenius power of another extension degree"
+        );
+        (0..g.power).fold(*x, |a, _| a.frobenius())
+    }
+}
+
+impl<const N: u32> Action<Frobenius, Gf2e<N>> for Galois {
+    fn act(g: &Frobenius, x: &Gf2e<N>) -> Gf2e<N> {
+        assert_eq!(g.degree, N, "Frobenius power of another extension degree");
+        (0..g.power).fold(*x, |a, _| a.frobenius())
+    }
+}
+
+/// Orbit of x under the group generated by the given elements
+/// For a finite group, the orbit under the generated subgroup equals the closure under the generators.
+pub fn orbit<A, G, X>(generators: &[G], x: &X) -> Vec<X>
+where
+    A: Action<G, X>,
+    X: Clone + PartialEq,
+{
+    let mut orbit = vec![x.clone()];
+    let mut frontier = 0;
+    while frontier < orbit.len() {
+        for g in generators {
+            let y = A::act(g, &orbit[frontier]);
+            if !orbit.contains(&y) {
+                orbit.push(y);
+            }
+        }
+        frontier += 1;
+    }
+    orbit
+}
+
+/// Stabilizer of x: the elements of the given group that fix x
+pub fn stabilizer<A, G, X>(elements: &[G], x: &X) -> Vec<G>
+where
+    A: Action<G, X>,
+    G: Clone,
+    X: PartialEq,
+{
+    elements
+        .iter()
+        .filter(|g| A::act(g, x) == *x)
+        .cloned()
+        .collect()
+}
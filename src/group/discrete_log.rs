@@ -0,0 +1,45 @@
+//! Discrete Logarithm
+//! https://en.wikipedia.org/wiki/Baby-step_giant-step
+//! The exponent k with g^k = h in the cyclic group generated by g, by Shanks' baby-step giant-step algorithm.
+//! With m = ceil(sqrt(n)) for a multiple n of the order of g, write k = i m + j with 0 <= i, j < m,
+//! store the baby steps g^j and walk the giant steps h g^{-im} until one of them is stored,
+//! in O(sqrt(n)) group operations and memory.
+
+use std::{collections::HashMap, hash::Hash};
+
+use super::Group;
+use crate::magma::Associative;
+
+/// Smallest k with base^k = target, None if target is not a power of base
+/// The order must be a positive multiple of the order of base, like the order of the group.
+/// The operation cannot be inferred from the arguments, e.g. `baby_step_giant_step::<Multiplication, _>`.
+pub fn baby_step_giant_step<Op, G>(base: &G, target: &G, order: u64) -> Option<u64>
+where
+    Op: Associative,
+    G: Group<Op> + Eq + Hash,
+{
+    let m = match order.isqrt() {
+        0 => return None,
+        r if r * r < order => r + 1,
+        r => r,
+    };
+
+    // Baby steps g^j, keeping the smallest j for repeated values
+    let mut baby_steps = HashMap::with_capacity(m as usize);
+    let mut step = G::identity();
+    for j in 0..m {
+        baby_steps.entry(step.clone()).or_insert(j);
+        step = step.op(base.clone());
+    }
+
+    // Giant steps h g^{-im}
+    let giant = base.clone().power(-(m as i64));
+    let mut gamma = target.clone();
+    for i in 0..m {
+        if let Some(j) = baby_steps.get(&gamma) {
+            return Some(i * m + j);
+        }
+        gamma = gamma.op(giant.clone());
+    }
+    None
+}
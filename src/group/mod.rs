@@ -0,0 +1,50 @@
+//! Group Theory
+//! https://ncatlab.org/nlab/show/group
+//! A group (G, *) is a monoid in which every element has an inverse.
+//! Equivalently an associative loop: the quasigroup division a \ e gives the inverse of a.
+
+use crate::magma::{Associative, Commutative, Loop, Semigroup};
+
+pub mod action;
+
+pub mod discrete_log;
+
+pub mod multiplicative;
+
+pub mod permutation;
+
+/// Group (G, *)
+/// An associative loop, so every element has a two-sided inverse.
+pub trait Group<Op: Associative>: Loop<Op> + Semigroup<Op> + Clone {
+    /// Inverse a^{-1}, the solution of a * x = e
+    fn invert(self) -> Self {
+        self.left_div(Self::identity())
+    }
+
+    /// Power a^n using square and multiply, negative exponents through the inverse
+    fn power(self, n: i64) -> Self {
+        let mut base = match n < 0 {
+            true => self.invert(),
+            false => self,
+        };
+        let mut exp = n.unsigned_abs();
+        let mut result = Self::identity();
+        while exp > 0 {
+            if exp % 2 == 1 {
+                result = result.op(base.clone());
+            }
+            base = base.clone().op(base);
+            exp /= 2;
+        }
+        result
+    }
+}
+
+impl<Op: Associative, T: Loop<Op> + Semigroup<Op> + Clone> Group<Op> for T {}
+
+/// Abelian group (G, *)
+/// A group with a commutative operation. Implemented for every commutative operation,
+/// and by hand for groups whose operation is only commutative on their elements, like Fp^*.
+pub trait AbelianGroup<Op: Associative>: Group<Op> {}
+
+impl<Op: Associative + Commutative, T: Group<Op>> AbelianGroup<Op> for T {}
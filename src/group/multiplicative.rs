@@ -0,0 +1,153 @@
+//! Multiplicative Group of Fp
+//! https://en.wikipedia.org/wiki/Primitive_root_modulo_n
+//! The non-zero elements of Fp form a cyclic group Fp^* of order P - 1. A generator (primitive root) g
+//! reaches every element, the order of each element divides P - 1, and for every d dividing P - 1
+//! there is exactly one subgroup of order d, generated by g^{(P-1)/d}.
+//! Discrete logarithms in Fp^* are the hardness assumption behind Diffie-Hellman key exchange.
+
+use std::{
+    fmt::Display,
+    ops::{Div, Mul},
+};
+
+use super::{discrete_log::baby_step_giant_step, AbelianGroup};
+use crate::{
+    error::AlgebraError,
+    field::{finite_field::Fp, FieldBase},
+    magma::{Loop, Multiplication, Quasigroup},
+    number::{factorize, mult_order},
+    ring::RingBase,
+};
+
+/// Element of the multiplicative group Fp^*, a non-zero element of Fp
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FpUnit<const P: u64>(Fp<P>);
+
+impl<const P: u64> FpUnit<P> {
+    /// Order P - 1 of the group
+    pub const ORDER: u64 = P - 1;
+
+    /// Class of value modulo P
+    /// Panics if P divides the value, see `TryFrom<Fp<P>>`.
+    pub fn new(value: u64) -> Self {
+        Self::try_from(Fp::new(value)).expect("Zero is not a unit")
+    }
+
+    /// Underlying field element
+    pub fn element(&self) -> Fp<P> {
+        self.0
+    }
+
+    /// Inverse a^{-1} = a^{P-2}
+    pub fn inverse(&self) -> Self {
+        Self(self.0.inverse())
+    }
+
+    /// Every element of the group, 1, 2, ..., P - 1
+    pub fn elements() -> impl Iterator<Item = Self> {
+        (1..P).map(|n| Self(Fp::new(n)))
+    }
+
+    /// Smallest generator, the smallest g with g^{(P-1)/q} != 1 for every prime q dividing P - 1
+    pub fn generator() -> Self {
+        let factors = factorize(Self::ORDER);
+        Self::elements()
+            .find(|g| {
+                factors
+                    .iter()
+                    .all(|(q, _)| g.0.pow(Self::ORDER / q) != Fp::one())
+            })
+            .expect("Fp^* is cyclic")
+    }
+
+    /// Whether the element generates the whole group
+    pub fn is_generator(&self) -> bool {
+        self.order() == Self::ORDER
+    }
+
+    /// Order, the smallest k > 0 with a^k = 1
+    pub fn order(&self) -> u64 {
+        mult_order(self.0.value(), P).expect("Units are coprime to P")
+    }
+
+    /// Generator g^{(P-1)/d} of the subgroup of order d, None unless d divides P - 1
+    pub fn subgroup_generator(d: u64) -> Option<Self> {
+        (d > 0 && Self::ORDER.is_multiple_of(d))
+            .then(|| Self(Self::generator().0.pow(Self::ORDER / d)))
+    }
+
+    /// Cyclic subgroup generated by the element, 1, a, a^2, ..., a^{k-1} for its order k
+    pub fn subgroup(&self) -> Vec<Self> {
+        let mut elements = vec![Self(Fp::one())];
+        let mut power = *self;
+        while power.0 != Fp::one() {
+            elements.push(power);
+            power = power * *self;
+        }
+        elements
+    }
+
+    /// Discrete logarithm: the smallest k with base^k = self, None if self is not a power of base
+    /// Baby-step giant-step in the subgroup generated by base.
+    pub fn discrete_log(&self, base: &Self) -> Option<u64> {
+        baby_step_giant_step::<Multiplication, _>(base, self, base.order())
+    }
+}
+
+impl<const P: u64> TryFrom<Fp<P>> for FpUnit<P> {
+    type Error = AlgebraError;
+
+    fn try_from(a: Fp<P>) -> Result<Self, AlgebraError> {
+        match a == Fp::zero() {
+            true => Err(AlgebraError::NotInvertible),
+            false => Ok(Self(a)),
+        }
+    }
+}
+
+impl<const P: u64> From<FpUnit<P>> for Fp<P> {
+    fn from(a: FpUnit<P>) -> Self {
+        a.0
+    }
+}
+
+impl<const P: u64> Display for FpUnit<P> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl<const P: u64> Mul for FpUnit<P> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        Self(self.0 * other.0)
+    }
+}
+
+impl<const P: u64> Div for FpUnit<P> {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        Self(self.0 / other.0)
+    }
+}
+
+/// a x = b and y a = b are both solved by b / a
+impl<const P: u64> Quasigroup<Multiplication> for FpUnit<P> {
+    fn left_div(self, other: Self) -> Self {
+        other / self
+    }
+
+    fn right_div(self, other: Self) -> Self {
+        other / self
+    }
+}
+
+impl<const P: u64> Loop<Multiplication> for FpUnit<P> {
+    fn identity() -> Self {
+        Self(Fp::one())
+    }
+}
+
+impl<const P: u64> AbelianGroup<Multiplication> for FpUnit<P> {}
@@ -0,0 +1,86 @@
+//! Permutations
+//! Bijections of the set {0, 1, ..., n-1}, forming the symmetric group S_n under composition.
+
+use std::fmt::Display;
+
+/// Permutation of {0, 1, ..., n-1}
+/// Stored as the list of images: the permutation sends i to images[i].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Permutation {
+    images: Vec<usize>,
+}
+
+impl Permutation {
+    /// Permutation from its list of images
+    /// Panics if the images are not a rearrangement of 0..n.
+    pub fn new(images: Vec<usize>) -> Self {
+        let mut seen = vec![false; images.len()];
+        for &i in images.iter() {
+            assert!(
+                i < images.len() && !seen[i],
+                "Images must be a rearrangement of 0..{}",
+                images.len()
+            );
+            seen[i] = true;
+        }
+        Self { images }
+    }
+
+    /// Identity permutation of n points
+    pub fn identity(n: usize) -> Self {
+        Self {
+            images: (0..n).collect(),
+        }
+    }
+
+    /// Permutation of n points given as a product of disjoint cycles
+    /// e.g. `from_cycles(4, &[&[0, 2], &[1, 3]])` is (0 2)(1 3).
+    pub fn from_cycles(n: usize, cycles: &[&[usize]]) -> Self {
+        let mut images = (0..n).collect::<Vec<_>>();
+        for cycle in cycles {
+            for (k, &i) in cycle.iter().enumerate() {
+                images[i] = cycle[(k + 1) % cycle.len()];
+            }
+        }
+        Self::new(images)
+    }
+
+    /// Number of points
+    pub fn len(&self) -> usize {
+        self.images.len()
+    }
+
+    /// Check for the permutation of zero points
+    pub fn is_empty(&self) -> bool {
+        self.images.is_empty()
+    }
+
+    /// Image of a point
+    pub fn apply(&self, i: usize) -> usize {
+        self.images[i]
+    }
+
+    /// Composition self ∘ other: apply other first, then self
+    pub fn compose(&self, other: &Self) -> Self {
+        assert_eq!(self.len(), other.len(), "Permutations of different sizes");
+        Self {
+            images: other.images.iter().map(|&i| self.images[i]).collect(),
+        }
+    }
+
+    /// Inverse permutation
+    pub fn inverse(&self) -> Self {
+        let mut images = vec![0; self.len()];
+        for (i, &j) in self.images.iter().enumerate() {
+            images[j] = i;
+        }
+        Self { images }
+    }
+}
+
+/// Formats in one-line notation "[2, 0, 1]".
+impl Display for Permutation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self.images)
+    }
+}
@@ -0,0 +1,147 @@
+//! Lattice Theory
+//! https://ncatlab.org/nlab/show/lattice
+//! A lattice (L, ∧, ∨) is a partially ordered set in which every pair of elements has a greatest lower bound
+//! (meet, ∧) and a least upper bound (join, ∨). Equivalently, two semilattices on the same set linked by the absorption laws
+//! a ∧ (a ∨ b) = a and a ∨ (a ∧ b) = a.
+
+use std::collections::{BTreeSet, HashSet};
+use std::hash::Hash;
+
+use crate::number::gcd;
+
+/// Lattice
+pub trait Lattice: Sized + PartialEq {
+    /// Greatest lower bound a ∧ b
+    fn meet(&self, other: &Self) -> Self;
+
+    /// Least upper bound a ∨ b
+    fn join(&self, other: &Self) -> Self;
+
+    /// Partial order: a <= b iff a ∧ b = a
+    fn leq(&self, other: &Self) -> bool {
+        self.meet(other) == *self
+    }
+}
+
+/// Lattice with a least element
+pub trait BoundedBelow: Lattice {
+    /// Least element ⊥
+    fn bottom() -> Self;
+}
+
+/// Lattice with a greatest element
+pub trait BoundedAbove: Lattice {
+    /// Greatest element ⊤
+    fn top() -> Self;
+}
+
+/// Least common multiple
+/// Panics on overflow.
+pub fn lcm(a: u64, b: u64) -> u64 {
+    if a == 0 || b == 0 {
+        return 0;
+    }
+    (a / gcd(a, b))
+        .checked_mul(b)
+        .expect("Least common multiple overflow")
+}
+
+/// Divisibility lattice on the natural numbers
+/// a <= b iff a | b, with meet gcd and join lcm.
+/// 1 divides everything and everything divides 0, so they are the bottom and top elements.
+impl Lattice for u64 {
+    fn meet(&self, other: &Self) -> Self {
+        gcd(*self, *other)
+    }
+
+    fn join(&self, other: &Self) -> Self {
+        lcm(*self, *other)
+    }
+}
+
+impl BoundedBelow for u64 {
+    fn bottom() -> Self {
+        1
+    }
+}
+
+impl BoundedAbove for u64 {
+    fn top() -> Self {
+        0
+    }
+}
+
+/// Boolean lattice: false < true, with meet AND and join OR
+impl Lattice for bool {
+    fn meet(&self, other: &Self) -> Self {
+        *self && *other
+    }
+
+    fn join(&self, other: &Self) -> Self {
+        *self || *other
+    }
+}
+
+impl BoundedBelow for bool {
+    fn bottom() -> Self {
+        false
+    }
+}
+
+impl BoundedAbove for bool {
+    fn top() -> Self {
+        true
+    }
+}
+
+/// Subset lattice: a <= b iff a ⊆ b, with meet intersection and join union
+impl<T: Ord + Clone> Lattice for BTreeSet<T> {
+    fn meet(&self, other: &Self) -> Self {
+        self.intersection(other).cloned().collect()
+    }
+
+    fn join(&self, other: &Self) -> Self {
+        self.union(other).cloned().collect()
+    }
+
+    fn leq(&self, other: &Self) -> bool {
+        self.is_subset(other)
+    }
+}
+
+impl<T: Ord + Clone> BoundedBelow for BTreeSet<T> {
+    fn bottom() -> Self {
+        BTreeSet::new()
+    }
+}
+
+/// Subset lattice: a <= b iff a ⊆ b, with meet intersection and join union
+impl<T: Eq + Hash + Clone> Lattice for HashSet<T> {
+    fn meet(&self, other: &Self) -> Self {
+        self.intersection(other).cloned().collect()
+    }
+
+    fn join(&self, other: &Self) -> Self {
+        self.union(other).cloned().collect()
+    }
+
+    fn leq(&self, other: &Self) -> bool {
+        self.is_subset(other)
+    }
+}
+
+impl<T: Eq + Hash + Clone> BoundedBelow for HashSet<T> {
+    fn bottom() -> Self {
+        HashSet::new()
+    }
+}
+
+/// Meet of all elements, ⊤ for an empty sequence
+pub fn meet_all<L: BoundedAbove>(items: impl IntoIterator<Item = L>) -> L {
+    items.into_iter().fold(L::top(), |acc, x| acc.meet(&x))
+}
+
+/// Join of all elements, ⊥ for an empty sequence
+pub fn join_all<L: BoundedBelow>(items: impl IntoIterator<Item = L>) -> L {
+    items.into_iter().fold(L::bottom(), |acc, x| acc.join(&x))
+}
@@ -30,8 +30,24 @@ pub mod number;
 
 pub mod magma;
 
+pub mod matrix;
+
 pub mod ring;
 
 pub mod field;
 
+pub mod group;
+
+pub mod lattice;
+
 pub mod polynomial;
+
+pub mod coding;
+
+pub mod expression;
+
+pub mod verify;
+
+pub mod error;
+
+pub mod secret_sharing;
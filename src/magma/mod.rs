@@ -1,8 +1,17 @@
 //! Magma Algebra
 //! A magma (S, *) is a set S with a single binary operation *: S x S -> S.
 //! https://ncatlab.org/nlab/show/magma
+//!
+//! Further structures are obtained by imposing laws on the operation:
+//! - Quasigroup: left and right division are always possible, a * x = b and y * a = b have unique solutions
+//! - Loop: quasigroup with an identity element
+//! - Semigroup: associative magma
+//! - Band: semigroup where every element is idempotent, a * a = a
+//! - Semilattice: commutative band
 
-use std::ops::{Add, Sub};
+use std::ops::{Add, Mul, Neg, Sub};
+
+use crate::ring::RingBase;
 
 pub trait Operation {}
 
@@ -10,6 +19,8 @@ pub trait Commutative: Operation {}
 
 pub trait Associative: Operation {}
 
+pub trait Idempotent: Operation {}
+
 pub struct Addition;
 
 impl Operation for Addition {}
@@ -20,6 +31,28 @@ pub struct Subtraction;
 
 impl Operation for Subtraction {}
 
+/// Multiplication, not assumed commutative (matrices, quaternions)
+pub struct Multiplication;
+
+impl Operation for Multiplication {}
+impl Associative for Multiplication {}
+
+/// Minimum of two elements of a totally ordered set
+pub struct Min;
+
+impl Operation for Min {}
+impl Commutative for Min {}
+impl Associative for Min {}
+impl Idempotent for Min {}
+
+/// Maximum of two elements of a totally ordered set
+pub struct Max;
+
+impl Operation for Max {}
+impl Commutative for Max {}
+impl Associative for Max {}
+impl Idempotent for Max {}
+
 /// Magma (S, *)
 /// The magma trait is implemented by a type S representing the set and is generic over the operation *.
 pub trait Magma<Op: Operation> {
@@ -43,3 +76,98 @@ where
         self - other
     }
 }
+
+impl<T> Magma<Multiplication> for T
+where
+    T: Mul<Output = T>,
+{
+    fn op(self, other: Self) -> Self {
+        self * other
+    }
+}
+
+impl<T: Ord> Magma<Min> for T {
+    fn op(self, other: Self) -> Self {
+        self.min(other)
+    }
+}
+
+impl<T: Ord> Magma<Max> for T {
+    fn op(self, other: Self) -> Self {
+        self.max(other)
+    }
+}
+
+/// Quasigroup (S, *)
+/// A magma with left and right division.
+pub trait Quasigroup<Op: Operation>: Magma<Op> {
+    /// Left division a \ b: the unique x such that a * x = b
+    fn left_div(self, other: Self) -> Self;
+
+    /// Right division b / a: the unique y such that y * a = b
+    fn right_div(self, other: Self) -> Self;
+}
+
+/// a + x = b and y + a = b are both solved by b - a
+/// Bounded on negation so the unsigned integers, where b - a underflows, are left out.
+impl<T> Quasigroup<Addition> for T
+where
+    T: Add<Output = T> + Sub<Output = T> + Neg<Output = T>,
+{
+    fn left_div(self, other: Self) -> Self {
+        other - self
+    }
+
+    fn right_div(self, other: Self) -> Self {
+        other - self
+    }
+}
+
+/// a - x = b is solved by a - b, and y - a = b by b + a
+/// Subtraction is the standard example of a non-associative quasigroup.
+impl<T> Quasigroup<Subtraction> for T
+where
+    T: Add<Output = T> + Sub<Output = T> + Neg<Output = T>,
+{
+    fn left_div(self, other: Self) -> Self {
+        self - other
+    }
+
+    fn right_div(self, other: Self) -> Self {
+        other + self
+    }
+}
+
+/// Loop (S, *)
+/// A quasigroup with a two-sided identity element.
+pub trait Loop<Op: Operation>: Quasigroup<Op> {
+    /// Identity element e, with e * a = a * e = a
+    fn identity() -> Self;
+}
+
+impl<T> Loop<Addition> for T
+where
+    T: RingBase + Add<Output = T> + Sub<Output = T> + Neg<Output = T>,
+{
+    fn identity() -> Self {
+        T::zero()
+    }
+}
+
+/// Semigroup (S, *)
+/// A magma with an associative operation.
+pub trait Semigroup<Op: Associative>: Magma<Op> {}
+
+impl<Op: Associative, T: Magma<Op>> Semigroup<Op> for T {}
+
+/// Band (S, *)
+/// A semigroup in which every element is idempotent.
+pub trait Band<Op: Associative + Idempotent>: Semigroup<Op> {}
+
+impl<Op: Associative + Idempotent, T: Semigroup<Op>> Band<Op> for T {}
+
+/// Semilattice (S, *)
+/// A commutative band. The operation defines a partial order by a <= b iff a * b = a.
+pub trait Semilattice<Op: Associative + Commutative + Idempotent>: Band<Op> {}
+
+impl<Op: Associative + Commutative + Idempotent, T: Band<Op>> Semilattice<Op> for T {}
@@ -0,0 +1,139 @@
+//! Block Matrices
+//! Assembling matrices from blocks and borrowing rectangular sub-blocks without copying.
+
+use std::ops::{Index, Range};
+
+use super::Matrix;
+use crate::ring::RingBase;
+
+/// Borrowed rectangular block of a matrix
+#[derive(Debug, Clone, Copy)]
+pub struct MatrixView<'a, T> {
+    matrix: &'a Matrix<T>,
+    row_offset: usize,
+    col_offset: usize,
+    rows: usize,
+    cols: usize,
+}
+
+impl<T: RingBase> MatrixView<'_, T> {
+    /// Number of rows
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Number of columns
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Row of the block as a slice
+    pub fn row(&self, i: usize) -> &[T] {
+        assert!(i < self.rows, "Index out of bounds");
+        &self.matrix.row(self.row_offset + i)[self.col_offset..self.col_offset + self.cols]
+    }
+
+    /// Copy the block into an owned matrix
+    pub fn to_matrix(&self) -> Matrix<T> {
+        Matrix::from_fn(self.rows, self.cols, |i, j| self[(i, j)].clone())
+    }
+}
+
+impl<T> Index<(usize, usize)> for MatrixView<'_, T> {
+    type Output = T;
+
+    fn index(&self, (i, j): (usize, usize)) -> &T {
+        assert!(i < self.rows && j < self.cols, "Index out of bounds");
+        &self.matrix[(self.row_offset + i, self.col_offset + j)]
+    }
+}
+
+impl<T: RingBase> Matrix<T> {
+    /// View of the block with the given row and column ranges
+    pub fn block(&self, rows: Range<usize>, cols: Range<usize>) -> MatrixView<'_, T> {
+        assert!(
+            rows.start <= rows.end && rows.end <= self.rows,
+            "Row range out of bounds"
+        );
+        assert!(
+            cols.start <= cols.end && cols.end <= self.cols,
+            "Column range out of bounds"
+        );
+        MatrixView {
+            matrix: self,
+            row_offset: rows.start,
+            col_offset: cols.start,
+            rows: rows.len(),
+            cols: cols.len(),
+        }
+    }
+
+    /// Overwrite the block whose top-left corner is at (row, col)
+    pub fn set_block(&mut self, row: usize, col: usize, block: &Matrix<T>) {
+        assert!(
+            row + block.rows <= self.rows && col + block.cols <= self.cols,
+            "Block out of bounds"
+        );
+        for i in 0..block.rows {
+            for j in 0..block.cols {
+                self[(row + i, col + j)] = block[(i, j)].clone();
+            }
+        }
+    }
+
+    /// Assemble a matrix from a grid of blocks
+    /// Blocks in the same block-row must have the same number of rows,
+    /// and blocks in the same block-column the same number of columns.
+    /// e.g. `from_blocks(&[&[&a, &b], &[&c, &d]])` is [[A, B], [C, D]].
+    pub fn from_blocks(blocks: &[&[&Matrix<T>]]) -> Self {
+        let block_cols = blocks.first().map_or(0, |row| row.len());
+        assert!(
+            blocks.iter().all(|row| row.len() == block_cols),
+            "Every block-row must have the same number of blocks"
+        );
+
+        let heights = blocks
+            .iter()
+            .map(|row| {
+                let height = row.first().map_or(0, |b| b.rows);
+                assert!(
+                    row.iter().all(|b| b.rows == height),
+                    "Blocks in a block-row must have the same number of rows"
+                );
+                height
+            })
+            .collect::<Vec<_>>();
+        let widths = (0..block_cols)
+            .map(|j| {
+                let width = blocks[0][j].cols;
+                assert!(
+                    blocks.iter().all(|row| row[j].cols == width),
+                    "Blocks in a block-column must have the same number of columns"
+                );
+                width
+            })
+            .collect::<Vec<_>>();
+
+        let mut result = Self::zeros(heights.iter().sum(), widths.iter().sum());
+        let mut row = 0;
+        for (block_row, height) in blocks.iter().zip(&heights) {
+            let mut col = 0;
+            for (block, width) in block_row.iter().zip(&widths) {
+                result.set_block(row, col, block);
+                col += width;
+            }
+            row += height;
+        }
+        result
+    }
+
+    /// Horizontal concatenation [A | B]
+    pub fn concat_horizontal(&self, other: &Self) -> Self {
+        Self::from_blocks(&[&[self, other]])
+    }
+
+    /// Vertical concatenation [A / B]
+    pub fn concat_vertical(&self, other: &Self) -> Self {
+        Self::from_blocks(&[&[self], &[other]])
+    }
+}
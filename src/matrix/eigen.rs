@@ -0,0 +1,189 @@
+//! Numerical Eigenvalues
+//! Eigenvalues of real dense matrices with the QR algorithm:
+//! Householder reduction to upper Hessenberg form followed by Francis double-shift QR iterations.
+//! Complex eigenvalues come in conjugate pairs, obtained from the 2 x 2 blocks of the real Schur form.
+
+use super::Matrix;
+use crate::number::Complex;
+
+/// Householder reflector P = I - beta v v^T with P u = -+|u| e_1
+/// Returns None if u is already zero.
+fn householder(u: &[f64]) -> Option<(Vec<f64>, f64)> {
+    let norm = u.iter().map(|x| x * x).sum::<f64>().sqrt();
+    if norm == 0.0 {
+        return None;
+    }
+    let mut v = u.to_vec();
+    v[0] += norm.copysign(u[0]);
+    let beta = 2.0 / v.iter().map(|x| x * x).sum::<f64>();
+    Some((v, beta))
+}
+
+impl Matrix<f64> {
+    /// Apply a reflector from the left to rows k..k+len(v), columns in cols
+    fn reflect_rows(
+        &mut self,
+        k: usize,
+        v: &[f64],
+        beta: f64,
+        cols: std::ops::RangeInclusive<usize>,
+    ) {
+        for j in cols {
+            let dot = v
+                .iter()
+                .enumerate()
+                .map(|(l, vl)| vl * self[(k + l, j)])
+                .sum::<f64>();
+            for (l, vl) in v.iter().enumerate() {
+                self[(k + l, j)] -= beta * vl * dot;
+            }
+        }
+    }
+
+    /// Apply a reflector from the right to columns k..k+len(v), rows in rows
+    fn reflect_cols(
+        &mut self,
+        k: usize,
+        v: &[f64],
+        beta: f64,
+        rows: std::ops::RangeInclusive<usize>,
+    ) {
+        for i in rows {
+            let dot = v
+                .iter()
+                .enumerate()
+                .map(|(l, vl)| vl * self[(i, k + l)])
+                .sum::<f64>();
+            for (l, vl) in v.iter().enumerate() {
+                self[(i, k + l)] -= beta * vl * dot;
+            }
+        }
+    }
+
+    /// Upper Hessenberg form
+    /// Orthogonally similar to the original matrix, with zeros below the first subdiagonal.
+    pub fn hessenberg(&self) -> Self {
+        assert!(self.is_square(), "Hessenberg form of a non-square matrix");
+        let n = self.rows;
+        let mut h = self.clone();
+        for k in 0..n.saturating_sub(2) {
+            let u = (k + 1..n).map(|i| h[(i, k)]).collect::<Vec<_>>();
+            if let Some((v, beta)) = householder(&u) {
+                h.reflect_rows(k + 1, &v, beta, 0..=n - 1);
+                h.reflect_cols(k + 1, &v, beta, 0..=n - 1);
+            }
+            for i in k + 2..n {
+                h[(i, k)] = 0.0;
+            }
+        }
+        h
+    }
+
+    /// Eigenvalues, in no particular order, repeated according to their algebraic multiplicity
+    /// Complex eigenvalues appear together with their conjugates.
+    /// Returns None if the QR iterations fail to converge.
+    pub fn eigenvalues(&self) -> Option<Vec<Complex>> {
+        assert!(self.is_square(), "Eigenvalues of a non-square matrix");
+        let n = self.rows;
+        let mut h = self.hessenberg();
+        let mut eigenvalues = Vec::with_capacity(n);
+
+        // Absolute floor for negligible entries, for blocks whose diagonal is zero or underflows
+        let norm = h.data.iter().fold(0.0f64, |acc, x| acc.max(x.abs()));
+        let floor = (f64::EPSILON * norm).max(f64::MIN_POSITIVE * n as f64 / f64::EPSILON);
+
+        // Active window is h[lo..=hi, lo..=hi], everything below hi is already deflated
+        let mut hi = n as isize - 1;
+        let mut iterations = 0;
+        while hi >= 0 {
+            let hi_u = hi as usize;
+
+            // Look for a negligible subdiagonal entry to split the problem
+            let mut lo = hi_u;
+            while lo > 0 {
+                let scale = h[(lo - 1, lo - 1)].abs() + h[(lo, lo)].abs();
+                if h[(lo, lo - 1)].abs() <= (f64::EPSILON * scale).max(floor) {
+                    h[(lo, lo - 1)] = 0.0;
+                    break;
+                }
+                lo -= 1;
+            }
+
+            // A 1 x 1 block is a real eigenvalue
+            if lo == hi_u {
+                eigenvalues.push(Complex::from(h[(hi_u, hi_u)]));
+                hi -= 1;
+                iterations = 0;
+                continue;
+            }
+
+            // A 2 x 2 block gives a real pair or a conjugate pair
+            if lo + 1 == hi_u {
+                let (a, b) = (h[(lo, lo)], h[(lo, hi_u)]);
+                let (c, d) = (h[(hi_u, lo)], h[(hi_u, hi_u)]);
+                let mean = (a + d) / 2.0;
+                let disc = (a - d) * (a - d) / 4.0 + b * c;
+                if disc >= 0.0 {
+                    // Avoid cancellation: compute the larger root first
+                    let root = mean + disc.sqrt().copysign(mean);
+                    let other = if root != 0.0 {
+                        (a * d - b * c) / root
+                    } else {
+                        0.0
+                    };
+                    eigenvalues.push(Complex::from(root));
+                    eigenvalues.push(Complex::from(other));
+                } else {
+                    let im = (-disc).sqrt();
+                    eigenvalues.push(Complex::new(mean, im));
+                    eigenvalues.push(Complex::new(mean, -im));
+                }
+                hi -= 2;
+                iterations = 0;
+                continue;
+            }
+
+            iterations += 1;
+            if iterations > 30 * n {
+                return None;
+            }
+
+            // Double shift by the eigenvalues of the trailing 2 x 2 block, with exceptional shifts to break cycles
+            let (s, t) = if iterations % 10 == 0 {
+                let w = h[(hi_u, hi_u - 1)].abs() + h[(hi_u - 1, hi_u - 2)].abs();
+                (1.5 * w + h[(hi_u, hi_u)], w * w)
+            } else {
+                let (a, b) = (h[(hi_u - 1, hi_u - 1)], h[(hi_u - 1, hi_u)]);
+                let (c, d) = (h[(hi_u, hi_u - 1)], h[(hi_u, hi_u)]);
+                (a + d, a * d - b * c)
+            };
+
+            // First column of (H - s1 I)(H - s2 I) = H^2 - s H + t I
+            let mut x =
+                h[(lo, lo)] * h[(lo, lo)] + h[(lo, lo + 1)] * h[(lo + 1, lo)] - s * h[(lo, lo)] + t;
+            let mut y = h[(lo + 1, lo)] * (h[(lo, lo)] + h[(lo + 1, lo + 1)] - s);
+            let mut z = h[(lo + 1, lo)] * h[(lo + 2, lo + 1)];
+
+            // Chase the bulge down the subdiagonal
+            for k in lo..hi_u - 1 {
+                if let Some((v, beta)) = householder(&[x, y, z]) {
+                    let first_col = if k > lo { k - 1 } else { lo };
+                    h.reflect_rows(k, &v, beta, first_col..=hi_u);
+                    h.reflect_cols(k, &v, beta, lo..=(k + 3).min(hi_u));
+                }
+                x = h[(k + 1, k)];
+                y = h[(k + 2, k)];
+                if k + 3 <= hi_u {
+                    z = h[(k + 3, k)];
+                }
+            }
+            let k = hi_u - 1;
+            if let Some((v, beta)) = householder(&[x, y]) {
+                h.reflect_rows(k, &v, beta, k - 1..=hi_u);
+                h.reflect_cols(k, &v, beta, lo..=hi_u);
+            }
+        }
+
+        Some(eigenvalues)
+    }
+}
@@ -0,0 +1,199 @@
+//! Matrices over Euclidean Domains
+//! Fraction-free elimination and the Smith normal form for matrices over the integers or K[x].
+
+use std::ops::{Add, Mul, Neg, Sub};
+
+use super::Matrix;
+use crate::{field::FieldBase, polynomial::Polynomial, ring::euclidean::EuclideanDomain};
+
+/// Exact quotient a / b in an integral domain
+/// In the Bareiss algorithm every entry is a minor of the input and Sylvester's identity makes b divide a.
+/// Panics if the remainder is nonzero, which means the ring is not an integral domain or its division is broken.
+fn exact_div<T: EuclideanDomain>(a: &T, b: &T) -> T {
+    let (q, r) = a.div_rem(b);
+    assert!(
+        r == T::zero(),
+        "Inexact division in fraction-free elimination"
+    );
+    q
+}
+
+impl<T> Matrix<T>
+where
+    T: EuclideanDomain,
+    for<'a> &'a T: Add<&'a T, Output = T>,
+    for<'a> &'a T: Sub<&'a T, Output = T>,
+    for<'a> &'a T: Mul<&'a T, Output = T>,
+{
+    /// Fraction-free row echelon form using the Bareiss algorithm
+    /// https://en.wikipedia.org/wiki/Bareiss_algorithm
+    /// Every division is exact, so the entries stay in the ring and grow only like minors of the matrix.
+    /// Returns the echelon form, the pivot columns and whether an odd number of row swaps was performed.
+    fn bareiss(&self) -> (Self, Vec<usize>, bool) {
+        let mut m = self.clone();
+        let mut pivots = vec![];
+        let mut odd_swaps = false;
+        let mut prev = T::one();
+
+        for c in 0..m.cols {
+            let r = pivots.len();
+            let Some(p) = (r..m.rows).find(|&i| m[(i, c)] != T::zero()) else {
+                continue;
+            };
+            if p != r {
+                m.swap_rows(p, r);
+                odd_swaps = !odd_swaps;
+            }
+
+            // M_ij <- (M_rc M_ij - M_ic M_rj) / previous pivot
+            for i in r + 1..m.rows {
+                for j in c + 1..m.cols {
+                    let num = &(&m[(r, c)] * &m[(i, j)]) - &(&m[(i, c)] * &m[(r, j)]);
+                    m[(i, j)] = exact_div(&num, &prev);
+                }
+                m[(i, c)] = T::zero();
+            }
+
+            prev = m[(r, c)].clone();
+            pivots.push(c);
+        }
+
+        (m, pivots, odd_swaps)
+    }
+
+    /// Determinant
+    /// Computed by fraction-free elimination, so no division leaves the ring.
+    /// Panics if the matrix is not square.
+    pub fn determinant(&self) -> T {
+        assert!(self.is_square(), "Determinant of a non-square matrix");
+        if self.rows == 0 {
+            return T::one();
+        }
+        let (m, pivots, odd_swaps) = self.bareiss();
+        if pivots.len() < self.rows {
+            return T::zero();
+        }
+        // The last Bareiss pivot is the determinant
+        let det = m[(self.rows - 1, self.cols - 1)].clone();
+        if odd_swaps {
+            &T::zero() - &det
+        } else {
+            det
+        }
+    }
+
+    /// Rank over the field of fractions
+    pub fn rank(&self) -> usize {
+        self.bareiss().1.len()
+    }
+
+    /// Smith normal form
+    /// https://en.wikipedia.org/wiki/Smith_normal_form
+    /// The unique diagonal matrix D = U A V, with U and V invertible, whose diagonal entries d_1 | d_2 | ... | d_r
+    /// are normalized and followed by zeros.
+    pub fn smith_normal_form(&self) -> Self {
+        let mut m = self.clone();
+        let size = m.rows.min(m.cols);
+
+        for t in 0..size {
+            loop {
+                // Move the non-zero entry of least size to the pivot position
+                let pivot = (t..m.rows)
+                    .flat_map(|i| (t..m.cols).map(move |j| (i, j)))
+                    .filter(|&(i, j)| m[(i, j)] != T::zero())
+                    .min_by_key(|&(i, j)| m[(i, j)].euclidean_size());
+                let Some((pi, pj)) = pivot else {
+                    return m;
+                };
+                m.swap_rows(t, pi);
+                m.swap_cols(t, pj);
+
+                // Clear the pivot column and row, remainders have smaller size than the pivot
+                let mut cleared = true;
+                for i in t + 1..m.rows {
+                    let (q, r) = m[(i, t)].div_rem(&m[(t, t)]);
+                    for j in t..m.cols {
+                        m[(i, j)] = &m[(i, j)] - &(&q * &m[(t, j)]);
+                    }
+                    cleared &= r == T::zero();
+                }
+                for j in t + 1..m.cols {
+                    let (q, r) = m[(t, j)].div_rem(&m[(t, t)]);
+                    for i in t..m.rows {
+                        m[(i, j)] = &m[(i, j)] - &(&q * &m[(i, t)]);
+                    }
+                    cleared &= r == T::zero();
+                }
+                if !cleared {
+                    continue;
+                }
+
+                // The pivot must divide every remaining entry, otherwise bring the offending row in
+                let offending = (t + 1..m.rows).find(|&i| {
+                    (t + 1..m.cols).any(|j| m[(i, j)].div_rem(&m[(t, t)]).1 != T::zero())
+                });
+                match offending {
+                    Some(i) => {
+                        for j in t..m.cols {
+                            m[(t, j)] = &m[(t, j)] + &m[(i, j)];
+                        }
+                    }
+                    None => break,
+                }
+            }
+            m[(t, t)] = m[(t, t)].normalize();
+        }
+
+        m
+    }
+
+    /// Invariant factors: the non-zero diagonal entries d_1 | d_2 | ... | d_r of the Smith normal form
+    pub fn invariant_factors(&self) -> Vec<T> {
+        let snf = self.smith_normal_form();
+        (0..snf.rows.min(snf.cols))
+            .map(|i| snf[(i, i)].clone())
+            .filter(|d| *d != T::zero())
+            .collect()
+    }
+}
+
+impl<T> Matrix<T>
+where
+    T: FieldBase + Copy + Neg<Output = T> + Mul<Output = T> + Sub<Output = T>,
+    Polynomial<T>: EuclideanDomain,
+    for<'a> &'a Polynomial<T>: Add<&'a Polynomial<T>, Output = Polynomial<T>>,
+    for<'a> &'a Polynomial<T>: Sub<&'a Polynomial<T>, Output = Polynomial<T>>,
+    for<'a> &'a Polynomial<T>: Mul<&'a Polynomial<T>, Output = Polynomial<T>>,
+{
+    /// Characteristic matrix x I - A over K[x]
+    pub fn characteristic_matrix(&self) -> Matrix<Polynomial<T>> {
+        assert!(
+            self.is_square(),
+            "Characteristic matrix of a non-square matrix"
+        );
+        Matrix::from_fn(self.rows, self.cols, |i, j| {
+            if i == j {
+                Polynomial::from_coeffs(vec![-self[(i, j)], T::one()])
+            } else {
+                Polynomial::from_coeffs(vec![-self[(i, j)]])
+            }
+        })
+    }
+
+    /// Characteristic polynomial det(x I - A)
+    pub fn characteristic_polynomial(&self) -> Polynomial<T> {
+        self.characteristic_matrix().determinant()
+    }
+
+    /// Similarity invariants: the non-constant invariant factors of x I - A
+    /// Two matrices are similar iff they have the same similarity invariants. Their product is the
+    /// characteristic polynomial, the last one is the minimal polynomial, and they are the companion blocks
+    /// of the rational canonical form.
+    pub fn similarity_invariants(&self) -> Vec<Polynomial<T>> {
+        self.characteristic_matrix()
+            .invariant_factors()
+            .into_iter()
+            .filter(|d| d.degree().is_some_and(|deg| deg > 0))
+            .collect()
+    }
+}
@@ -0,0 +1,100 @@
+//! Gaussian Elimination
+//! https://en.wikipedia.org/wiki/Gaussian_elimination
+//! Linear algebra for matrices with entries in a field: echelon forms, null spaces, inverses and linear systems.
+//! Determinant and rank come from the fraction-free elimination of the Euclidean domain impl, which the fields
+//! Fp, FpMont, Gf2e, FpExt and Rational implement with exact division. Zn is only a field for prime n, so its
+//! matrices use the division-free `berkowitz_determinant` instead.
+
+use std::ops::{Mul, Sub};
+
+use super::Matrix;
+use crate::field::FieldBase;
+
+impl<T> Matrix<T>
+where
+    T: FieldBase + Copy + Sub<Output = T> + Mul<Output = T>,
+{
+    /// Reduced row echelon form
+    /// Returns the reduced matrix and the pivot column of each non-zero row.
+    pub fn row_reduce(&self) -> (Self, Vec<usize>) {
+        let mut m = self.clone();
+        let mut pivots = Vec::new();
+        for col in 0..m.cols {
+            let r = pivots.len();
+            let Some(pivot) = (r..m.rows).find(|&i| m[(i, col)] != T::zero()) else {
+                continue;
+            };
+            m.swap_rows(r, pivot);
+
+            // Scale the pivot row to a leading one
+            let inv = m[(r, col)].inverse();
+            for j in col..m.cols {
+                m[(r, j)] = m[(r, j)] * inv;
+            }
+
+            // Clear the column above and below the pivot
+            for i in (0..m.rows).filter(|&i| i != r) {
+                let factor = m[(i, col)];
+                if factor != T::zero() {
+                    for j in col..m.cols {
+                        m[(i, j)] = m[(i, j)] - factor * m[(r, j)];
+                    }
+                }
+            }
+            pivots.push(col);
+        }
+        (m, pivots)
+    }
+
+    /// Basis of the null space {v : A v = 0}
+    /// Returned as the rows of a matrix with `cols` columns, one for each free column of the echelon form.
+    pub fn null_space(&self) -> Self {
+        let (reduced, pivots) = self.row_reduce();
+        let free = (0..self.cols)
+            .filter(|c| pivots.binary_search(c).is_err())
+            .collect::<Vec<_>>();
+
+        // Setting a free variable to one forces x_p = -a_{r,f} for the pivot p of each row r
+        let mut basis = Self::zeros(free.len(), self.cols);
+        for (k, &f) in free.iter().enumerate() {
+            basis[(k, f)] = T::one();
+            for (r, &p) in pivots.iter().enumerate() {
+                basis[(k, p)] = T::zero() - reduced[(r, f)];
+            }
+        }
+        basis
+    }
+
+    /// Inverse matrix, None if the matrix is singular
+    /// Row reduces [A | I] to [I | A^{-1}].
+    /// Panics if the matrix is not square.
+    pub fn inverse(&self) -> Option<Self> {
+        assert!(self.is_square(), "Inverse of a non-square matrix");
+        let n = self.rows;
+        let (reduced, pivots) = self.concat_horizontal(&Self::identity(n)).row_reduce();
+        // Invertible iff the left half reduces to I
+        if pivots.iter().filter(|&&p| p < n).count() < n {
+            return None;
+        }
+        Some(Self::from_fn(n, n, |i, j| reduced[(i, n + j)]))
+    }
+
+    /// A solution x of A x = b, None if the system is inconsistent
+    /// Free variables are set to zero, every solution is x plus a vector of the null space.
+    /// Panics if b does not have one entry per row.
+    pub fn solve(&self, b: &[T]) -> Option<Vec<T>> {
+        assert_eq!(b.len(), self.rows, "Right-hand side length must match rows");
+        let column = Self::from_fn(self.rows, 1, |i, _| b[i]);
+        let (reduced, pivots) = self.concat_horizontal(&column).row_reduce();
+
+        // A pivot in the last column is the equation 0 = 1
+        if pivots.last() == Some(&self.cols) {
+            return None;
+        }
+        let mut x = vec![T::zero(); self.cols];
+        for (r, &p) in pivots.iter().enumerate() {
+            x[p] = reduced[(r, self.cols)];
+        }
+        Some(x)
+    }
+}
@@ -0,0 +1,228 @@
+//! Matrices over GF(2)
+//! Bit-packed dense matrices over the two-element field, 64 entries per machine word.
+//! Addition of rows is a word-wise XOR, which makes Gaussian elimination fast enough
+//! for the large sparse systems met in sieve factorization methods.
+
+use std::fmt::Display;
+
+use super::Matrix;
+use crate::{field::finite_field::Fp, ring::RingBase};
+
+const WORD: usize = u64::BITS as usize;
+
+/// Dense bit-packed matrix over GF(2)
+/// Each row is stored as ceil(cols / 64) words, unused trailing bits are always zero.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BitMatrix {
+    rows: usize,
+    cols: usize,
+    words_per_row: usize,
+    data: Vec<u64>,
+}
+
+impl BitMatrix {
+    /// Zero matrix
+    pub fn zeros(rows: usize, cols: usize) -> Self {
+        let words_per_row = cols.div_ceil(WORD);
+        Self {
+            rows,
+            cols,
+            words_per_row,
+            data: vec![0; rows * words_per_row],
+        }
+    }
+
+    /// Identity matrix
+    pub fn identity(n: usize) -> Self {
+        let mut matrix = Self::zeros(n, n);
+        for i in 0..n {
+            matrix.set(i, i, true);
+        }
+        matrix
+    }
+
+    /// New matrix with entries given by a function of the position
+    pub fn from_fn(rows: usize, cols: usize, f: impl Fn(usize, usize) -> bool) -> Self {
+        let mut matrix = Self::zeros(rows, cols);
+        for i in 0..rows {
+            for j in 0..cols {
+                matrix.set(i, j, f(i, j));
+            }
+        }
+        matrix
+    }
+
+    /// Number of rows
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Number of columns
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Packed words of a row
+    pub fn row_words(&self, i: usize) -> &[u64] {
+        &self.data[i * self.words_per_row..(i + 1) * self.words_per_row]
+    }
+
+    /// Entry at (i, j)
+    pub fn get(&self, i: usize, j: usize) -> bool {
+        assert!(i < self.rows && j < self.cols, "Index out of bounds");
+        (self.data[i * self.words_per_row + j / WORD] >> (j % WORD)) & 1 == 1
+    }
+
+    /// Set the entry at (i, j)
+    pub fn set(&mut self, i: usize, j: usize, value: bool) {
+        assert!(i < self.rows && j < self.cols, "Index out of bounds");
+        let word = &mut self.data[i * self.words_per_row + j / WORD];
+        let mask = 1 << (j % WORD);
+        if value {
+            *word |= mask;
+        } else {
+            *word &= !mask;
+        }
+    }
+
+    /// Add one to the entry at (i, j)
+    pub fn flip(&mut self, i: usize, j: usize) {
+        assert!(i < self.rows && j < self.cols, "Index out of bounds");
+        self.data[i * self.words_per_row + j / WORD] ^= 1 << (j % WORD);
+    }
+
+    /// Column indices of the non-zero entries of a row
+    pub fn row_ones(&self, i: usize) -> impl Iterator<Item = usize> + '_ {
+        self.row_words(i).iter().enumerate().flat_map(|(w, &word)| {
+            let mut word = word;
+            std::iter::from_fn(move || {
+                (word != 0).then(|| {
+                    let bit = word.trailing_zeros() as usize;
+                    word &= word - 1;
+                    w * WORD + bit
+                })
+            })
+        })
+    }
+
+    /// Add row `src` to row `dst`
+    fn add_row(&mut self, src: usize, dst: usize) {
+        let w = self.words_per_row;
+        let (src, dst) = (src * w, dst * w);
+        for k in 0..w {
+            self.data[dst + k] ^= self.data[src + k];
+        }
+    }
+
+    /// Swap two rows
+    fn swap_rows(&mut self, a: usize, b: usize) {
+        let w = self.words_per_row;
+        for k in 0..w {
+            self.data.swap(a * w + k, b * w + k);
+        }
+    }
+
+    /// Transposed matrix
+    pub fn transpose(&self) -> Self {
+        let mut transposed = Self::zeros(self.cols, self.rows);
+        for i in 0..self.rows {
+            for j in self.row_ones(i) {
+                transposed.set(j, i, true);
+            }
+        }
+        transposed
+    }
+
+    /// Reduced row echelon form in place
+    /// Returns the pivot column of each non-zero row, in order.
+    pub fn row_reduce(&mut self) -> Vec<usize> {
+        let mut pivots = Vec::new();
+        for col in 0..self.cols {
+            let r = pivots.len();
+            let Some(pivot) = (r..self.rows).find(|&i| self.get(i, col)) else {
+                continue;
+            };
+            self.swap_rows(r, pivot);
+            for i in 0..self.rows {
+                if i != r && self.get(i, col) {
+                    self.add_row(r, i);
+                }
+            }
+            pivots.push(col);
+        }
+        pivots
+    }
+
+    /// Rank
+    pub fn rank(&self) -> usize {
+        self.clone().row_reduce().len()
+    }
+
+    /// Basis of the null space {v : A v = 0}
+    /// Returned as the rows of a matrix with `cols` columns, one for each free column of the echelon form.
+    pub fn null_space(&self) -> Self {
+        let mut reduced = self.clone();
+        let pivots = reduced.row_reduce();
+        let free = (0..self.cols)
+            .filter(|c| pivots.binary_search(c).is_err())
+            .collect::<Vec<_>>();
+
+        // Setting a free variable to one forces the pivot variables of the rows containing it
+        let mut basis = Self::zeros(free.len(), self.cols);
+        for (k, &f) in free.iter().enumerate() {
+            basis.set(k, f, true);
+            for (r, &p) in pivots.iter().enumerate() {
+                if reduced.get(r, f) {
+                    basis.set(k, p, true);
+                }
+            }
+        }
+        basis
+    }
+
+    /// Product A v of the matrix with a bit vector given as packed words
+    pub fn mul_vector(&self, v: &[u64]) -> Vec<bool> {
+        assert_eq!(v.len(), self.words_per_row, "Dimension mismatch");
+        (0..self.rows)
+            .map(|i| {
+                self.row_words(i)
+                    .iter()
+                    .zip(v)
+                    .fold(0, |acc, (a, b)| acc ^ (a & b).count_ones())
+                    % 2
+                    == 1
+            })
+            .collect()
+    }
+}
+
+/// Rows of 0s and 1s
+impl Display for BitMatrix {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for i in 0..self.rows {
+            for j in 0..self.cols {
+                write!(f, "{}", self.get(i, j) as u8)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl From<&Matrix<Fp<2>>> for BitMatrix {
+    fn from(matrix: &Matrix<Fp<2>>) -> Self {
+        Self::from_fn(matrix.rows, matrix.cols, |i, j| matrix[(i, j)].value() == 1)
+    }
+}
+
+impl From<&BitMatrix> for Matrix<Fp<2>> {
+    fn from(matrix: &BitMatrix) -> Self {
+        Matrix::from_fn(matrix.rows, matrix.cols, |i, j| {
+            if matrix.get(i, j) {
+                Fp::one()
+            } else {
+                Fp::zero()
+            }
+        })
+    }
+}
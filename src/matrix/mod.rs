@@ -0,0 +1,190 @@
+//! Linear Algebra
+//! Dense matrices with entries in a ring.
+
+use std::ops::{Add, Mul, Sub};
+
+use crate::ring::RingBase;
+
+/// Dense Matrix
+/// Entries are stored in row-major order.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Matrix<T> {
+    pub(crate) rows: usize,
+    pub(crate) cols: usize,
+    pub(crate) data: Vec<T>,
+}
+
+impl<T: RingBase> Matrix<T> {
+    /// Zero matrix
+    pub fn zeros(rows: usize, cols: usize) -> Self {
+        Self {
+            rows,
+            cols,
+            data: vec![T::zero(); rows * cols],
+        }
+    }
+
+    /// Identity matrix
+    pub fn identity(n: usize) -> Self {
+        Self::from_fn(n, n, |i, j| if i == j { T::one() } else { T::zero() })
+    }
+
+    /// New matrix with entries given by a function of the position
+    pub fn from_fn(rows: usize, cols: usize, f: impl Fn(usize, usize) -> T) -> Self {
+        Self {
+            rows,
+            cols,
+            data: (0..rows)
+                .flat_map(|i| (0..cols).map(move |j| (i, j)))
+                .map(|(i, j)| f(i, j))
+                .collect(),
+        }
+    }
+
+    /// New matrix from its rows
+    /// Panics if the rows have different lengths.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        let cols = rows.first().map_or(0, |row| row.len());
+        assert!(
+            rows.iter().all(|row| row.len() == cols),
+            "Rows must have the same length"
+        );
+        Self {
+            rows: rows.len(),
+            cols,
+            data: rows.into_iter().flatten().collect(),
+        }
+    }
+
+    /// Number of rows
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Number of columns
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Check whether the matrix is square
+    pub fn is_square(&self) -> bool {
+        self.rows == self.cols
+    }
+
+    /// Row as a slice
+    pub fn row(&self, i: usize) -> &[T] {
+        &self.data[i * self.cols..(i + 1) * self.cols]
+    }
+
+    /// Transpose
+    pub fn transpose(&self) -> Self {
+        Self::from_fn(self.cols, self.rows, |i, j| self[(j, i)].clone())
+    }
+
+    /// Apply a map to every entry
+    pub fn map<U: RingBase>(&self, f: impl Fn(&T) -> U) -> Matrix<U> {
+        Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            data: self.data.iter().map(f).collect(),
+        }
+    }
+
+    /// Apply a fallible map to every entry, stopping at the first error
+    pub fn try_map<U: RingBase, E>(&self, f: impl Fn(&T) -> Result<U, E>) -> Result<Matrix<U>, E> {
+        Ok(Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            data: self.data.iter().map(f).collect::<Result<_, _>>()?,
+        })
+    }
+
+    /// Swap two rows
+    pub(crate) fn swap_rows(&mut self, a: usize, b: usize) {
+        for j in 0..self.cols {
+            self.data.swap(a * self.cols + j, b * self.cols + j);
+        }
+    }
+
+    /// Swap two columns
+    pub(crate) fn swap_cols(&mut self, a: usize, b: usize) {
+        for i in 0..self.rows {
+            self.data.swap(i * self.cols + a, i * self.cols + b);
+        }
+    }
+}
+
+impl<T> Matrix<T>
+where
+    T: RingBase,
+    for<'a> &'a T: Add<&'a T, Output = T>,
+    for<'a> &'a T: Mul<&'a T, Output = T>,
+{
+    /// Matrix-vector product A v
+    pub fn apply(&self, v: &[T]) -> Vec<T> {
+        assert_eq!(v.len(), self.cols, "Vector length must match the columns");
+        (0..self.rows)
+            .map(|i| {
+                self.row(i)
+                    .iter()
+                    .zip(v)
+                    .fold(T::zero(), |acc, (a, x)| &acc + &(a * x))
+            })
+            .collect()
+    }
+}
+
+impl<T> Matrix<T>
+where
+    T: RingBase + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+{
+    /// Berkowitz's division-free determinant
+    /// The characteristic polynomials of the leading principal submatrices satisfy p_k = T_k p_{k-1},
+    /// with T_k the lower triangular Toeplitz matrix of (1, -a_kk, -R S, -R A S, -R A^2 S, ...)
+    /// where A is the previous submatrix, R and S the new row and column.
+    /// O(n^4) ring operations and no divisions, so it works over any commutative ring.
+    pub fn berkowitz_determinant(&self) -> T {
+        assert!(self.is_square(), "Determinant of a non-square matrix");
+        let (a, n) = (self, self.rows);
+        let mut p = vec![T::one()];
+        for k in 0..n {
+            let mut column = vec![T::one(), T::zero() - a[(k, k)].clone()];
+            let mut v = (0..k).map(|i| a[(i, k)].clone()).collect::<Vec<_>>();
+            for _ in 0..k {
+                let rv = (0..k).fold(T::zero(), |acc, j| acc + a[(k, j)].clone() * v[j].clone());
+                column.push(T::zero() - rv);
+                v = (0..k)
+                    .map(|i| {
+                        (0..k).fold(T::zero(), |acc, j| acc + a[(i, j)].clone() * v[j].clone())
+                    })
+                    .collect();
+            }
+            p = (0..k + 2)
+                .map(|i| {
+                    (0..=i.min(k)).fold(T::zero(), |acc, j| {
+                        acc + column[i - j].clone() * p[j].clone()
+                    })
+                })
+                .collect();
+        }
+        // p holds det(x I - A), highest degree first
+        let det = p.pop().unwrap();
+        if n.is_multiple_of(2) {
+            det
+        } else {
+            T::zero() - det
+        }
+    }
+}
+
+mod trait_impls;
+
+pub mod blocks;
+
+pub mod eigen;
+
+pub mod euclidean;
+
+pub mod gaussian;
+
+pub mod gf2;
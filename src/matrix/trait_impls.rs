@@ -0,0 +1,145 @@
+use super::Matrix;
+use crate::{
+    field::finite_field::{
+        reduce::{ReduceMod, ReduceToFp, ReductionError},
+        Fp,
+    },
+    ring::RingBase,
+};
+use std::fmt::Display;
+use std::ops::{Add, Index, IndexMut, Mul, Neg, Sub};
+
+/// Formats one row per line: "[a, b]\n[c, d]".
+impl<T: Display> Display for Matrix<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let repr = self
+            .data
+            .chunks(self.cols.max(1))
+            .take(self.rows)
+            .map(|row| {
+                let row = row.iter().map(|x| x.to_string()).collect::<Vec<_>>();
+                format!("[{}]", row.join(", "))
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        write!(f, "{repr}")
+    }
+}
+
+/// A[(i, j)]
+impl<T> Index<(usize, usize)> for Matrix<T> {
+    type Output = T;
+
+    fn index(&self, (i, j): (usize, usize)) -> &T {
+        assert!(i < self.rows && j < self.cols, "Index out of bounds");
+        &self.data[i * self.cols + j]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Matrix<T> {
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut T {
+        assert!(i < self.rows && j < self.cols, "Index out of bounds");
+        &mut self.data[i * self.cols + j]
+    }
+}
+
+// --- Add / Sub ---
+
+macro_rules! impl_entrywise_ops {
+    ($trait:ident, $method:ident) => {
+        /// &A op &B, entrywise
+        impl<'b, T> $trait<&'b Matrix<T>> for &Matrix<T>
+        where
+            T: RingBase,
+            for<'a> &'a T: $trait<&'a T, Output = T>,
+        {
+            type Output = Matrix<T>;
+            fn $method(self, rhs: &'b Matrix<T>) -> Matrix<T> {
+                assert_eq!(
+                    (self.rows, self.cols),
+                    (rhs.rows, rhs.cols),
+                    "Matrix dimensions mismatch"
+                );
+                Matrix {
+                    rows: self.rows,
+                    cols: self.cols,
+                    data: self
+                        .data
+                        .iter()
+                        .zip(rhs.data.iter())
+                        .map(|(a, b)| a.$method(b))
+                        .collect(),
+                }
+            }
+        }
+
+        /// A op B, entrywise
+        impl<T> $trait for Matrix<T>
+        where
+            T: RingBase,
+            for<'a> &'a T: $trait<&'a T, Output = T>,
+        {
+            type Output = Matrix<T>;
+            fn $method(self, rhs: Matrix<T>) -> Matrix<T> {
+                (&self).$method(&rhs)
+            }
+        }
+    };
+}
+
+impl_entrywise_ops!(Add, add);
+impl_entrywise_ops!(Sub, sub);
+
+// --- Neg ---
+
+/// -A
+impl<T> Neg for &Matrix<T>
+where
+    T: RingBase,
+    for<'a> &'a T: Sub<&'a T, Output = T>,
+{
+    type Output = Matrix<T>;
+    fn neg(self) -> Matrix<T> {
+        self.map(|x| &T::zero() - x)
+    }
+}
+
+// --- Mul ---
+
+/// &A * &B
+impl<'b, T> Mul<&'b Matrix<T>> for &Matrix<T>
+where
+    T: RingBase,
+    for<'a> &'a T: Add<&'a T, Output = T>,
+    for<'a> &'a T: Mul<&'a T, Output = T>,
+{
+    type Output = Matrix<T>;
+    fn mul(self, rhs: &'b Matrix<T>) -> Matrix<T> {
+        assert_eq!(self.cols, rhs.rows, "Matrix dimensions mismatch");
+        Matrix::from_fn(self.rows, rhs.cols, |i, j| {
+            (0..self.cols).fold(T::zero(), |acc, k| &acc + &(&self[(i, k)] * &rhs[(k, j)]))
+        })
+    }
+}
+
+/// A * B
+impl<T> Mul for Matrix<T>
+where
+    T: RingBase,
+    for<'a> &'a T: Add<&'a T, Output = T>,
+    for<'a> &'a T: Mul<&'a T, Output = T>,
+{
+    type Output = Matrix<T>;
+    fn mul(self, rhs: Matrix<T>) -> Matrix<T> {
+        &self * &rhs
+    }
+}
+
+/// Entrywise reduction modulo P
+impl<T: ReduceToFp + RingBase> ReduceMod for Matrix<T> {
+    type Output<const P: u64> = Matrix<Fp<P>>;
+
+    fn reduce_mod<const P: u64>(&self) -> Result<Matrix<Fp<P>>, ReductionError> {
+        self.try_map(|x| x.to_fp::<P>())
+    }
+}
@@ -0,0 +1,87 @@
+//! Lucas Sequences
+//! https://en.wikipedia.org/wiki/Lucas_sequence
+//! The sequences U_n(P, Q) and V_n(P, Q) defined by x_{n+2} = P x_{n+1} - Q x_n with
+//! U_0 = 0, U_1 = 1 and V_0 = 2, V_1 = P.
+//! Fibonacci and Lucas numbers are U_n(1, -1) and V_n(1, -1).
+
+use super::modular::{jacobi, mul_mod};
+
+/// Lucas sequence terms (U_n, V_n, Q^n) modulo m by fast doubling
+/// Walks the pairs (k, k + 1) down the bits of n using
+/// $$ U_{2k} = U_k V_k, \quad V_{2k} = V_k^2 - 2 Q^k $$
+/// $$ U_{2k+1} = U_{k+1} V_k - Q^k, \quad V_{2k+1} = V_{k+1} V_k - P Q^k $$
+fn lucas_doubling(p: i64, q: i64, n: u64, m: u64) -> (u64, u64, u64) {
+    let p = (p as i128).rem_euclid(m as i128) as u64;
+    let q = (q as i128).rem_euclid(m as i128) as u64;
+    let sub = |a: u64, b: u64| if a >= b { a - b } else { a + (m - b) };
+
+    // (U_k, U_{k+1}, V_k, V_{k+1}, Q^k) starting from k = 0
+    let (mut u, mut u1, mut v, mut v1, mut qk) = (0, 1 % m, 2 % m, p, 1 % m);
+    for bit in (0..u64::BITS - n.leading_zeros()).rev() {
+        // Terms at 2k and 2k + 1
+        let u_2k = mul_mod(u, v, m);
+        let v_2k = sub(mul_mod(v, v, m), mul_mod(2, qk, m));
+        let u_2k1 = sub(mul_mod(u1, v, m), qk);
+        let v_2k1 = sub(mul_mod(v1, v, m), mul_mod(p, qk, m));
+        let q_2k = mul_mod(qk, qk, m);
+        if (n >> bit) & 1 == 0 {
+            (u, u1, v, v1, qk) = (u_2k, u_2k1, v_2k, v_2k1, q_2k);
+        } else {
+            // Terms at 2k + 2 from k + 1
+            let q_k1 = mul_mod(qk, q, m);
+            let u_2k2 = mul_mod(u1, v1, m);
+            let v_2k2 = sub(mul_mod(v1, v1, m), mul_mod(2, q_k1, m));
+            (u, u1, v, v1, qk) = (u_2k1, u_2k2, v_2k1, v_2k2, mul_mod(q_2k, q, m));
+        }
+    }
+    (u, v, qk)
+}
+
+/// Lucas sequences (U_n(P, Q), V_n(P, Q)) modulo m
+/// Uses O(log n) modular multiplications. Panics if m = 0.
+pub fn lucas_sequence(p: i64, q: i64, n: u64, m: u64) -> (u64, u64) {
+    assert!(m > 0, "Lucas sequence modulo zero");
+    let (u, v, _) = lucas_doubling(p, q, n, m);
+    (u, v)
+}
+
+/// Strong Lucas probable prime test with Selfridge's parameters
+/// https://en.wikipedia.org/wiki/Lucas_pseudoprime#Strong_Lucas_pseudoprimes
+/// D is the first of 5, -7, 9, -11, ... with (D / n) = -1, then P = 1 and Q = (1 - D) / 4.
+/// Writing n + 1 = d 2^s, n passes if U_d = 0 or V_{d 2^r} = 0 (mod n) for some 0 <= r < s.
+/// Every prime passes; combined with a base 2 strong Fermat test this is the Baillie-PSW test.
+pub fn is_strong_lucas_prp(n: u64) -> bool {
+    if n == 2 {
+        return true;
+    }
+    if n < 2 || n.is_multiple_of(2) || n.isqrt().pow(2) == n {
+        return false;
+    }
+
+    // Selfridge's method A, terminates since n is not a perfect square
+    let mut d = 5_i64;
+    loop {
+        match jacobi(d, n) {
+            -1 => break,
+            0 if d.unsigned_abs() != n => return false,
+            _ => d = if d > 0 { -(d + 2) } else { -d + 2 },
+        }
+    }
+    let (p, q) = (1, (1 - d) / 4);
+
+    let s = (n + 1).trailing_zeros();
+    let (u, mut v, mut qk) = lucas_doubling(p, q, (n + 1) >> s, n);
+    if u == 0 || v == 0 {
+        return true;
+    }
+    for _ in 1..s {
+        // V_{2k} = V_k^2 - 2 Q^k
+        let (v2, qk2) = (mul_mod(v, v, n), mul_mod(2, qk, n));
+        v = if v2 >= qk2 { v2 - qk2 } else { v2 + (n - qk2) };
+        qk = mul_mod(qk, qk, n);
+        if v == 0 {
+            return true;
+        }
+    }
+    false
+}
@@ -3,6 +3,43 @@
 
 use std::ops::{Add, Div, Mul, Sub};
 
+use crate::error::AlgebraError;
+
+mod complex;
+mod lucas;
+mod modular;
+mod partitions;
+mod prime;
+mod quadratic_sieve;
+mod rational;
+mod sequences;
+mod stirling;
+
+pub use complex::Complex;
+pub use lucas::{is_strong_lucas_prp, lucas_sequence};
+pub use modular::{
+    add_mod, carmichael_lambda, crt, euler_phi, jacobi, mod_inverse, mul_mod, mult_order, pow_mod,
+    sqrt_mod, sqrt_mod_prime, sqrt_mod_prime_power,
+};
+pub use partitions::{
+    checked_partition_numbers, checked_partitions, compositions_into_parts, partition_numbers,
+    partition_numbers_mod, partitions, partitions_into_at_most, partitions_into_distinct_parts,
+    partitions_into_parts,
+};
+pub use prime::{factorize, is_prime};
+pub use quadratic_sieve::quadratic_sieve;
+pub use rational::Rational;
+pub use sequences::{
+    bernoulli, bernoulli_numbers, checked_bernoulli, checked_bernoulli_numbers, checked_harmonic,
+    checked_harmonic_numbers, harmonic, harmonic_numbers,
+};
+pub use stirling::{
+    bell, bell_mod, bell_numbers, bell_numbers_mod, stirling_first, stirling_first_mod,
+    stirling_first_row_mod, stirling_first_triangle, stirling_first_triangle_mod, stirling_second,
+    stirling_second_mod, stirling_second_row_mod, stirling_second_triangle,
+    stirling_second_triangle_mod,
+};
+
 /// Greatest Common Divisor
 /// Adapted from `uutils`
 pub fn gcd(mut a: u64, mut b: u64) -> u64 {
@@ -42,13 +79,25 @@ pub fn gcd(mut a: u64, mut b: u64) -> u64 {
     }
 }
 
+/// Greatest Common Divisor of 128-bit integers
+pub(crate) fn gcd_u128(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Fraction of u64 parts with unchecked arithmetic
+/// See `Rational` for signed fractions with overflow checks.
 pub struct UnsignedRational {
     num: u64,
     den: u64,
 }
 
 impl UnsignedRational {
+    /// Panics if the denominator is zero, see `TryFrom<(u64, u64)>`.
     pub fn new(num: u64, den: u64) -> Self {
+        assert!(den != 0, "Rational with zero denominator");
         Self { num, den }.reduce()
     }
 
@@ -60,6 +109,17 @@ impl UnsignedRational {
     }
 }
 
+impl TryFrom<(u64, u64)> for UnsignedRational {
+    type Error = AlgebraError;
+
+    fn try_from((num, den): (u64, u64)) -> Result<Self, AlgebraError> {
+        match den {
+            0 => Err(AlgebraError::ZeroDenominator),
+            _ => Ok(Self::new(num, den)),
+        }
+    }
+}
+
 impl Add for UnsignedRational {
     type Output = Self;
 
@@ -0,0 +1,307 @@
+//! Modular Arithmetic
+//! Arithmetic on residues modulo a runtime integer n.
+
+use super::{gcd, prime::factorize};
+
+/// Modular addition (a + b) % m without overflow
+#[inline(always)]
+pub fn add_mod(a: u64, b: u64, m: u64) -> u64 {
+    ((a as u128 + b as u128) % m as u128) as u64
+}
+
+/// Modular multiplication a * b % m without overflow
+#[inline(always)]
+pub fn mul_mod(a: u64, b: u64, m: u64) -> u64 {
+    ((a as u128 * b as u128) % m as u128) as u64
+}
+
+/// Modular exponentiation base^exp % m using square and multiply
+pub fn pow_mod(base: u64, mut exp: u64, m: u64) -> u64 {
+    let mut base = base % m;
+    let mut result = 1 % m;
+
+    while exp > 0 {
+        if exp % 2 == 1 {
+            result = mul_mod(result, base, m);
+        }
+        base = mul_mod(base, base, m);
+        exp /= 2;
+    }
+
+    result
+}
+
+/// Modular inverse a^{-1} % m using the extended Euclidean algorithm
+/// Returns `None` when gcd(a, m) != 1.
+pub fn mod_inverse(a: u64, m: u64) -> Option<u64> {
+    // Invariant: r_i = s_i * a (mod m)
+    let (mut r0, mut r1) = (m as i128, (a % m) as i128);
+    let (mut s0, mut s1) = (0_i128, 1_i128);
+
+    while r1 != 0 {
+        let q = r0 / r1;
+        (r0, r1) = (r1, r0 - q * r1);
+        (s0, s1) = (s1, s0 - q * s1);
+    }
+
+    if r0 != 1 {
+        return None;
+    }
+    Some(s0.rem_euclid(m as i128) as u64)
+}
+
+/// Chinese Remainder Theorem
+/// Find the unique x mod m_1 m_2 ... m_k such that x = a_i (mod m_i) for every congruence (a_i, m_i).
+/// The moduli must be pairwise coprime and their product must fit in a u64.
+/// Returns `None` when the moduli are not pairwise coprime.
+pub fn crt(congruences: &[(u64, u64)]) -> Option<(u64, u64)> {
+    congruences.iter().try_fold((0, 1), |(x, m), &(a, n)| {
+        // x + m t = a (mod n) => t = (a - x) m^{-1} (mod n)
+        let m_inv = mod_inverse(m % n, n)?;
+        let diff = add_mod(a % n, n - x % n, n);
+        let t = mul_mod(diff, m_inv, n);
+        Some((x + m * t, m * n))
+    })
+}
+
+/// Square root modulo an odd prime using the Tonelli-Shanks algorithm
+/// https://en.wikipedia.org/wiki/Tonelli%E2%80%93Shanks_algorithm
+/// Returns one root r, the other one being p - r. Returns `None` if a is a quadratic non-residue.
+pub fn sqrt_mod_prime(a: u64, p: u64) -> Option<u64> {
+    let a = a % p;
+    if a == 0 || p == 2 {
+        return Some(a);
+    }
+
+    // Euler's criterion
+    if pow_mod(a, (p - 1) / 2, p) != 1 {
+        return None;
+    }
+
+    // p - 1 = 2^s q with q odd
+    let s = (p - 1).trailing_zeros();
+    let q = (p - 1) >> s;
+
+    // Shortcut for p = 3 (mod 4)
+    if s == 1 {
+        return Some(pow_mod(a, (p + 1) / 4, p));
+    }
+
+    // Find a quadratic non-residue z
+    let z = (2..p)
+        .find(|&z| pow_mod(z, (p - 1) / 2, p) == p - 1)
+        .expect("Half of the non-zero residues are non-residues");
+
+    let mut m = s;
+    let mut c = pow_mod(z, q, p);
+    let mut t = pow_mod(a, q, p);
+    let mut r = pow_mod(a, q.div_ceil(2), p);
+
+    while t != 1 {
+        // Least i such that t^{2^i} = 1
+        let mut i = 0;
+        let mut t2i = t;
+        while t2i != 1 {
+            t2i = mul_mod(t2i, t2i, p);
+            i += 1;
+        }
+
+        let b = pow_mod(c, 1 << (m - i - 1), p);
+        m = i;
+        c = mul_mod(b, b, p);
+        t = mul_mod(t, c, p);
+        r = mul_mod(r, b, p);
+    }
+
+    Some(r)
+}
+
+/// Square roots of a unit b modulo p^k
+fn sqrt_unit_mod_prime_power(b: u64, p: u64, k: u32) -> Vec<u64> {
+    let pk = p.pow(k);
+
+    if p == 2 {
+        return match k {
+            1 => vec![1],
+            2 if b % 4 == 1 => vec![1, 3],
+            2 => vec![],
+            _ if b % 8 != 1 => vec![],
+            _ => {
+                // Lift a root r of b mod 2^j to a root mod 2^{j+1}
+                let mut r = 1_u64;
+                for j in 3..k {
+                    if mul_mod(r, r, 1 << (j + 1)) != b % (1 << (j + 1)) {
+                        r += 1 << (j - 1);
+                    }
+                }
+                // Four roots: +-r and +-r + 2^{k-1}
+                let half = pk / 2;
+                let mut roots = vec![r, pk - r, (r + half) % pk, (pk - r + half) % pk];
+                roots.sort_unstable();
+                roots
+            }
+        };
+    }
+
+    // Hensel lifting: r <- r - (r^2 - b) / (2 r)
+    let Some(mut r) = sqrt_mod_prime(b, p) else {
+        return vec![];
+    };
+    let mut pj = p;
+    for _ in 1..k {
+        pj *= p;
+        let f = add_mod(mul_mod(r, r, pj), pj - b % pj, pj);
+        let df_inv =
+            mod_inverse(add_mod(r, r, pj), pj).expect("2r is a unit modulo an odd prime power");
+        r = add_mod(r, pj - mul_mod(f, df_inv, pj), pj);
+    }
+
+    let mut roots = vec![r, pk - r];
+    roots.sort_unstable();
+    roots
+}
+
+/// All square roots of a modulo the prime power p^k
+pub fn sqrt_mod_prime_power(a: u64, p: u64, k: u32) -> Vec<u64> {
+    let pk = p.pow(k);
+    let a = a % pk;
+
+    // x^2 = 0 (mod p^k) iff p^{ceil(k/2)} | x
+    if a == 0 {
+        let step = p.pow(k.div_ceil(2));
+        return (0..pk).step_by(step as usize).collect();
+    }
+
+    // a = p^e b with p not dividing b, only even valuations admit roots
+    let mut e = 0;
+    let mut b = a;
+    while b.is_multiple_of(p) {
+        b /= p;
+        e += 1;
+    }
+    if e % 2 == 1 {
+        return vec![];
+    }
+
+    // x = p^h y with y^2 = b (mod p^{k-e}), y is free modulo p^{k-h}
+    let h = e / 2;
+    let ph = p.pow(h);
+    let pke = p.pow(k - e);
+    let mut roots = sqrt_unit_mod_prime_power(b, p, k - e)
+        .into_iter()
+        .flat_map(|y| (0..ph).map(move |t| ph * (y + t * pke)))
+        .collect::<Vec<_>>();
+    roots.sort_unstable();
+    roots
+}
+
+/// All square roots of a modulo n
+/// Roots are computed modulo each prime power dividing n and combined through the Chinese Remainder Theorem.
+/// Returns the sorted list of every x in [0, n) such that x^2 = a (mod n), empty for n = 0.
+pub fn sqrt_mod(a: u64, n: u64) -> Vec<u64> {
+    match n {
+        0 => return vec![],
+        1 => return vec![0],
+        _ => {}
+    }
+
+    let mut roots = factorize(n)
+        .into_iter()
+        .map(|(p, k)| (p.pow(k), sqrt_mod_prime_power(a, p, k)))
+        .fold(vec![(0, 1)], |partial, (pk, roots)| {
+            // Combine every partial root with every root modulo p^k
+            partial
+                .iter()
+                .flat_map(|&(x, m)| {
+                    roots.iter().map(move |&r| {
+                        crt(&[(x, m), (r, pk)]).expect("Prime powers are pairwise coprime")
+                    })
+                })
+                .collect()
+        })
+        .into_iter()
+        .map(|(x, _)| x)
+        .collect::<Vec<_>>();
+    roots.sort_unstable();
+    roots
+}
+
+/// Euler's totient function
+/// https://en.wikipedia.org/wiki/Euler%27s_totient_function
+/// Number of units modulo n, $$ \varphi(n) = n \prod_{p | n} (1 - 1/p) $$
+/// Returns 0 for n = 0.
+pub fn euler_phi(n: u64) -> u64 {
+    if n == 0 {
+        return 0;
+    }
+    factorize(n)
+        .into_iter()
+        .fold(n, |phi, (p, _)| phi / p * (p - 1))
+}
+
+/// Carmichael function
+/// https://en.wikipedia.org/wiki/Carmichael_function
+/// Exponent of the unit group modulo n, the smallest m > 0 with a^m = 1 (mod n) for every unit a.
+/// Returns 0 for n = 0.
+pub fn carmichael_lambda(n: u64) -> u64 {
+    if n == 0 {
+        return 0;
+    }
+    factorize(n).into_iter().fold(1, |lambda, (p, k)| {
+        let lambda_pk = match (p, k) {
+            // (Z/2^k)^* = C_2 x C_{2^{k-2}} for k >= 3
+            (2, k) if k >= 3 => 1 << (k - 2),
+            _ => p.pow(k - 1) * (p - 1),
+        };
+        lambda / gcd(lambda, lambda_pk) * lambda_pk
+    })
+}
+
+/// Multiplicative order of a modulo n
+/// https://en.wikipedia.org/wiki/Multiplicative_order
+/// Smallest k > 0 such that a^k = 1 (mod n), found by stripping prime factors from the Carmichael function λ(n).
+/// Returns `None` when gcd(a, n) != 1 or n = 0.
+pub fn mult_order(a: u64, n: u64) -> Option<u64> {
+    if n == 0 || gcd(a % n, n) != 1 {
+        return None;
+    }
+    let lambda = carmichael_lambda(n);
+    let order = factorize(lambda)
+        .into_iter()
+        .fold(lambda, |mut order, (q, _)| {
+            while order.is_multiple_of(q) && pow_mod(a, order / q, n) == 1 % n {
+                order /= q;
+            }
+            order
+        });
+    Some(order)
+}
+
+/// Jacobi symbol (a / n) for odd n > 0
+/// https://en.wikipedia.org/wiki/Jacobi_symbol
+/// Equals the Legendre symbol when n is prime, and 0 when gcd(a, n) != 1.
+/// Panics if n is even or zero.
+pub fn jacobi(a: i64, n: u64) -> i32 {
+    assert!(n % 2 == 1, "Jacobi symbol requires an odd modulus");
+    let mut a = (a as i128).rem_euclid(n as i128) as u64;
+    let mut n = n;
+    let mut result = 1;
+    while a != 0 {
+        // (2 / n) = -1 iff n = 3, 5 (mod 8)
+        let twos = a.trailing_zeros();
+        a >>= twos;
+        if twos % 2 == 1 && matches!(n % 8, 3 | 5) {
+            result = -result;
+        }
+        // Quadratic reciprocity
+        if a % 4 == 3 && n % 4 == 3 {
+            result = -result;
+        }
+        (a, n) = (n % a, a);
+    }
+    if n == 1 {
+        result
+    } else {
+        0
+    }
+}
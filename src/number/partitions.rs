@@ -0,0 +1,154 @@
+//! Integer Partitions
+//! https://en.wikipedia.org/wiki/Integer_partition
+//! Counting partitions and compositions of integers.
+//! Exact counts are u128, which holds p(n) up to n = 1458. Past that `partitions` panics and `checked_partitions`
+//! returns None.
+//! Restricted counts are coefficients of the generating functions
+//! $$ \prod_{a \in A} \frac{1}{1 - x^a}, \quad \prod_{a \in A} (1 + x^a), \quad \frac{1}{1 - \sum_{a \in A} x^a} $$
+//! and work over any ring, typically Fp to count modulo a prime. Over the primitive integers they overflow
+//! exactly when T's addition does.
+
+use std::ops::Add;
+
+use crate::{field::finite_field::Fp, polynomial::power_series::PowerSeries, ring::RingBase};
+
+/// Generalized pentagonal numbers k (3k - 1) / 2 for k = 1, -1, 2, -2, ... up to n, paired with the sign (-1)^{k+1}
+fn pentagonal_numbers(n: usize) -> impl Iterator<Item = (usize, bool)> {
+    (1..)
+        .flat_map(|k: usize| {
+            [
+                (k * (3 * k - 1) / 2, k % 2 == 1),
+                (k * (3 * k + 1) / 2, k % 2 == 1),
+            ]
+        })
+        .take_while(move |&(g, _)| g <= n)
+}
+
+/// Partition numbers p(0), p(1), ..., p(n)
+/// https://en.wikipedia.org/wiki/Pentagonal_number_theorem
+/// Euler's recurrence $$ p(n) = \sum_{k \neq 0} (-1)^{k+1} p(n - k (3k - 1) / 2) $$
+/// takes O(n^{3/2}) operations.
+/// Panics on overflow, for n > 1458, see `checked_partition_numbers`.
+pub fn partition_numbers(n: usize) -> Vec<u128> {
+    checked_partition_numbers(n).expect("Partition number overflow")
+}
+
+/// Partition numbers p(0), p(1), ..., p(n), None on overflow, for n > 1458
+pub fn checked_partition_numbers(n: usize) -> Option<Vec<u128>> {
+    let mut p = Vec::with_capacity(n + 1);
+    p.push(1_u128);
+    for m in 1..=n {
+        // Wrapping sums are exact whenever p(m) fits, and since p(m - 1) <= p(m) < 2 p(m - 1)
+        // an overflow shows up as a wrapped value smaller than p(m - 1).
+        let p_m = pentagonal_numbers(m).fold(0_u128, |acc, (g, sign)| {
+            if sign {
+                acc.wrapping_add(p[m - g])
+            } else {
+                acc.wrapping_sub(p[m - g])
+            }
+        });
+        if p_m < p[m - 1] {
+            return None;
+        }
+        p.push(p_m);
+    }
+    Some(p)
+}
+
+/// Partition number p(n)
+/// Panics on overflow, for n > 1458, see `checked_partitions`.
+pub fn partitions(n: usize) -> u128 {
+    partition_numbers(n)[n]
+}
+
+/// Partition number p(n), None on overflow, for n > 1458
+pub fn checked_partitions(n: usize) -> Option<u128> {
+    checked_partition_numbers(n).map(|p| p[n])
+}
+
+/// Partition numbers p(0), ..., p(n) modulo a prime P
+/// Inverts the Euler function $$ \prod_{k \geq 1} (1 - x^k) = \sum_k (-1)^k x^{k (3k - 1) / 2} $$ as a power series.
+pub fn partition_numbers_mod<const P: u64>(n: usize) -> Vec<Fp<P>> {
+    let mut euler = vec![Fp::<P>::zero(); n + 1];
+    euler[0] = Fp::one();
+    for (g, sign) in pentagonal_numbers(n) {
+        euler[g] = if sign { -Fp::one() } else { Fp::one() };
+    }
+    PowerSeries::new(euler, n + 1).inverse().coeffs
+}
+
+/// Number of partitions of 0, 1, ..., n into parts from the given set
+/// Coefficients of $$ \prod_{a \in A} \frac{1}{1 - x^a} $$
+/// Each part may be used any number of times. Repeated or zero parts are ignored.
+pub fn partitions_into_parts<T>(parts: &[usize], n: usize) -> Vec<T>
+where
+    T: RingBase + Copy + Add<Output = T>,
+{
+    let mut counts = unit_series(n);
+    for a in distinct_parts(parts) {
+        // Divide by 1 - x^a
+        for m in a..=n {
+            counts[m] = counts[m] + counts[m - a];
+        }
+    }
+    counts
+}
+
+/// Number of partitions of 0, 1, ..., n into distinct parts from the given set
+/// Coefficients of $$ \prod_{a \in A} (1 + x^a) $$
+pub fn partitions_into_distinct_parts<T>(parts: &[usize], n: usize) -> Vec<T>
+where
+    T: RingBase + Copy + Add<Output = T>,
+{
+    let mut counts = unit_series(n);
+    for a in distinct_parts(parts) {
+        // Multiply by 1 + x^a
+        for m in (a..=n).rev() {
+            counts[m] = counts[m] + counts[m - a];
+        }
+    }
+    counts
+}
+
+/// Number of compositions (ordered partitions) of 0, 1, ..., n into parts from the given set
+/// Coefficients of $$ \frac{1}{1 - \sum_{a \in A} x^a} $$
+pub fn compositions_into_parts<T>(parts: &[usize], n: usize) -> Vec<T>
+where
+    T: RingBase + Copy + Add<Output = T>,
+{
+    let parts = distinct_parts(parts);
+    let mut counts = unit_series(n);
+    for m in 1..=n {
+        counts[m] = parts
+            .iter()
+            .take_while(|&&a| a <= m)
+            .fold(T::zero(), |acc, &a| acc + counts[m - a]);
+    }
+    counts
+}
+
+/// Number of partitions of 0, 1, ..., n into at most k parts
+/// By conjugation these are also the partitions with largest part at most k, the coefficients of
+/// $$ \prod_{j=1}^{k} \frac{1}{1 - x^j} $$
+pub fn partitions_into_at_most<T>(k: usize, n: usize) -> Vec<T>
+where
+    T: RingBase + Copy + Add<Output = T>,
+{
+    let parts = (1..=k).collect::<Vec<_>>();
+    partitions_into_parts(&parts, n)
+}
+
+/// Series 1 + 0 x + ... + 0 x^n
+fn unit_series<T: RingBase>(n: usize) -> Vec<T> {
+    let mut series = vec![T::zero(); n + 1];
+    series[0] = T::one();
+    series
+}
+
+/// Sorted distinct non-zero parts
+fn distinct_parts(parts: &[usize]) -> Vec<usize> {
+    let mut parts = parts.iter().copied().filter(|&a| a > 0).collect::<Vec<_>>();
+    parts.sort_unstable();
+    parts.dedup();
+    parts
+}
@@ -0,0 +1,138 @@
+//! Primality and Integer Factorization
+//!
+
+use super::{
+    gcd,
+    modular::{add_mod, mul_mod, pow_mod},
+};
+
+/// Deterministic Miller-Rabin primality test for 64-bit integers
+/// https://en.wikipedia.org/wiki/Miller%E2%80%93Rabin_primality_test
+/// The first 12 primes as witnesses are enough for every n < 2^64.
+pub fn is_prime(n: u64) -> bool {
+    const WITNESSES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+    if n < 2 {
+        return false;
+    }
+    for p in WITNESSES {
+        if n.is_multiple_of(p) {
+            return n == p;
+        }
+    }
+
+    // n - 1 = 2^s d with d odd
+    let s = (n - 1).trailing_zeros();
+    let d = (n - 1) >> s;
+
+    'witness: for a in WITNESSES {
+        let mut x = pow_mod(a, d, n);
+        if x == 1 || x == n - 1 {
+            continue;
+        }
+        for _ in 1..s {
+            x = mul_mod(x, x, n);
+            if x == n - 1 {
+                continue 'witness;
+            }
+        }
+        return false;
+    }
+
+    true
+}
+
+/// Find a non-trivial divisor of a composite odd n using Pollard's rho (Brent's variant)
+/// https://en.wikipedia.org/wiki/Pollard%27s_rho_algorithm
+fn pollard_rho(n: u64) -> u64 {
+    // Try successive polynomials x^2 + c until a proper divisor shows up
+    for c in 1.. {
+        let f = |x: u64| add_mod(mul_mod(x, x, n), c, n);
+
+        let mut y = 2;
+        let mut r = 1;
+        let mut q = 1;
+        let mut g = 1;
+        let mut x = y;
+        let mut ys = y;
+
+        while g == 1 {
+            x = y;
+            for _ in 0..r {
+                y = f(y);
+            }
+            let mut k = 0;
+            while k < r && g == 1 {
+                ys = y;
+                // Batch the gcd computations
+                for _ in 0..(r - k).min(128) {
+                    y = f(y);
+                    q = mul_mod(q, x.abs_diff(y), n);
+                }
+                g = gcd(q, n);
+                k += 128;
+            }
+            r *= 2;
+        }
+
+        // The batch overshot, backtrack one step at a time
+        if g == n {
+            loop {
+                ys = f(ys);
+                g = gcd(x.abs_diff(ys), n);
+                if g > 1 {
+                    break;
+                }
+            }
+        }
+
+        if g != n {
+            return g;
+        }
+    }
+    unreachable!("Pollard's rho always finds a divisor of a composite number")
+}
+
+/// Prime factorization
+/// Returns the pairs (p, e) such that n = \prod p^e, sorted by prime, empty for n = 0 and n = 1.
+/// Small factors are removed by trial division, the remaining cofactor is split with Pollard's rho.
+pub fn factorize(mut n: u64) -> Vec<(u64, u32)> {
+    if n == 0 {
+        return vec![];
+    }
+    let mut primes = vec![];
+
+    // Trial division by small primes
+    for p in [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37] {
+        while n.is_multiple_of(p) {
+            primes.push(p);
+            n /= p;
+        }
+    }
+
+    // Split the remaining cofactor
+    let mut stack = vec![n];
+    while let Some(m) = stack.pop() {
+        if m == 1 {
+            continue;
+        }
+        if is_prime(m) {
+            primes.push(m);
+            continue;
+        }
+        let d = pollard_rho(m);
+        stack.push(d);
+        stack.push(m / d);
+    }
+
+    // Group equal primes
+    primes.sort_unstable();
+    let mut factors: Vec<(u64, u32)> = vec![];
+    for p in primes {
+        match factors.last_mut() {
+            Some((q, e)) if *q == p => *e += 1,
+            _ => factors.push((p, 1)),
+        }
+    }
+    factors
+}
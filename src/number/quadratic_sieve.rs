@@ -0,0 +1,241 @@
+//! Quadratic Sieve
+//! https://en.wikipedia.org/wiki/Quadratic_sieve
+//! Factorization of composites up to about 100 bits, the range where Pollard's rho becomes slow.
+//! Looks for x with Q(x) = (x + m)^2 - n smooth over a factor base, m = ceil(sqrt(n)).
+//! A set of relations whose exponent vectors sum to zero modulo 2 (a null vector of a GF(2) matrix)
+//! gives a congruence of squares X^2 = Y^2 (mod n), and gcd(X - Y, n) is often a proper factor.
+
+use super::{gcd_u128, is_prime, modular::sqrt_mod_prime, pow_mod};
+use crate::matrix::gf2::BitMatrix;
+
+/// Half-width of each sieving interval
+const BLOCK: usize = 1 << 16;
+
+/// Extra relations beyond the factor base size, each one adds a dependency
+const EXTRA_RELATIONS: usize = 16;
+
+/// Smallest sieved prime, smaller ones are left to the trial division of candidates
+const MIN_SIEVE_PRIME: u64 = 30;
+
+/// Modular multiplication a * b % m for m < 2^127
+fn mul_mod(a: u128, b: u128, m: u128) -> u128 {
+    if a < 1 << 64 && b < 1 << 64 {
+        return (a * b) % m;
+    }
+    // Double and add, a + a cannot overflow since a < m < 2^127
+    let (mut a, mut b, mut result) = (a % m, b, 0);
+    while b > 0 {
+        if b & 1 == 1 {
+            result = (result + a) % m;
+        }
+        a = (a + a) % m;
+        b >>= 1;
+    }
+    result
+}
+
+/// Factor base size for an n of the given bit length
+fn factor_base_size(bits: u32) -> usize {
+    match bits {
+        0..=50 => 60,
+        51..=60 => 100,
+        61..=70 => 150,
+        71..=80 => 250,
+        81..=90 => 400,
+        _ => 650,
+    }
+}
+
+/// Smooth relation (x + m)^2 = Q(x) (mod n) with the full exponent vector of Q(x)
+/// Index 0 is the sign, index i > 0 is the i-th factor base prime.
+struct Relation {
+    t: u128,
+    exponents: Vec<(usize, u32)>,
+}
+
+/// Sieve state: factor base primes, their logarithms and the square roots of n modulo each of them
+struct FactorBase {
+    primes: Vec<u64>,
+    logs: Vec<u8>,
+    roots: Vec<u64>,
+}
+
+impl FactorBase {
+    /// Primes p with (n / p) = 1, or a prime factor of n found along the way
+    fn new(n: u128, size: usize) -> Result<Self, u128> {
+        let mut primes = vec![2];
+        let mut roots = vec![1];
+        let mut p = 3;
+        while primes.len() < size {
+            if (3..)
+                .step_by(2)
+                .take_while(|d| d * d <= p)
+                .all(|d| p % d != 0)
+            {
+                let a = (n % p as u128) as u64;
+                if a == 0 {
+                    return Err(p as u128);
+                }
+                if pow_mod(a, (p - 1) / 2, p) == 1 {
+                    primes.push(p);
+                    roots.push(sqrt_mod_prime(a, p).expect("Quadratic residue has a root"));
+                }
+            }
+            p += 2;
+        }
+        let logs = primes.iter().map(|&p| p.ilog2() as u8 + 1).collect();
+        Ok(Self {
+            primes,
+            logs,
+            roots,
+        })
+    }
+
+    /// Trial division of Q(x) over the factor base, the exponent vector if it is smooth
+    fn factor(&self, q: i128) -> Option<Vec<(usize, u32)>> {
+        let mut exponents = vec![];
+        if q < 0 {
+            exponents.push((0, 1));
+        }
+        let mut q = q.unsigned_abs();
+        for (i, &p) in self.primes.iter().enumerate() {
+            let p = p as u128;
+            let mut e = 0;
+            while q.is_multiple_of(p) {
+                q /= p;
+                e += 1;
+            }
+            if e > 0 {
+                exponents.push((i + 1, e));
+            }
+        }
+        (q == 1).then_some(exponents)
+    }
+}
+
+/// Sieve the interval x in [start, start + BLOCK) and collect the smooth relations
+fn sieve_block(n: u128, m: u128, start: i128, base: &FactorBase, relations: &mut Vec<Relation>) {
+    let mut sieve = vec![0_u8; BLOCK];
+    for ((&p, &log), &root) in base.primes.iter().zip(&base.logs).zip(&base.roots) {
+        if p < MIN_SIEVE_PRIME {
+            continue;
+        }
+        // (x + m)^2 = n (mod p) <=> x = +-root - m (mod p)
+        let offset = (start + m as i128).rem_euclid(p as i128) as u64;
+        for r in [root, p - root] {
+            let mut i = ((r + p - offset) % p) as usize;
+            while i < BLOCK {
+                sieve[i] = sieve[i].wrapping_add(log);
+                i += p as usize;
+            }
+        }
+    }
+
+    // Accept candidates whose logarithm falls short by about the unsieved small primes and prime powers
+    let largest = *base.primes.last().unwrap();
+    let slack = 2 * (largest.ilog2() + 1) as i32;
+    for (i, &s) in sieve.iter().enumerate() {
+        let x = start + i as i128;
+        let t = m as i128 + x;
+        if t <= 0 {
+            continue;
+        }
+        let q = t * t - n as i128;
+        if q == 0 {
+            continue;
+        }
+        let size = q.unsigned_abs().ilog2() as i32;
+        if (s as i32) < size - slack {
+            continue;
+        }
+        if let Some(exponents) = base.factor(q) {
+            relations.push(Relation {
+                t: t as u128,
+                exponents,
+            });
+        }
+    }
+}
+
+/// Proper factor of n from a dependency between relations, if the congruence of squares is not trivial
+fn factor_from_dependency(n: u128, base: &FactorBase, relations: &[&Relation]) -> Option<u128> {
+    // X = prod (x + m), Y = sqrt(prod Q(x)) from the halved exponent sums
+    let x = relations
+        .iter()
+        .fold(1 % n, |acc, r| mul_mod(acc, r.t % n, n));
+    let mut totals = vec![0_u32; base.primes.len() + 1];
+    for r in relations {
+        for &(i, e) in &r.exponents {
+            totals[i] += e;
+        }
+    }
+    let y = totals
+        .iter()
+        .enumerate()
+        .skip(1)
+        .fold(1 % n, |acc, (i, &e)| {
+            (0..e / 2).fold(acc, |acc, _| mul_mod(acc, base.primes[i - 1] as u128, n))
+        });
+    let d = gcd_u128((x + n - y) % n, n);
+    (d != 1 && d != n).then_some(d)
+}
+
+/// Find a proper factor of n with the quadratic sieve
+/// Returns `None` if n is prime or 1, or if every dependency found yields a trivial factor.
+/// Small factors and perfect squares are detected directly.
+/// Intended for n of roughly 40 to 100 bits, above 2^126 the intermediate values would overflow.
+pub fn quadratic_sieve(n: u128) -> Option<u128> {
+    assert!(n < 1 << 126, "Quadratic sieve input too large");
+    if n < 4 {
+        return None;
+    }
+    if n.is_multiple_of(2) {
+        return Some(2);
+    }
+    let root = n.isqrt();
+    if root * root == n {
+        return Some(root);
+    }
+    if n < 1 << 64 && is_prime(n as u64) {
+        return None;
+    }
+
+    let base = match FactorBase::new(n, factor_base_size(n.ilog2() + 1)) {
+        Ok(base) => base,
+        Err(p) => return (p != n).then_some(p),
+    };
+    let m = root + 1;
+
+    // Sieve blocks alternately right and left of x = 0 until there are enough relations
+    let target = base.primes.len() + 1 + EXTRA_RELATIONS;
+    let mut relations = vec![];
+    let mut block = 0_i128;
+    while relations.len() < target {
+        sieve_block(n, m, block * BLOCK as i128, &base, &mut relations);
+        sieve_block(n, m, -(block + 1) * BLOCK as i128, &base, &mut relations);
+        block += 1;
+        // Give up on inputs that are too large or prime beyond the u64 range
+        if block > 1 << 12 {
+            return None;
+        }
+    }
+
+    // Exponent parities: one row per sign or prime, one column per relation
+    let mut matrix = BitMatrix::zeros(base.primes.len() + 1, relations.len());
+    for (j, r) in relations.iter().enumerate() {
+        for &(i, e) in &r.exponents {
+            if e % 2 == 1 {
+                matrix.set(i, j, true);
+            }
+        }
+    }
+
+    let dependencies = matrix.null_space();
+    (0..dependencies.rows()).find_map(|k| {
+        let subset = dependencies
+            .row_ones(k)
+            .map(|j| &relations[j])
+            .collect::<Vec<_>>();
+        factor_from_dependency(n, &base, &subset)
+    })
+}
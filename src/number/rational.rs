@@ -0,0 +1,295 @@
+//! Rational Numbers
+//! Exact signed fractions n / d stored in lowest terms with d > 0.
+
+use std::{
+    cmp::Ordering,
+    fmt::Display,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+use super::gcd_u128;
+use crate::{error::AlgebraError, field::FieldBase, ring::RingBase};
+
+/// Signed rational number
+/// Numerator and denominator are kept coprime with a positive denominator,
+/// so structural equality is equality of rationals.
+/// Arithmetic is checked and panics on overflow instead of silently wrapping,
+/// the `checked_*` methods return None instead.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rational {
+    num: i128,
+    den: i128,
+}
+
+impl Rational {
+    /// Create a new rational n / d
+    /// Panics if the denominator is zero, see `TryFrom<(i128, i128)>`.
+    pub fn new(num: i128, den: i128) -> Self {
+        if den == 0 {
+            panic!("Rational with zero denominator");
+        }
+        Self::reduced(num, den).unwrap_or_else(|| overflow())
+    }
+
+    /// Integer n / 1
+    pub const fn integer(num: i128) -> Self {
+        Self { num, den: 1 }
+    }
+
+    /// Numerator
+    pub fn num(&self) -> i128 {
+        self.num
+    }
+
+    /// Denominator (always positive)
+    pub fn den(&self) -> i128 {
+        self.den
+    }
+
+    /// Whether the denominator is one
+    pub fn is_integer(&self) -> bool {
+        self.den == 1
+    }
+
+    /// Absolute value
+    pub fn abs(&self) -> Self {
+        match self.num < 0 {
+            true => -*self,
+            false => *self,
+        }
+    }
+
+    /// n / d in lowest terms with positive denominator, None if it does not fit
+    fn reduced(num: i128, den: i128) -> Option<Self> {
+        let gcd = gcd_u128(num.unsigned_abs(), den.unsigned_abs());
+        // gcd = 2^127 only for num = den = i128::MIN
+        let gcd = i128::try_from(gcd).unwrap_or(i128::MIN);
+        let (num, den) = (num / gcd, den / gcd);
+        match den < 0 {
+            true => Some(Self {
+                num: num.checked_neg()?,
+                den: den.checked_neg()?,
+            }),
+            false => Some(Self { num, den }),
+        }
+    }
+
+    /// Negation, None on overflow
+    pub fn checked_neg(&self) -> Option<Self> {
+        Some(Self {
+            num: self.num.checked_neg()?,
+            den: self.den,
+        })
+    }
+
+    /// Sum, None on overflow
+    pub fn checked_add(&self, other: &Self) -> Option<Self> {
+        // Scale by lcm(b, d) instead of b d to delay overflow
+        let g = gcd_u128(self.den as u128, other.den as u128) as i128;
+        let (b, d) = (self.den / g, other.den / g);
+        let num = self
+            .num
+            .checked_mul(d)?
+            .checked_add(other.num.checked_mul(b)?)?;
+        Self::reduced(num, self.den.checked_mul(d)?)
+    }
+
+    /// Difference, None on overflow
+    pub fn checked_sub(&self, other: &Self) -> Option<Self> {
+        self.checked_add(&other.checked_neg()?)
+    }
+
+    /// Product, None on overflow
+    pub fn checked_mul(&self, other: &Self) -> Option<Self> {
+        // Cross-cancel before multiplying to delay overflow
+        let g1 = gcd_u128(self.num.unsigned_abs(), other.den as u128).max(1) as i128;
+        let g2 = gcd_u128(other.num.unsigned_abs(), self.den as u128).max(1) as i128;
+        Some(Self {
+            num: (self.num / g1).checked_mul(other.num / g2)?,
+            den: (self.den / g2).checked_mul(other.den / g1)?,
+        })
+    }
+
+    /// Quotient, None for division by zero or on overflow
+    pub fn checked_div(&self, other: &Self) -> Option<Self> {
+        self.checked_mul(&other.checked_inverse()?)
+    }
+}
+
+impl TryFrom<(i128, i128)> for Rational {
+    type Error = AlgebraError;
+
+    /// Rational (n, d), failing for a zero denominator
+    fn try_from((num, den): (i128, i128)) -> Result<Self, AlgebraError> {
+        match den {
+            0 => Err(AlgebraError::ZeroDenominator),
+            _ => Ok(Self::new(num, den)),
+        }
+    }
+}
+
+impl Display for Rational {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.den {
+            1 => write!(f, "{}", self.num),
+            den => write!(f, "{}/{den}", self.num),
+        }
+    }
+}
+
+/// Compare positive fractions a_0 / a_1 and b_0 / b_1 by their continued fractions, without overflow
+fn cmp_positive(mut a: (u128, u128), mut b: (u128, u128)) -> Ordering {
+    loop {
+        let (qa, qb) = (a.0 / a.1, b.0 / b.1);
+        if qa != qb {
+            return qa.cmp(&qb);
+        }
+        let (ra, rb) = (a.0 % a.1, b.0 % b.1);
+        match (ra, rb) {
+            (0, 0) => return Ordering::Equal,
+            (0, _) => return Ordering::Less,
+            (_, 0) => return Ordering::Greater,
+            // ra / a_1 < rb / b_1 iff b_1 / rb < a_1 / ra
+            _ => (a, b) = ((b.1, rb), (a.1, ra)),
+        }
+    }
+}
+
+impl Ord for Rational {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let (a, b) = (
+            (self.num.unsigned_abs(), self.den as u128),
+            (other.num.unsigned_abs(), other.den as u128),
+        );
+        match (self.num.signum(), other.num.signum()) {
+            (s, t) if s != t => s.cmp(&t),
+            (0, _) => Ordering::Equal,
+            (1, _) => cmp_positive(a, b),
+            _ => cmp_positive(b, a),
+        }
+    }
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl RingBase for Rational {
+    fn zero() -> Self {
+        Self::integer(0)
+    }
+
+    fn one() -> Self {
+        Self::integer(1)
+    }
+}
+
+impl FieldBase for Rational {
+    /// Inverse d / n
+    /// Panics for zero.
+    fn inverse(&self) -> Self {
+        if self.num == 0 {
+            panic!("Division by zero");
+        }
+        Self::new(self.den, self.num)
+    }
+
+    /// Inverse d / n, None for zero or on overflow
+    fn checked_inverse(&self) -> Option<Self> {
+        match self.num {
+            0 => None,
+            _ => Self::reduced(self.den, self.num),
+        }
+    }
+}
+
+#[inline(always)]
+fn overflow() -> ! {
+    panic!("Rational arithmetic overflow")
+}
+
+impl Neg for Rational {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        self.checked_neg().unwrap_or_else(|| overflow())
+    }
+}
+
+impl Add for Rational {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        self.checked_add(&other).unwrap_or_else(|| overflow())
+    }
+}
+
+impl Sub for Rational {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        self.checked_sub(&other).unwrap_or_else(|| overflow())
+    }
+}
+
+impl Mul for Rational {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        self.checked_mul(&other).unwrap_or_else(|| overflow())
+    }
+}
+
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl Div for Rational {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        if other.num == 0 {
+            panic!("Division by zero");
+        }
+        self * other.inverse()
+    }
+}
+
+macro_rules! impl_rational_ops {
+    ($trait:ident, $method:ident, $assignTrait:ident, $assignMethod:ident) => {
+        impl<'b> $trait<&'b Rational> for &Rational {
+            type Output = Rational;
+            fn $method(self, other: &'b Rational) -> Rational {
+                (*self).$method(*other)
+            }
+        }
+        impl $assignTrait for Rational {
+            fn $assignMethod(&mut self, other: Rational) {
+                *self = (*self).$method(other)
+            }
+        }
+        impl<'a> $assignTrait<&'a Rational> for Rational {
+            fn $assignMethod(&mut self, other: &'a Rational) {
+                *self = (*self).$method(*other)
+            }
+        }
+    };
+}
+
+impl_rational_ops!(Add, add, AddAssign, add_assign);
+impl_rational_ops!(Sub, sub, SubAssign, sub_assign);
+impl_rational_ops!(Mul, mul, MulAssign, mul_assign);
+impl_rational_ops!(Div, div, DivAssign, div_assign);
+
+impl Neg for &Rational {
+    type Output = Rational;
+
+    fn neg(self) -> Rational {
+        -*self
+    }
+}
+
+impl From<i128> for Rational {
+    fn from(value: i128) -> Self {
+        Self::integer(value)
+    }
+}
@@ -0,0 +1,84 @@
+//! Classical Number Sequences
+//! Exact values as rationals.
+//! Rationals are backed by i128, so the tables are exact up to B_56 and H_88.
+//! Past that the arithmetic overflows: the plain functions panic, the `checked_` ones return None.
+
+use super::Rational;
+
+/// Bernoulli numbers B_0, B_1, ..., B_n
+/// https://en.wikipedia.org/wiki/Bernoulli_number
+/// Computed with the Akiyama-Tanigawa algorithm, using the convention B_1 = -1/2.
+/// Every step of the algorithm yields the next Bernoulli number, so the whole table costs O(n^2) operations.
+/// Panics on overflow, for n >= 58, see `checked_bernoulli_numbers`.
+pub fn bernoulli_numbers(n: usize) -> Vec<Rational> {
+    checked_bernoulli_numbers(n).expect("Bernoulli number overflow")
+}
+
+/// Bernoulli numbers B_0, B_1, ..., B_n, None on overflow, for n >= 58
+pub fn checked_bernoulli_numbers(n: usize) -> Option<Vec<Rational>> {
+    let mut row = Vec::with_capacity(n + 1);
+    let mut numbers = Vec::with_capacity(n + 1);
+
+    for m in 0..=n {
+        row.push(Rational::new(1, m as i128 + 1));
+        for j in (1..=m).rev() {
+            row[j - 1] =
+                Rational::integer(j as i128).checked_mul(&row[j - 1].checked_sub(&row[j])?)?;
+        }
+        numbers.push(row[0]);
+    }
+
+    // The algorithm yields B_1 = +1/2
+    if n >= 1 {
+        numbers[1] = -numbers[1];
+    }
+
+    Some(numbers)
+}
+
+/// Bernoulli number B_n
+/// Uses the convention B_1 = -1/2.
+/// Panics on overflow, for even n >= 58, see `checked_bernoulli`.
+pub fn bernoulli(n: usize) -> Rational {
+    checked_bernoulli(n).expect("Bernoulli number overflow")
+}
+
+/// Bernoulli number B_n, None on overflow, for even n >= 58
+pub fn checked_bernoulli(n: usize) -> Option<Rational> {
+    match n {
+        1 => Some(Rational::new(-1, 2)),
+        // Odd Bernoulli numbers beyond B_1 vanish
+        n if n % 2 == 1 => Some(Rational::integer(0)),
+        n => checked_bernoulli_numbers(n).map(|numbers| numbers[n]),
+    }
+}
+
+/// Harmonic numbers H_0, H_1, ..., H_n
+/// $$ H_n = \sum_{k=1}^n \frac{1}{k} $$
+/// Panics on overflow, for n >= 89, see `checked_harmonic_numbers`.
+pub fn harmonic_numbers(n: usize) -> Vec<Rational> {
+    checked_harmonic_numbers(n).expect("Harmonic number overflow")
+}
+
+/// Harmonic numbers H_0, H_1, ..., H_n, None on overflow, for n >= 89
+pub fn checked_harmonic_numbers(n: usize) -> Option<Vec<Rational>> {
+    let mut numbers = Vec::with_capacity(n + 1);
+    numbers.push(Rational::integer(0));
+    for k in 1..=n {
+        numbers.push(numbers[k - 1].checked_add(&Rational::new(1, k as i128))?);
+    }
+    Some(numbers)
+}
+
+/// Harmonic number H_n
+/// Panics on overflow, for n >= 89, see `checked_harmonic`.
+pub fn harmonic(n: usize) -> Rational {
+    checked_harmonic(n).expect("Harmonic number overflow")
+}
+
+/// Harmonic number H_n, None on overflow, for n >= 89
+pub fn checked_harmonic(n: usize) -> Option<Rational> {
+    (1..=n).try_fold(Rational::integer(0), |h, k| {
+        h.checked_add(&Rational::new(1, k as i128))
+    })
+}
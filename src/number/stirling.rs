@@ -0,0 +1,231 @@
+//! Stirling and Bell Numbers
+//! https://en.wikipedia.org/wiki/Stirling_number
+//! Exact values are u128, the arithmetic is checked and panics on overflow.
+//! Values modulo a prime P are elements of Fp.
+//! Triangles use the O(n^2) recurrences, single values modulo P use the factorial tables and a convolution.
+
+use crate::{
+    field::finite_field::{factorials::Factorials, Fp},
+    polynomial::genfunc::convolution,
+    ring::RingBase,
+};
+
+/// Stirling numbers triangle from T(0, 0) = 1 and T(m + 1, j) = w(m, j) T(m, j) + T(m, j - 1), truncated to columns j <= k
+fn triangle<T>(n: usize, k: usize, step: impl Fn(usize, usize, T, T) -> T) -> Vec<Vec<T>>
+where
+    T: RingBase + Copy,
+{
+    let mut rows: Vec<Vec<T>> = Vec::with_capacity(n + 1);
+    rows.push(vec![T::one()]);
+    for m in 0..n {
+        let prev = &rows[m];
+        let len = (m + 2).min(k + 1);
+        let row = (0..len)
+            .map(|j| {
+                let same = prev.get(j).copied().unwrap_or(T::zero());
+                let left = if j > 0 { prev[j - 1] } else { T::zero() };
+                step(m, j, same, left)
+            })
+            .collect();
+        rows.push(row);
+    }
+    rows
+}
+
+#[inline(always)]
+fn checked(value: Option<u128>) -> u128 {
+    value.expect("Stirling number overflow")
+}
+
+/// Unsigned Stirling numbers of the first kind c(m, j) for 0 <= j <= m <= n
+/// Number of permutations of m elements with j cycles, $$ c(m + 1, j) = m c(m, j) + c(m, j - 1) $$
+/// The signed numbers are s(m, j) = (-1)^{m - j} c(m, j).
+pub fn stirling_first_triangle(n: usize) -> Vec<Vec<u128>> {
+    triangle(n, n, |m, _, same, left| {
+        checked(
+            (m as u128)
+                .checked_mul(same)
+                .and_then(|x| x.checked_add(left)),
+        )
+    })
+}
+
+/// Stirling numbers of the second kind S(m, j) for 0 <= j <= m <= n
+/// Number of partitions of m elements into j blocks, $$ S(m + 1, j) = j S(m, j) + S(m, j - 1) $$
+pub fn stirling_second_triangle(n: usize) -> Vec<Vec<u128>> {
+    triangle(n, n, |_, j, same, left| {
+        checked(
+            (j as u128)
+                .checked_mul(same)
+                .and_then(|x| x.checked_add(left)),
+        )
+    })
+}
+
+/// Single entry T(n, k) of a Stirling triangle, row m restricted to the band k - (n - m) <= j <= k
+/// The column grows by at most one per row, so entries left of the band never reach T(n, k).
+fn triangle_entry(n: usize, k: usize, step: impl Fn(usize, usize, u128, u128) -> u128) -> u128 {
+    if k > n {
+        return 0;
+    }
+    // row[j - lo] = T(m, j) for lo <= j <= min(m, k)
+    let mut lo = 0;
+    let mut row = vec![1_u128];
+    for m in 0..n {
+        let next_lo = k.saturating_sub(n - m - 1);
+        let get = |j: usize| {
+            j.checked_sub(lo)
+                .and_then(|i| row.get(i))
+                .copied()
+                .unwrap_or(0)
+        };
+        row = (next_lo..=(m + 1).min(k))
+            .map(|j| {
+                let left = if j > 0 { get(j - 1) } else { 0 };
+                step(m, j, get(j), left)
+            })
+            .collect();
+        lo = next_lo;
+    }
+    row[k - lo]
+}
+
+/// Unsigned Stirling number of the first kind c(n, k)
+/// Every intermediate value is at most c(n, k), so this only panics if the result itself overflows.
+pub fn stirling_first(n: usize, k: usize) -> u128 {
+    triangle_entry(n, k, |m, _, same, left| {
+        checked(
+            (m as u128)
+                .checked_mul(same)
+                .and_then(|x| x.checked_add(left)),
+        )
+    })
+}
+
+/// Stirling number of the second kind S(n, k)
+/// Every intermediate value is at most S(n, k), so this only panics if the result itself overflows.
+pub fn stirling_second(n: usize, k: usize) -> u128 {
+    triangle_entry(n, k, |_, j, same, left| {
+        checked(
+            (j as u128)
+                .checked_mul(same)
+                .and_then(|x| x.checked_add(left)),
+        )
+    })
+}
+
+/// Bell numbers B_0, B_1, ..., B_n
+/// https://en.wikipedia.org/wiki/Bell_number
+/// Number of partitions of a set, $$ B_m = \sum_j S(m, j) $$
+pub fn bell_numbers(n: usize) -> Vec<u128> {
+    stirling_second_triangle(n)
+        .into_iter()
+        .map(|row| checked(row.into_iter().try_fold(0_u128, u128::checked_add)))
+        .collect()
+}
+
+/// Bell number B_n
+pub fn bell(n: usize) -> u128 {
+    bell_numbers(n)[n]
+}
+
+/// Unsigned Stirling numbers of the first kind c(m, j) modulo P for 0 <= j <= m <= n
+pub fn stirling_first_triangle_mod<const P: u64>(n: usize) -> Vec<Vec<Fp<P>>> {
+    triangle(n, n, |m, _, same, left| Fp::new(m as u64) * same + left)
+}
+
+/// Stirling numbers of the second kind S(m, j) modulo P for 0 <= j <= m <= n
+pub fn stirling_second_triangle_mod<const P: u64>(n: usize) -> Vec<Vec<Fp<P>>> {
+    triangle(n, n, |_, j, same, left| Fp::new(j as u64) * same + left)
+}
+
+/// Row c(n, 0), ..., c(n, n) modulo P
+/// Coefficients of the rising factorial x (x + 1) ... (x + n - 1), multiplied by divide and conquer.
+pub fn stirling_first_row_mod<const P: u64>(n: usize) -> Vec<Fp<P>> {
+    fn rising<const P: u64>(lo: usize, hi: usize) -> Vec<Fp<P>> {
+        match hi - lo {
+            0 => vec![Fp::one()],
+            1 => vec![Fp::new(lo as u64), Fp::one()],
+            len => convolution(&rising(lo, lo + len / 2), &rising(lo + len / 2, hi)),
+        }
+    }
+    rising(0, n)
+}
+
+/// Row S(n, 0), ..., S(n, n) modulo P
+/// Convolution of j^n / j! with (-1)^j / j!, from $$ S(n, k) = \sum_{j=0}^{k} \frac{j^n}{j!} \frac{(-1)^{k-j}}{(k-j)!} $$
+/// Panics if n >= P.
+pub fn stirling_second_row_mod<const P: u64>(n: usize) -> Vec<Fp<P>> {
+    let factorials = Factorials::<P>::new(n);
+    let powers = (0..=n)
+        .map(|j| Fp::new(j as u64).pow(n as u64) * factorials.inverse_factorial(j))
+        .collect::<Vec<_>>();
+    let signs = (0..=n)
+        .map(|j| {
+            let inv = factorials.inverse_factorial(j);
+            if j.is_multiple_of(2) {
+                inv
+            } else {
+                -inv
+            }
+        })
+        .collect::<Vec<_>>();
+    let mut row = convolution(&powers, &signs);
+    row.truncate(n + 1);
+    row
+}
+
+/// Unsigned Stirling number of the first kind c(n, k) modulo P
+pub fn stirling_first_mod<const P: u64>(n: usize, k: usize) -> Fp<P> {
+    stirling_first_row_mod(n)
+        .get(k)
+        .copied()
+        .unwrap_or(Fp::zero())
+}
+
+/// Stirling number of the second kind S(n, k) modulo P
+/// Single inclusion-exclusion sum using the factorial tables, O(k log n) operations.
+/// Panics if k >= P.
+pub fn stirling_second_mod<const P: u64>(n: usize, k: usize) -> Fp<P> {
+    if k > n {
+        return Fp::zero();
+    }
+    // k! S(n, k) = \sum_j (-1)^{k-j} C(k, j) j^n
+    let factorials = Factorials::<P>::new(k);
+    let sum = (0..=k).fold(Fp::zero(), |acc, j| {
+        let term = factorials.binomial(k, j) * Fp::new(j as u64).pow(n as u64);
+        if (k - j).is_multiple_of(2) {
+            acc + term
+        } else {
+            acc - term
+        }
+    });
+    sum * factorials.inverse_factorial(k)
+}
+
+/// Bell numbers B_0, ..., B_n modulo P using the Bell triangle
+/// https://en.wikipedia.org/wiki/Bell_triangle
+pub fn bell_numbers_mod<const P: u64>(n: usize) -> Vec<Fp<P>> {
+    let mut numbers = Vec::with_capacity(n + 1);
+    let mut row = vec![Fp::<P>::one()];
+    for _ in 0..=n {
+        numbers.push(row[0]);
+        // Next row starts with the last entry, each entry adds its upper neighbour
+        let mut next = Vec::with_capacity(row.len() + 1);
+        next.push(*row.last().unwrap());
+        for &x in &row {
+            let last = *next.last().unwrap();
+            next.push(last + x);
+        }
+        row = next;
+    }
+    numbers
+}
+
+/// Bell number B_n modulo P, as the sum of the row S(n, k)
+/// Panics if n >= P.
+pub fn bell_mod<const P: u64>(n: usize) -> Fp<P> {
+    stirling_second_row_mod::<P>(n)
+        .into_iter()
+        .fold(Fp::zero(), |acc, s| acc + s)
+}
@@ -0,0 +1,364 @@
+//! Polynomials in Characteristic 2
+//! https://en.wikipedia.org/wiki/Carry-less_product
+//! Bit-packed polynomials over GF(2), 64 coefficients per machine word.
+//! Addition is a word-wise XOR and multiplication multiplies whole words with a carry-less product,
+//! switching to Karatsuba for long operands.
+//! Polynomials over GF(2^N) are multiplied by Kronecker substitution: each coefficient is packed into a
+//! slot of 2N - 1 bits, so the coefficients of the packed product are the unreduced coefficient products.
+
+use std::fmt::Display;
+use std::ops::{Add, Mul, Sub};
+
+use super::Polynomial;
+use crate::field::finite_field::{
+    binary::{clmul64, reduce, Gf2e},
+    Fp,
+};
+use crate::ring::RingBase;
+
+/// Operands with fewer words than this are multiplied by schoolbook multiplication
+const KARATSUBA_THRESHOLD: usize = 24;
+
+/// Dense bit-packed polynomial over GF(2)
+/// Bit i of the word sequence is the coefficient of x^i, the last word is never zero.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct BitPolynomial {
+    words: Vec<u64>,
+}
+
+impl BitPolynomial {
+    /// Zero polynomial
+    pub fn zero() -> Self {
+        Self { words: vec![] }
+    }
+
+    /// Constant polynomial 1
+    pub fn one() -> Self {
+        Self { words: vec![1] }
+    }
+
+    /// Monomial x^k
+    pub fn monomial(k: usize) -> Self {
+        let mut words = vec![0; k / 64 + 1];
+        words[k / 64] = 1 << (k % 64);
+        Self { words }
+    }
+
+    /// Polynomial with the given packed coefficients, lowest degree first
+    pub fn from_words(words: Vec<u64>) -> Self {
+        let mut poly = Self { words };
+        poly.normalize();
+        poly
+    }
+
+    /// Polynomial with the given coefficients, lowest degree first
+    pub fn from_bits(bits: &[bool]) -> Self {
+        let mut words = vec![0; bits.len().div_ceil(64)];
+        for (i, _) in bits.iter().enumerate().filter(|(_, bit)| **bit) {
+            words[i / 64] |= 1 << (i % 64);
+        }
+        Self::from_words(words)
+    }
+
+    fn normalize(&mut self) {
+        while self.words.last() == Some(&0) {
+            self.words.pop();
+        }
+    }
+
+    /// Packed coefficients, lowest degree first
+    pub fn words(&self) -> &[u64] {
+        &self.words
+    }
+
+    /// Coefficients, lowest degree first
+    pub fn bits(&self) -> Vec<bool> {
+        (0..self.degree().map_or(0, |d| d + 1))
+            .map(|i| self.coeff(i))
+            .collect()
+    }
+
+    /// Whether this is the zero polynomial
+    pub fn is_zero(&self) -> bool {
+        self.words.is_empty()
+    }
+
+    /// Degree, None for the zero polynomial
+    pub fn degree(&self) -> Option<usize> {
+        let last = self.words.last()?;
+        Some(64 * (self.words.len() - 1) + 63 - last.leading_zeros() as usize)
+    }
+
+    /// Coefficient of x^k
+    pub fn coeff(&self, k: usize) -> bool {
+        self.words
+            .get(k / 64)
+            .is_some_and(|w| (w >> (k % 64)) & 1 == 1)
+    }
+
+    /// Square, which in characteristic 2 spreads the coefficients: (\sum a_i x^i)^2 = \sum a_i x^{2i}
+    pub fn square(&self) -> Self {
+        let spread = |half: u32| {
+            let mut x = half as u64;
+            x = (x | (x << 16)) & 0x0000_ffff_0000_ffff;
+            x = (x | (x << 8)) & 0x00ff_00ff_00ff_00ff;
+            x = (x | (x << 4)) & 0x0f0f_0f0f_0f0f_0f0f;
+            x = (x | (x << 2)) & 0x3333_3333_3333_3333;
+            (x | (x << 1)) & 0x5555_5555_5555_5555
+        };
+        Self::from_words(
+            self.words
+                .iter()
+                .flat_map(|&w| [spread(w as u32), spread((w >> 32) as u32)])
+                .collect(),
+        )
+    }
+
+    /// Euclidean division
+    /// Returns the quotient q and remainder r such that self = q divisor + r with deg r < deg divisor.
+    /// Panics if the divisor is the zero polynomial.
+    pub fn div_rem(&self, divisor: &Self) -> (Self, Self) {
+        let Some(divisor_degree) = divisor.degree() else {
+            panic!("Polynomial division by zero");
+        };
+        let mut remainder = self.words.clone();
+        let Some(degree) = self.degree().filter(|&d| d >= divisor_degree) else {
+            return (Self::zero(), self.clone());
+        };
+        let mut quotient = vec![0; (degree - divisor_degree) / 64 + 1];
+
+        // Cancel the leading term of the remainder, highest degree first
+        for shift in (0..=degree - divisor_degree).rev() {
+            let i = shift + divisor_degree;
+            if (remainder[i / 64] >> (i % 64)) & 1 == 1 {
+                quotient[shift / 64] |= 1 << (shift % 64);
+                xor_shifted(&mut remainder, &divisor.words, shift);
+            }
+        }
+        (Self::from_words(quotient), Self::from_words(remainder))
+    }
+
+    /// Greatest common divisor by Euclid's algorithm
+    pub fn gcd(&self, other: &Self) -> Self {
+        let (mut a, mut b) = (self.clone(), other.clone());
+        while !b.is_zero() {
+            (a, b) = (b.clone(), a.div_rem(&b).1);
+        }
+        a
+    }
+}
+
+/// dst += src x^shift, dst must be long enough to hold the result
+fn xor_shifted(dst: &mut [u64], src: &[u64], shift: usize) {
+    let (words, bits) = (shift / 64, shift % 64);
+    for (i, &w) in src.iter().enumerate() {
+        dst[i + words] ^= w << bits;
+        if bits != 0 && w >> (64 - bits) != 0 {
+            dst[i + words + 1] ^= w >> (64 - bits);
+        }
+    }
+}
+
+/// out += a b by word-wise carry-less products
+fn mul_schoolbook(a: &[u64], b: &[u64], out: &mut [u64]) {
+    for (i, &x) in a.iter().enumerate() {
+        for (j, &y) in b.iter().enumerate() {
+            let product = clmul64(x, y);
+            out[i + j] ^= product as u64;
+            out[i + j + 1] ^= (product >> 64) as u64;
+        }
+    }
+}
+
+/// out += a b, out must have room for a.len() + b.len() words
+/// Karatsuba: with a = a_0 + a_1 y and b = b_0 + b_1 y, the middle term is
+/// (a_0 + a_1)(b_0 + b_1) - a_0 b_0 - a_1 b_1, where subtraction is XOR.
+fn mul_karatsuba(a: &[u64], b: &[u64], out: &mut [u64]) {
+    let (a, b) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    if b.len() < KARATSUBA_THRESHOLD {
+        return mul_schoolbook(a, b, out);
+    }
+    let half = a.len().div_ceil(2);
+    let (a0, a1) = a.split_at(half);
+    if b.len() <= half {
+        // Unbalanced: split only the longer operand
+        mul_karatsuba(a0, b, &mut out[..half + b.len()]);
+        mul_karatsuba(a1, b, &mut out[half..]);
+        return;
+    }
+    let (b0, b1) = b.split_at(half);
+
+    let mut low = vec![0; 2 * half];
+    mul_karatsuba(a0, b0, &mut low);
+    let mut high = vec![0; a1.len() + b1.len()];
+    mul_karatsuba(a1, b1, &mut high);
+
+    let sum = |x: &[u64], y: &[u64]| -> Vec<u64> {
+        let mut s = x.to_vec();
+        s.iter_mut().zip(y).for_each(|(s, y)| *s ^= y);
+        s
+    };
+    let mut middle = vec![0; 2 * half];
+    mul_karatsuba(&sum(a0, a1), &sum(b0, b1), &mut middle);
+    middle.iter_mut().zip(&low).for_each(|(m, l)| *m ^= l);
+    middle.iter_mut().zip(&high).for_each(|(m, h)| *m ^= h);
+
+    out.iter_mut().zip(&low).for_each(|(o, l)| *o ^= l);
+    out[2 * half..]
+        .iter_mut()
+        .zip(&high)
+        .for_each(|(o, h)| *o ^= h);
+    out[half..]
+        .iter_mut()
+        .zip(&middle)
+        .for_each(|(o, m)| *o ^= m);
+}
+
+/// Formats as "1 + x + x^3"
+impl Display for BitPolynomial {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_zero() {
+            return write!(f, "0");
+        }
+        let terms = (0..=self.degree().unwrap())
+            .filter(|&i| self.coeff(i))
+            .map(|i| match i {
+                0 => "1".to_string(),
+                1 => "x".to_string(),
+                _ => format!("x^{i}"),
+            })
+            .collect::<Vec<_>>();
+        write!(f, "{}", terms.join(" + "))
+    }
+}
+
+impl Add for &BitPolynomial {
+    type Output = BitPolynomial;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn add(self, other: Self) -> BitPolynomial {
+        let (long, short) = if self.words.len() >= other.words.len() {
+            (self, other)
+        } else {
+            (other, self)
+        };
+        let mut words = long.words.clone();
+        words
+            .iter_mut()
+            .zip(&short.words)
+            .for_each(|(w, s)| *w ^= s);
+        BitPolynomial::from_words(words)
+    }
+}
+
+impl Add for BitPolynomial {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        &self + &other
+    }
+}
+
+impl Sub for &BitPolynomial {
+    type Output = BitPolynomial;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn sub(self, other: Self) -> BitPolynomial {
+        self + other
+    }
+}
+
+impl Sub for BitPolynomial {
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn sub(self, other: Self) -> Self {
+        &self + &other
+    }
+}
+
+impl Mul for &BitPolynomial {
+    type Output = BitPolynomial;
+
+    fn mul(self, other: Self) -> BitPolynomial {
+        if self.is_zero() || other.is_zero() {
+            return BitPolynomial::zero();
+        }
+        let mut words = vec![0; self.words.len() + other.words.len()];
+        mul_karatsuba(&self.words, &other.words, &mut words);
+        BitPolynomial::from_words(words)
+    }
+}
+
+impl Mul for BitPolynomial {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        &self * &other
+    }
+}
+
+impl From<&Polynomial<Fp<2>>> for BitPolynomial {
+    fn from(poly: &Polynomial<Fp<2>>) -> Self {
+        Self::from_bits(
+            &poly
+                .coeffs
+                .iter()
+                .map(|c| c.value() == 1)
+                .collect::<Vec<_>>(),
+        )
+    }
+}
+
+impl From<&BitPolynomial> for Polynomial<Fp<2>> {
+    fn from(poly: &BitPolynomial) -> Self {
+        Polynomial::from_coeffs(
+            poly.bits()
+                .into_iter()
+                .map(|bit| if bit { Fp::one() } else { Fp::zero() })
+                .collect(),
+        )
+    }
+}
+
+impl Polynomial<Fp<2>> {
+    /// Product through the bit-packed representation
+    pub fn mul_clmul(&self, other: &Self) -> Self {
+        (&(&BitPolynomial::from(self) * &BitPolynomial::from(other))).into()
+    }
+}
+
+impl<const N: u32> Polynomial<Gf2e<N>> {
+    /// Width of a Kronecker slot, enough for an unreduced product of two coefficients
+    const SLOT: usize = 2 * N as usize - 1;
+
+    fn pack(&self) -> BitPolynomial {
+        let mut words = vec![0; (self.coeffs.len() * Self::SLOT).div_ceil(64) + 1];
+        for (i, c) in self.coeffs.iter().enumerate() {
+            xor_shifted(&mut words, &[c.value() as u64], i * Self::SLOT);
+        }
+        BitPolynomial::from_words(words)
+    }
+
+    /// Product by Kronecker substitution into GF(2)[x]
+    /// Sums of coefficient products do not carry in characteristic 2, so slot k of the packed product
+    /// holds \sum_{i + j = k} a_i b_j before reduction modulo the field polynomial.
+    pub fn mul_clmul(&self, other: &Self) -> Self {
+        let (Some(da), Some(db)) = (self.degree(), other.degree()) else {
+            return Self::zero();
+        };
+        let product = &self.pack() * &other.pack();
+        let words = product.words();
+        let slot = |k: usize| -> u64 {
+            let start = k * Self::SLOT;
+            let (i, bits) = (start / 64, start % 64);
+            let low = words.get(i).map_or(0, |w| w >> bits);
+            let high = match bits {
+                0 => 0,
+                _ => words.get(i + 1).map_or(0, |w| w << (64 - bits)),
+            };
+            (low | high) & ((1 << Self::SLOT) - 1)
+        };
+        Self::from_coeffs((0..=da + db).map(|k| Gf2e(reduce::<N>(slot(k)))).collect())
+    }
+}
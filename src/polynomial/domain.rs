@@ -0,0 +1,210 @@
+//! Evaluation Domains
+//! https://en.wikipedia.org/wiki/Polynomial_interpolation
+//! A polynomial of degree below n is determined by its values on the n-th roots of unity
+//! $$ D = \{1, w, w^2, ..., w^{n-1}\} $$
+//! In this form addition and multiplication are pointwise, O(n), and the transforms to and from
+//! coefficients are NTTs that reuse the roots and radix plan stored in the domain.
+//! Pointwise products are products modulo the vanishing polynomial x^n - 1, so they are exact
+//! as long as the degrees of the factors add up to less than n.
+
+use std::ops::{Add, Mul, Sub};
+
+use super::{
+    ntt::{dft, prime_factors, root_of_unity, transform_length},
+    Polynomial,
+};
+use crate::{
+    field::{finite_field::Fp, FieldBase},
+    ring::RingBase,
+};
+
+/// Multiplicative subgroup of the n-th roots of unity in Fp, with precomputed transform data
+#[derive(Debug, Clone, PartialEq)]
+pub struct EvaluationDomain<const P: u64> {
+    root: Fp<P>,
+    root_inv: Fp<P>,
+    size_inv: Fp<P>,
+    elements: Vec<Fp<P>>,
+    factors: Vec<usize>,
+}
+
+impl<const P: u64> EvaluationDomain<P> {
+    /// Domain of size n, None unless n > 0 divides P - 1
+    pub fn new(size: usize) -> Option<Self> {
+        let root = root_of_unity::<P>(size)?;
+        let elements = std::iter::successors(Some(Fp::one()), |w| Some(*w * root))
+            .take(size)
+            .collect();
+        Some(Self {
+            root,
+            root_inv: root.inverse(),
+            size_inv: Fp::new(size as u64).inverse(),
+            elements,
+            factors: prime_factors(size),
+        })
+    }
+
+    /// Smallest domain with at least n points
+    pub fn containing(n: usize) -> Option<Self> {
+        Self::new(transform_length::<P>(n.max(1))?)
+    }
+
+    pub fn size(&self) -> usize {
+        self.elements.len()
+    }
+
+    /// Generator w of the domain
+    pub fn root(&self) -> Fp<P> {
+        self.root
+    }
+
+    /// Points w^i, i < n
+    pub fn elements(&self) -> &[Fp<P>] {
+        &self.elements
+    }
+
+    /// Vanishing polynomial x^n - 1, zero exactly on the domain
+    pub fn vanishing_polynomial(&self) -> Polynomial<Fp<P>> {
+        let mut coeffs = vec![Fp::zero(); self.size() + 1];
+        coeffs[0] = Fp::zero() - Fp::one();
+        coeffs[self.size()] = Fp::one();
+        Polynomial::from_coeffs(coeffs)
+    }
+
+    /// Values of f on the domain
+    /// Panics unless deg f < n.
+    pub fn evaluate(&self, f: &Polynomial<Fp<P>>) -> Evaluations<'_, P> {
+        assert!(
+            f.coeffs.len() <= self.size(),
+            "Polynomial degree must be less than the domain size"
+        );
+        let mut coeffs = f.coeffs.clone();
+        coeffs.resize(self.size(), Fp::zero());
+        self.with_values(dft(&coeffs, self.root, &self.factors))
+    }
+
+    /// Polynomial of degree below n with the given values
+    pub fn interpolate(&self, evaluations: &Evaluations<'_, P>) -> Polynomial<Fp<P>> {
+        self.check(evaluations);
+        Polynomial::from_coeffs(
+            dft(&evaluations.values, self.root_inv, &self.factors)
+                .into_iter()
+                .map(|c| c * self.size_inv)
+                .collect(),
+        )
+    }
+
+    /// Evaluations from the values at w^0, w^1, ...
+    /// Panics unless there is one value per point.
+    pub fn with_values(&self, values: Vec<Fp<P>>) -> Evaluations<'_, P> {
+        assert_eq!(values.len(), self.size(), "One value per domain point");
+        Evaluations {
+            domain: self,
+            values,
+        }
+    }
+
+    /// Constant function c
+    pub fn constant(&self, c: Fp<P>) -> Evaluations<'_, P> {
+        self.with_values(vec![c; self.size()])
+    }
+
+    fn check(&self, evaluations: &Evaluations<'_, P>) {
+        assert!(
+            self.size() == evaluations.domain.size(),
+            "Evaluations belong to a different domain"
+        );
+    }
+}
+
+/// Polynomial of degree below n stored by its values on an evaluation domain
+#[derive(Debug, Clone, PartialEq)]
+pub struct Evaluations<'a, const P: u64> {
+    domain: &'a EvaluationDomain<P>,
+    values: Vec<Fp<P>>,
+}
+
+impl<'a, const P: u64> Evaluations<'a, P> {
+    pub fn domain(&self) -> &'a EvaluationDomain<P> {
+        self.domain
+    }
+
+    /// Values at w^0, w^1, ...
+    pub fn values(&self) -> &[Fp<P>] {
+        &self.values
+    }
+
+    /// Back to coefficient form
+    pub fn interpolate(&self) -> Polynomial<Fp<P>> {
+        self.domain.interpolate(self)
+    }
+
+    /// Value at any point by the barycentric formula, O(n)
+    /// $$ f(x) = \frac{x^n - 1}{n} \sum_i \frac{f(w^i) w^i}{x - w^i} $$
+    pub fn evaluate_at(&self, x: Fp<P>) -> Fp<P> {
+        let n = self.domain.size();
+        if let Some(i) = self.domain.elements.iter().position(|w| *w == x) {
+            return self.values[i];
+        }
+
+        // Common denominator \prod (x - w^i) with prefix and suffix products, one inversion
+        let differences = self
+            .domain
+            .elements
+            .iter()
+            .map(|w| x - *w)
+            .collect::<Vec<_>>();
+        let mut prefix = Vec::with_capacity(n + 1);
+        prefix.push(Fp::one());
+        for d in &differences {
+            prefix.push(*prefix.last().unwrap() * *d);
+        }
+        let mut suffix = Fp::one();
+        let mut sum = Fp::zero();
+        for i in (0..n).rev() {
+            // \prod_{j != i} (x - w^j)
+            let others = prefix[i] * suffix;
+            sum += self.values[i] * self.domain.elements[i] * others;
+            suffix *= differences[i];
+        }
+        let vanishing = x.pow(n as u64) - Fp::one();
+        vanishing * self.domain.size_inv * sum * prefix[n].inverse()
+    }
+
+    fn zip_with(&self, other: &Self, op: impl Fn(Fp<P>, Fp<P>) -> Fp<P>) -> Self {
+        self.domain.check(other);
+        Evaluations {
+            domain: self.domain,
+            values: self
+                .values
+                .iter()
+                .zip(&other.values)
+                .map(|(a, b)| op(*a, *b))
+                .collect(),
+        }
+    }
+}
+
+macro_rules! impl_pointwise_ops {
+    ($trait:ident, $method:ident) => {
+        impl<'a, const P: u64> $trait<&Evaluations<'a, P>> for &Evaluations<'a, P> {
+            type Output = Evaluations<'a, P>;
+
+            fn $method(self, other: &Evaluations<'a, P>) -> Evaluations<'a, P> {
+                self.zip_with(other, Fp::$method)
+            }
+        }
+
+        impl<'a, const P: u64> $trait for Evaluations<'a, P> {
+            type Output = Evaluations<'a, P>;
+
+            fn $method(self, other: Evaluations<'a, P>) -> Evaluations<'a, P> {
+                (&self).$method(&other)
+            }
+        }
+    };
+}
+
+impl_pointwise_ops!(Add, add);
+impl_pointwise_ops!(Sub, sub);
+impl_pointwise_ops!(Mul, mul);
@@ -0,0 +1,292 @@
+//! Factorization over Finite Fields
+//! https://en.wikipedia.org/wiki/Factorization_of_polynomials_over_finite_fields
+//! Complete factorization of polynomials in Fp[x] into monic irreducible factors.
+//! Both backends start from the square-free decomposition. Cantor-Zassenhaus then separates factors by degree
+//! and splits each part with random gcds, Berlekamp splits with the fixed space of the Frobenius map.
+
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use super::Polynomial;
+use crate::{field::finite_field::Fp, matrix::Matrix, ring::RingBase};
+
+type Poly<const P: u64> = Polynomial<Fp<P>>;
+
+/// Berlekamp tries every constant of the field to split factors, so `Auto` only picks it below this size
+const BERLEKAMP_MAX_P: u64 = 1 << 10;
+
+/// Fixed seed, so factorizations are reproducible
+const SEED: u64 = 0x5eed;
+
+/// Factorization backend
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FactorAlgorithm {
+    /// Berlekamp for small fields, Cantor-Zassenhaus otherwise
+    #[default]
+    Auto,
+    /// Distinct-degree factorization followed by randomized equal-degree splitting
+    CantorZassenhaus,
+    /// Null space of the Frobenius map minus the identity, split by gcds with every constant
+    Berlekamp,
+}
+
+fn x<const P: u64>() -> Poly<P> {
+    Poly::from_coeffs(vec![Fp::zero(), Fp::one()])
+}
+
+fn one<const P: u64>() -> Poly<P> {
+    Poly::from_coeffs(vec![Fp::one()])
+}
+
+/// Power a^e modulo f by square and multiply
+fn pow_mod<const P: u64>(a: &Poly<P>, mut e: u64, f: &Poly<P>) -> Poly<P> {
+    let mut base = a % f;
+    let mut result = &one() % f;
+    while e > 0 {
+        if e % 2 == 1 {
+            result = &(&result * &base) % f;
+        }
+        base = &(&base * &base) % f;
+        e /= 2;
+    }
+    result
+}
+
+/// Formal derivative
+fn derivative<const P: u64>(f: &Poly<P>) -> Poly<P> {
+    Poly::from_coeffs(
+        f.coeffs()
+            .iter()
+            .enumerate()
+            .skip(1)
+            .map(|(k, c)| *c * Fp::new(k as u64))
+            .collect(),
+    )
+}
+
+fn is_one<const P: u64>(f: &Poly<P>) -> bool {
+    f.degree() == Some(0)
+}
+
+/// Square-free decomposition of a monic polynomial
+/// Returns pairs (g_i, i) of monic, square-free, pairwise coprime g_i with f = \prod g_i^i, omitting trivial g_i.
+/// Uses the characteristic p variant of Yun's algorithm: when f' = 0, f is a p-th power.
+pub fn square_free_factorization<const P: u64>(
+    f: &Polynomial<Fp<P>>,
+) -> Vec<(Polynomial<Fp<P>>, usize)> {
+    let f = f.monic();
+    if f.degree().unwrap_or(0) == 0 {
+        return vec![];
+    }
+    let mut factors = vec![];
+    let mut c = f.gcd(&derivative(&f));
+    let mut w = &f / &c;
+    let mut i = 1;
+    while !is_one(&w) {
+        let y = w.gcd(&c);
+        let factor = &w / &y;
+        if !is_one(&factor) {
+            factors.push((factor, i));
+        }
+        c = &c / &y;
+        w = y;
+        i += 1;
+    }
+    if !is_one(&c) {
+        // c = h^p with h(x) = sum c_{kp} x^k, as a^p = a in Fp
+        let root = Poly::from_coeffs(c.coeffs().iter().step_by(P as usize).copied().collect());
+        factors.extend(
+            square_free_factorization(&root)
+                .into_iter()
+                .map(|(g, j)| (g, j * P as usize)),
+        );
+    }
+    factors.sort_by_key(|(_, i)| *i);
+    factors
+}
+
+/// Distinct-degree factorization of a monic square-free polynomial
+/// Returns pairs (g_d, d) where g_d is the product of all irreducible factors of degree d,
+/// found as gcd(f, x^{p^d} - x).
+pub fn distinct_degree_factorization<const P: u64>(
+    f: &Polynomial<Fp<P>>,
+) -> Vec<(Polynomial<Fp<P>>, usize)> {
+    let mut f = f.monic();
+    let mut factors = vec![];
+    let mut h = &x() % &f;
+    let mut d = 1;
+    while f.degree().unwrap_or(0) >= 2 * d {
+        h = pow_mod(&h, P, &f);
+        let g = f.gcd(&(&h - &x()));
+        if !is_one(&g) {
+            f = &f / &g;
+            h = &h % &f;
+            factors.push((g, d));
+        }
+        d += 1;
+    }
+    if let Some(deg) = f.degree().filter(|&deg| deg > 0) {
+        factors.push((f, deg));
+    }
+    factors
+}
+
+/// Cantor-Zassenhaus equal-degree splitting of a monic square-free g whose irreducible factors all have degree d
+/// A random a gives b = a^{(p^d - 1) / 2} - 1 (or the trace a + a^2 + ... + a^{2^{d-1}} when p = 2),
+/// and gcd(b, g) is a proper factor about half of the time.
+fn equal_degree_split<const P: u64>(g: &Poly<P>, d: usize, rng: &mut StdRng) -> Vec<Poly<P>> {
+    let n = g.degree().unwrap();
+    let count = n / d;
+    let mut factors = vec![g.clone()];
+    while factors.len() < count {
+        let a = Poly::from_coeffs((0..n).map(|_| Fp::new(rng.random_range(0..P))).collect());
+        if a.degree().unwrap_or(0) == 0 {
+            continue;
+        }
+        let b = if P == 2 {
+            // Trace map into GF(2)
+            let mut power = &a % g;
+            let mut trace = power.clone();
+            for _ in 1..d {
+                power = &(&power * &power) % g;
+                trace = &trace + &power;
+            }
+            trace
+        } else {
+            // a^{1 + p + ... + p^{d-1}} is the norm down to Fp, then raise to (p - 1) / 2
+            let mut power = &a % g;
+            let mut norm = power.clone();
+            for _ in 1..d {
+                power = pow_mod(&power, P, g);
+                norm = &(&norm * &power) % g;
+            }
+            &pow_mod(&norm, (P - 1) / 2, g) - &one()
+        };
+        factors = factors
+            .into_iter()
+            .flat_map(|u| {
+                if u.degree() == Some(d) {
+                    return vec![u];
+                }
+                let h = u.gcd(&(&b % &u));
+                match h.degree() {
+                    Some(k) if k > 0 && Some(k) < u.degree() => {
+                        let other = &u / &h;
+                        vec![h, other]
+                    }
+                    _ => vec![u],
+                }
+            })
+            .collect();
+    }
+    factors
+}
+
+/// Berlekamp's algorithm for a monic square-free f
+/// Polynomials v with v^p = v (mod f) form a space of dimension equal to the number of irreducible factors,
+/// the null space of Q - I where row i of Q holds x^{ip} mod f.
+/// Each such v splits f as \prod_{s in Fp} gcd(f, v - s).
+fn berlekamp<const P: u64>(f: &Poly<P>) -> Vec<Poly<P>> {
+    let n = f.degree().unwrap();
+    let xp = pow_mod(&x(), P, f);
+    let mut row = &one() % f;
+    let mut q = Matrix::<Fp<P>>::zeros(n, n);
+    for i in 0..n {
+        for (j, c) in row.coeffs().iter().enumerate() {
+            q[(i, j)] = *c;
+        }
+        q[(i, i)] -= Fp::one();
+        row = &(&row * &xp) % f;
+    }
+    // v (Q - I) = 0
+    let kernel = q.transpose().null_space();
+    let count = kernel.rows();
+
+    let mut factors = vec![f.clone()];
+    for k in 0..count {
+        if factors.len() == count {
+            break;
+        }
+        let v = Poly::from_coeffs(kernel.row(k).to_vec());
+        if v.degree().unwrap_or(0) == 0 {
+            continue;
+        }
+        factors = factors
+            .into_iter()
+            .flat_map(|u| {
+                if u.degree() == Some(1) {
+                    return vec![u];
+                }
+                let mut parts = vec![];
+                let mut rest = u;
+                for s in 0..P {
+                    let g = rest.gcd(&(&v - &Poly::from_coeffs(vec![Fp::new(s)])));
+                    if g.degree().unwrap_or(0) > 0 {
+                        rest = &rest / &g;
+                        parts.push(g);
+                    }
+                    if rest.degree().unwrap_or(0) == 0 {
+                        break;
+                    }
+                }
+                parts
+            })
+            .collect();
+    }
+    factors
+}
+
+/// Whether f is irreducible over Fp
+/// Constant polynomials are not irreducible.
+pub fn is_irreducible<const P: u64>(f: &Polynomial<Fp<P>>) -> bool {
+    match f.degree() {
+        None | Some(0) => false,
+        Some(n) => {
+            let f = f.monic();
+            f.gcd(&derivative(&f)) == one()
+                && matches!(distinct_degree_factorization(&f).as_slice(), [(_, d)] if *d == n)
+        }
+    }
+}
+
+/// Factorization into monic irreducible polynomials
+/// Returns (c, [(p_1, e_1), ..., (p_k, e_k)]) with f = c \prod p_i^{e_i}, the p_i distinct,
+/// sorted by degree and then by coefficients.
+/// Panics on the zero polynomial.
+pub fn factor<const P: u64>(
+    f: &Polynomial<Fp<P>>,
+    algorithm: FactorAlgorithm,
+) -> (Fp<P>, Vec<(Polynomial<Fp<P>>, usize)>) {
+    let Some(&lc) = f.coeffs().last() else {
+        panic!("Factorization of the zero polynomial");
+    };
+    let use_berlekamp = match algorithm {
+        FactorAlgorithm::Auto => P <= BERLEKAMP_MAX_P,
+        FactorAlgorithm::CantorZassenhaus => false,
+        FactorAlgorithm::Berlekamp => true,
+    };
+    let mut rng = StdRng::seed_from_u64(SEED);
+
+    let mut factors = vec![];
+    for (g, e) in square_free_factorization(f) {
+        let parts = if use_berlekamp {
+            berlekamp(&g)
+        } else {
+            distinct_degree_factorization(&g)
+                .into_iter()
+                .flat_map(|(h, d)| equal_degree_split(&h, d, &mut rng))
+                .collect()
+        };
+        factors.extend(parts.into_iter().map(|p| (p.monic(), e)));
+    }
+    factors.sort_by_key(|(p, _)| {
+        (
+            p.degree(),
+            p.coeffs()
+                .iter()
+                .rev()
+                .map(|c| c.value())
+                .collect::<Vec<_>>(),
+        )
+    });
+    (lc, factors)
+}
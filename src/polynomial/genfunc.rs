@@ -0,0 +1,164 @@
+//! Generating Functions
+//! https://en.wikipedia.org/wiki/Generating_function
+//! Tools for ordinary generating functions over exact coefficient rings such as Fp and the rationals.
+//! All products go through `convolution`, which uses the number theoretic transform over Fp for long operands.
+
+use std::ops::{Add, Mul, Neg, Sub};
+
+use super::{power_series::PowerSeries, Polynomial};
+use crate::{
+    field::{
+        finite_field::{
+            binary::Gf2e,
+            extension::{ExtensionModulus, FpExt},
+            montgomery::FpMont,
+            zn::Zn,
+        },
+        FieldBase,
+    },
+    number::{Complex, Rational},
+    ring::RingBase,
+};
+
+/// Coefficient rings of generating functions, with their fastest convolution
+/// Schoolbook multiplication by default, prime fields override it with the number theoretic transform.
+pub trait Convolution: RingBase + Copy + Add<Output = Self> + Mul<Output = Self> {
+    /// Cauchy product of two coefficient sequences
+    fn convolve(a: &[Self], b: &[Self]) -> Vec<Self> {
+        schoolbook_convolution(a, b)
+    }
+}
+
+macro_rules! impl_schoolbook_convolution {
+    ($($t:ty),*) => {
+        $(
+            impl Convolution for $t {}
+        )*
+    };
+}
+
+impl_schoolbook_convolution!(
+    u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, usize, isize, f32, f64, Rational, Complex, Zn
+);
+
+impl<const N: u32> Convolution for Gf2e<N> {}
+
+impl<const P: u64> Convolution for FpMont<P> {}
+
+impl<const P: u64, const N: usize, M: ExtensionModulus<P, N>> Convolution for FpExt<P, N, M> {}
+
+/// Convolution (Cauchy product) of two coefficient sequences
+/// $$ c_n = \sum_{k=0}^{n} a_k b_{n-k} $$
+/// The result has length len(a) + len(b) - 1, or is empty if either input is.
+pub fn convolution<T: Convolution>(a: &[T], b: &[T]) -> Vec<T> {
+    T::convolve(a, b)
+}
+
+/// Convolution by the O(n m) schoolbook method
+pub(crate) fn schoolbook_convolution<T>(a: &[T], b: &[T]) -> Vec<T>
+where
+    T: RingBase + Copy + Add<Output = T> + Mul<Output = T>,
+{
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+    let mut c = vec![T::zero(); a.len() + b.len() - 1];
+    for (i, &a_i) in a.iter().enumerate() {
+        for (j, &b_j) in b.iter().enumerate() {
+            c[i + j] = c[i + j] + a_i * b_j;
+        }
+    }
+    c
+}
+
+/// First n coefficients of the rational generating function f(x) / g(x)
+/// Panics if g(0) = 0.
+pub fn expand<T>(numerator: &Polynomial<T>, denominator: &Polynomial<T>, n: usize) -> PowerSeries<T>
+where
+    T: FieldBase + Convolution + Sub<Output = T>,
+{
+    PowerSeries::from_polynomial(numerator, n) / PowerSeries::from_polynomial(denominator, n)
+}
+
+/// Coefficient extraction [x^n] f(x) / g(x) with the Bostan-Mori algorithm
+/// https://arxiv.org/abs/2008.08822
+/// Uses O(log n) polynomial products of size deg g, so n can be astronomically large.
+/// Panics if g(0) = 0.
+pub fn coefficient<T>(numerator: &Polynomial<T>, denominator: &Polynomial<T>, mut n: u64) -> T
+where
+    T: FieldBase + Convolution + Neg<Output = T>,
+{
+    let mut p = numerator.coeffs().to_vec();
+    let mut q = denominator.coeffs().to_vec();
+    if q.first().is_none_or(|q_0| *q_0 == T::zero()) {
+        panic!("Denominator must have a non-zero constant term");
+    }
+    while n > 0 {
+        if p.is_empty() {
+            return T::zero();
+        }
+        // q(-x)
+        let q_neg = q
+            .iter()
+            .enumerate()
+            .map(|(k, &c)| if k % 2 == 1 { -c } else { c })
+            .collect::<Vec<_>>();
+        // p(x) q(-x) = u_even(x^2) + x u_odd(x^2) and q(x) q(-x) = v(x^2)
+        let u = convolution(&p, &q_neg);
+        let v = convolution(&q, &q_neg);
+        let parity = (n % 2) as usize;
+        p = u.into_iter().skip(parity).step_by(2).collect();
+        q = v.into_iter().step_by(2).collect();
+        n /= 2;
+    }
+    match p.first() {
+        Some(&p_0) => p_0 * q[0].inverse(),
+        None => T::zero(),
+    }
+}
+
+/// Hadamard (pointwise) product
+/// $$ (f \odot g)(x) = \sum_n a_n b_n x^n $$
+pub fn hadamard_product<T>(f: &PowerSeries<T>, g: &PowerSeries<T>) -> PowerSeries<T>
+where
+    T: Copy + Mul<Output = T>,
+{
+    PowerSeries {
+        coeffs: f
+            .coeffs
+            .iter()
+            .zip(&g.coeffs)
+            .map(|(&a, &b)| a * b)
+            .collect(),
+    }
+}
+
+/// Binomial transform
+/// $$ b_n = \sum_{k=0}^{n} \binom{n}{k} a_k $$
+/// Computed from the table of repeated sums, so no division is needed and it works in any characteristic.
+pub fn binomial_transform<T>(a: &[T]) -> Vec<T>
+where
+    T: Copy + Add<Output = T>,
+{
+    difference_table(a, |x, y| x + y)
+}
+
+/// Inverse binomial transform (iterated forward differences)
+/// $$ a_n = \sum_{k=0}^{n} (-1)^{n-k} \binom{n}{k} b_k $$
+pub fn inverse_binomial_transform<T>(b: &[T]) -> Vec<T>
+where
+    T: Copy + Sub<Output = T>,
+{
+    difference_table(b, |x, y| y - x)
+}
+
+/// Leading entries of the rows of the table row_{i+1}[k] = op(row_i[k], row_i[k+1])
+fn difference_table<T: Copy>(a: &[T], op: impl Fn(T, T) -> T) -> Vec<T> {
+    let mut row = a.to_vec();
+    let mut result = Vec::with_capacity(a.len());
+    while let Some(&first) = row.first() {
+        result.push(first);
+        row = row.windows(2).map(|w| op(w[0], w[1])).collect();
+    }
+    result
+}
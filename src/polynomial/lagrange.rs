@@ -1,8 +1,9 @@
 //! Lagrange Interpolation
 //!
 
-use std::ops::{AddAssign, Div, Mul, MulAssign, Neg, Sub};
+use std::ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Sub};
 
+use crate::field::{batch_inverse, FieldBase};
 use crate::polynomial::Polynomial;
 use crate::ring::RingBase;
 
@@ -54,3 +55,50 @@ where
 
     poly
 }
+
+/// Lagrange interpolation with a single inversion, for finite fields
+/// Each numerator is M(x) / (x - x_i) for M(x) = \prod_j (x - x_j), found by synthetic division, and all the
+/// denominators are inverted at once by `batch_inverse`, for O(n^2) field operations.
+/// The product of every denominator overflows `Rational` and `f64` already for a few dozen points, so those
+/// use `lagrange_interpolation`.
+/// Panics if two points share an abscissa.
+pub fn lagrange_interpolation_fast<T>(points: &[(T, T)]) -> Polynomial<T>
+where
+    T: FieldBase + Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+{
+    let n = points.len();
+
+    // Coefficients of M(x), lowest degree first
+    let mut master = vec![T::one()];
+    for (x_j, _) in points {
+        master.insert(0, T::zero());
+        for k in 0..master.len() - 1 {
+            master[k] = master[k] - *x_j * master[k + 1];
+        }
+    }
+
+    let denominators = points
+        .iter()
+        .enumerate()
+        .map(|(i, (x_i, _))| {
+            points
+                .iter()
+                .enumerate()
+                .filter(|(j, _)| *j != i)
+                .fold(T::one(), |acc, (_, (x_j, _))| acc * (*x_i - *x_j))
+        })
+        .collect::<Vec<_>>();
+
+    let mut coeffs = vec![T::zero(); n];
+    for ((x_i, y_i), denom_inv) in points.iter().zip(batch_inverse(&denominators)) {
+        let weight = *y_i * denom_inv;
+        // Quotient of M(x) by x - x_i, highest degree first
+        let mut q = T::zero();
+        for k in (0..n).rev() {
+            q = master[k + 1] + *x_i * q;
+            coeffs[k] = coeffs[k] + weight * q;
+        }
+    }
+
+    Polynomial::from_coeffs(coeffs)
+}
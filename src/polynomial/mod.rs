@@ -1,8 +1,10 @@
 //! Polynomial Algebra
 //!
 
-use std::ops::{Add, Mul, Neg};
+use std::ops::{Add, Mul, Neg, Sub};
 
+use crate::error::AlgebraError;
+use crate::field::FieldBase;
 use crate::ring::RingBase;
 
 /// Dense Polynomial
@@ -34,6 +36,27 @@ impl<T: RingBase> Polynomial<T> {
         }
     }
 
+    /// Coefficients, lowest degree first
+    pub fn coeffs(&self) -> &[T] {
+        &self.coeffs
+    }
+
+    /// Apply a map to every coefficient
+    /// The result is normalized, since the map may send the leading coefficient to zero (e.g. reduction mod p).
+    pub fn map<U: RingBase>(&self, f: impl Fn(&T) -> U) -> Polynomial<U> {
+        Polynomial::from_coeffs(self.coeffs.iter().map(f).collect())
+    }
+
+    /// Apply a fallible map to every coefficient, stopping at the first error
+    pub fn try_map<U: RingBase, E>(
+        &self,
+        f: impl Fn(&T) -> Result<U, E>,
+    ) -> Result<Polynomial<U>, E> {
+        Ok(Polynomial::from_coeffs(
+            self.coeffs.iter().map(f).collect::<Result<_, _>>()?,
+        ))
+    }
+
     /// Get constant term
     pub fn constant_term(&self) -> Option<&T> {
         self.coeffs.first()
@@ -77,6 +100,173 @@ where
     }
 }
 
+impl<T> Polynomial<T>
+where
+    T: RingBase + Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+{
+    /// Evaluate at the arithmetic progression start, start + step, ..., start + (count - 1) step
+    /// The first d + 1 values are computed directly, after which the forward differences
+    /// $$ \Delta^k p(x) = \Delta^{k-1} p(x + h) - \Delta^{k-1} p(x) $$
+    /// give each further value with d additions, since the d-th difference of a degree d polynomial is constant.
+    pub fn evaluate_progression(&self, start: T, step: T, count: usize) -> Vec<T> {
+        let Some(degree) = self.degree() else {
+            return vec![T::zero(); count];
+        };
+        let horner = |x: T| {
+            self.coeffs
+                .iter()
+                .rev()
+                .fold(T::zero(), |acc, coeff| acc * x + *coeff)
+        };
+
+        let mut x = start;
+        let mut values = Vec::with_capacity(count);
+        for _ in 0..count.min(degree + 1) {
+            values.push(horner(x));
+            x = x + step;
+        }
+        if count <= degree + 1 {
+            return values;
+        }
+
+        // Trailing diagonal of the difference table, diffs[d - k] = \Delta^k p(x_{d - k})
+        let mut diffs = values.clone();
+        for k in 1..=degree {
+            for i in 0..=degree - k {
+                diffs[i] = diffs[i + 1] - diffs[i];
+            }
+        }
+        diffs.reverse();
+        // Step every order forward by one point, the d-th difference stays fixed
+        for _ in degree + 1..count {
+            for k in (0..degree).rev() {
+                diffs[k] = diffs[k] + diffs[k + 1];
+            }
+            values.push(diffs[0]);
+        }
+        values
+    }
+}
+
+impl<T> Polynomial<T>
+where
+    T: FieldBase + Copy + Mul<Output = T> + Sub<Output = T>,
+{
+    /// Euclidean division
+    /// Returns the quotient q and remainder r such that self = q divisor + r with deg r < deg divisor.
+    /// Panics if the divisor is the zero polynomial, see `checked_div_rem`.
+    pub fn div_rem(&self, divisor: &Self) -> (Self, Self) {
+        self.checked_div_rem(divisor)
+            .expect("Polynomial division by zero")
+    }
+
+    /// Euclidean division, an error if the divisor is the zero polynomial
+    pub fn checked_div_rem(&self, divisor: &Self) -> Result<(Self, Self), AlgebraError> {
+        let divisor_degree = divisor.degree().ok_or(AlgebraError::DivisionByZero)?;
+        let lc_inv = divisor.coeffs[divisor_degree].inverse();
+
+        let mut remainder = self.coeffs.clone();
+        if remainder.len() <= divisor_degree {
+            return Ok((Self::zero(), self.clone()));
+        }
+        let mut quotient = vec![T::zero(); remainder.len() - divisor_degree];
+
+        // Cancel the leading term of the remainder, highest degree first
+        for shift in (0..quotient.len()).rev() {
+            let q = remainder[shift + divisor_degree] * lc_inv;
+            quotient[shift] = q;
+            for (r, d) in remainder[shift..].iter_mut().zip(divisor.coeffs.iter()) {
+                *r = *r - q * *d;
+            }
+        }
+
+        remainder.truncate(divisor_degree);
+        Ok((Self::from_coeffs(quotient), Self::from_coeffs(remainder)))
+    }
+
+    /// Monic greatest common divisor by Euclid's algorithm
+    /// gcd(0, 0) = 0.
+    pub fn gcd(&self, other: &Self) -> Self {
+        let (mut a, mut b) = (self.clone(), other.clone());
+        while b.degree().is_some() {
+            let r = a.div_rem(&b).1;
+            (a, b) = (b, r);
+        }
+        a.monic()
+    }
+
+    /// Extended Euclidean algorithm
+    /// Returns (g, s, t) with g the monic gcd and s self + t other = g.
+    pub fn extended_gcd(&self, other: &Self) -> (Self, Self, Self) {
+        let (mut r0, mut r1) = (self.clone(), other.clone());
+        let (mut s0, mut s1) = (Self::from_coeffs(vec![T::one()]), Self::zero());
+        let (mut t0, mut t1) = (Self::zero(), Self::from_coeffs(vec![T::one()]));
+        while r1.degree().is_some() {
+            let (q, r) = r0.div_rem(&r1);
+            let s = s0.sub_product(&q, &s1);
+            let t = t0.sub_product(&q, &t1);
+            (r0, r1) = (r1, r);
+            (s0, s1) = (s1, s);
+            (t0, t1) = (t1, t);
+        }
+        match r0.coeffs.last() {
+            None => (r0, s0, t0),
+            Some(lc) => {
+                let lc_inv = lc.inverse();
+                let scale =
+                    |p: Self| Self::from_coeffs(p.coeffs.iter().map(|c| *c * lc_inv).collect());
+                (scale(r0), scale(s0), scale(t0))
+            }
+        }
+    }
+
+    /// self - a b, with schoolbook multiplication under the field bounds
+    fn sub_product(&self, a: &Self, b: &Self) -> Self {
+        let mut coeffs = self.coeffs.clone();
+        coeffs.resize(coeffs.len().max(a.coeffs.len() + b.coeffs.len()), T::zero());
+        for (i, x) in a.coeffs.iter().enumerate() {
+            for (j, y) in b.coeffs.iter().enumerate() {
+                coeffs[i + j] = coeffs[i + j] - *x * *y;
+            }
+        }
+        Self::from_coeffs(coeffs)
+    }
+
+    /// Monic associate: divide by the leading coefficient
+    /// The zero polynomial is left unchanged.
+    pub fn monic(&self) -> Self {
+        match self.coeffs.last() {
+            None => Self::zero(),
+            Some(lc) => {
+                let lc_inv = lc.inverse();
+                Self::from_coeffs(self.coeffs.iter().map(|c| *c * lc_inv).collect())
+            }
+        }
+    }
+}
+
 mod trait_impls;
 
+pub mod binary;
+
+pub mod domain;
+
+pub mod factor;
+
+pub mod genfunc;
+
 pub mod lagrange;
+
+pub mod multivariate;
+
+pub mod ntt;
+
+pub mod power_series;
+
+pub mod rational;
+
+mod rational_roots;
+
+pub mod resultant;
+
+pub mod roots;
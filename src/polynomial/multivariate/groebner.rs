@@ -0,0 +1,148 @@
+//! Gröbner Bases
+//! https://en.wikipedia.org/wiki/Gr%C3%B6bner_basis
+//! A Gröbner basis G of an ideal I is a generating set such that the leading monomial of every element of I
+//! is divisible by the leading monomial of some element of G.
+//! Then the remainder of the multivariate division by G is unique and vanishes exactly on I.
+
+use std::ops::{AddAssign, Mul, Neg};
+
+use super::{monomial::Monomial, ordering::MonomialOrder, MultiPoly};
+use crate::field::FieldBase;
+
+/// Multivariate division algorithm
+/// Divides f by the ordered list of divisors (f_1, ..., f_s), returning the quotients q_i and the remainder r such that
+/// $$ f = q_1 f_1 + ... + q_s f_s + r $$
+/// where no term of r is divisible by any leading monomial LM(f_i).
+pub fn divide<T, O>(
+    f: &MultiPoly<T, O>,
+    divisors: &[MultiPoly<T, O>],
+) -> (Vec<MultiPoly<T, O>>, MultiPoly<T, O>)
+where
+    T: FieldBase + Copy + AddAssign + Neg<Output = T> + Mul<Output = T>,
+    O: MonomialOrder,
+{
+    let mut quotients = vec![MultiPoly::zero(f.nvars, f.order.clone()); divisors.len()];
+    let mut remainder = MultiPoly::zero(f.nvars, f.order.clone());
+    let mut p = f.clone();
+
+    while let Some((lm, lc)) = p.leading_term().map(|(m, c)| (m.clone(), *c)) {
+        let divisor = divisors.iter().enumerate().find_map(|(i, g)| {
+            let (g_lm, g_lc) = g.leading_term()?;
+            lm.div(g_lm).map(|t| (i, t, lc * g_lc.inverse()))
+        });
+
+        match divisor {
+            // Cancel the leading term of p with a multiple of f_i
+            Some((i, t, c)) => {
+                quotients[i] +=
+                    MultiPoly::from_terms(f.nvars, vec![(t.clone(), c)], f.order.clone());
+                p -= divisors[i].mul_term(&t, c);
+            }
+            // No leading monomial divides LT(p): move it to the remainder
+            None => {
+                let lt = p.terms.remove(0);
+                remainder.terms.push(lt);
+            }
+        }
+    }
+
+    (quotients, remainder)
+}
+
+/// S-polynomial
+/// $$ S(f, g) = \frac{L}{LT(f)} f - \frac{L}{LT(g)} g $$
+/// where L = lcm(LM(f), LM(g)). It cancels the leading terms of f and g.
+pub fn s_polynomial<T, O>(f: &MultiPoly<T, O>, g: &MultiPoly<T, O>) -> MultiPoly<T, O>
+where
+    T: FieldBase + Copy + AddAssign + Neg<Output = T> + Mul<Output = T>,
+    O: MonomialOrder,
+{
+    let (Some((f_lm, f_lc)), Some((g_lm, g_lc))) = (f.leading_term(), g.leading_term()) else {
+        return MultiPoly::zero(f.nvars, f.order.clone());
+    };
+    let lcm = f_lm.lcm(g_lm);
+    let f_t: Monomial = lcm.div(f_lm).expect("LM(f) divides the lcm");
+    let g_t: Monomial = lcm.div(g_lm).expect("LM(g) divides the lcm");
+    &f.mul_term(&f_t, f_lc.inverse()) - &g.mul_term(&g_t, g_lc.inverse())
+}
+
+/// Buchberger's algorithm
+/// Computes the reduced Gröbner basis of the ideal generated by the given polynomials:
+/// every element is monic, and no term of an element is divisible by the leading monomial of another.
+/// The reduced basis is unique for a given ideal and monomial order; it is returned sorted by decreasing leading monomial.
+pub fn groebner_basis<T, O>(generators: &[MultiPoly<T, O>]) -> Vec<MultiPoly<T, O>>
+where
+    T: FieldBase + Copy + AddAssign + Neg<Output = T> + Mul<Output = T>,
+    O: MonomialOrder,
+{
+    let mut basis: Vec<MultiPoly<T, O>> = generators
+        .iter()
+        .filter(|g| !g.is_zero())
+        .cloned()
+        .collect();
+    let mut pairs: Vec<(usize, usize)> = (0..basis.len())
+        .flat_map(|j| (0..j).map(move |i| (i, j)))
+        .collect();
+
+    while let Some((i, j)) = pairs.pop() {
+        let (lm_i, lm_j) = (
+            basis[i].leading_monomial().unwrap(),
+            basis[j].leading_monomial().unwrap(),
+        );
+
+        // Buchberger's first criterion: coprime leading monomials reduce to zero
+        if lm_i.lcm(lm_j) == lm_i.mul(lm_j) {
+            continue;
+        }
+
+        let (_, r) = divide(&s_polynomial(&basis[i], &basis[j]), &basis);
+        if !r.is_zero() {
+            let k = basis.len();
+            basis.push(r);
+            pairs.extend((0..k).map(|i| (i, k)));
+        }
+    }
+
+    reduce_basis(basis)
+}
+
+/// Turn a Gröbner basis into the reduced Gröbner basis
+fn reduce_basis<T, O>(basis: Vec<MultiPoly<T, O>>) -> Vec<MultiPoly<T, O>>
+where
+    T: FieldBase + Copy + AddAssign + Neg<Output = T> + Mul<Output = T>,
+    O: MonomialOrder,
+{
+    // Drop elements whose leading monomial is divisible by the leading monomial of another
+    let mut minimal: Vec<MultiPoly<T, O>> = vec![];
+    for g in basis {
+        let lm = g.leading_monomial().unwrap();
+        if minimal
+            .iter()
+            .any(|h| h.leading_monomial().unwrap().divides(lm))
+        {
+            continue;
+        }
+        minimal.retain(|h| !lm.divides(h.leading_monomial().unwrap()));
+        minimal.push(g);
+    }
+
+    // Reduce every element by the others and make it monic
+    let mut reduced = minimal.clone();
+    for i in 0..reduced.len() {
+        let others = reduced
+            .iter()
+            .enumerate()
+            .filter(|(j, _)| *j != i)
+            .map(|(_, h)| h.clone())
+            .collect::<Vec<_>>();
+        let (_, r) = divide(&reduced[i], &others);
+        let lc_inv = r.leading_coeff().unwrap().inverse();
+        reduced[i] = r.mul_term(&Monomial::one(r.nvars), lc_inv);
+    }
+
+    reduced.sort_by(|f, g| {
+        f.order
+            .cmp(g.leading_monomial().unwrap(), f.leading_monomial().unwrap())
+    });
+    reduced
+}
@@ -0,0 +1,68 @@
+//! Polynomial Ideals
+//! The ideal <f_1, ..., f_s> is the set of all combinations q_1 f_1 + ... + q_s f_s with polynomial coefficients.
+//! Membership and normal forms are decided through a Gröbner basis of the ideal.
+
+use std::ops::{AddAssign, Mul, Neg};
+
+use super::{
+    groebner::{divide, groebner_basis},
+    ordering::MonomialOrder,
+    MultiPoly,
+};
+use crate::field::FieldBase;
+
+/// Ideal of a multivariate polynomial ring over a field
+#[derive(Debug, Clone)]
+pub struct Ideal<T, O: MonomialOrder> {
+    generators: Vec<MultiPoly<T, O>>,
+    basis: Vec<MultiPoly<T, O>>,
+}
+
+impl<T, O> Ideal<T, O>
+where
+    T: FieldBase + Copy + AddAssign + Neg<Output = T> + Mul<Output = T>,
+    O: MonomialOrder,
+{
+    /// Ideal generated by the given polynomials
+    /// The reduced Gröbner basis is computed upfront.
+    pub fn new(generators: Vec<MultiPoly<T, O>>) -> Self {
+        let basis = groebner_basis(&generators);
+        Self { generators, basis }
+    }
+
+    /// Ideal from a precomputed Gröbner basis
+    /// The basis is trusted: if it is not a Gröbner basis, membership tests may give false negatives.
+    pub fn from_groebner_basis(basis: Vec<MultiPoly<T, O>>) -> Self {
+        Self {
+            generators: basis.clone(),
+            basis,
+        }
+    }
+
+    /// Generators the ideal was built from
+    pub fn generators(&self) -> &[MultiPoly<T, O>] {
+        &self.generators
+    }
+
+    /// Gröbner basis of the ideal
+    pub fn groebner_basis(&self) -> &[MultiPoly<T, O>] {
+        &self.basis
+    }
+
+    /// Normal form of f modulo the ideal
+    /// The remainder of the division by the Gröbner basis: the unique representative of f + I
+    /// with no term divisible by a leading monomial of the basis.
+    pub fn normal_form(&self, f: &MultiPoly<T, O>) -> MultiPoly<T, O> {
+        divide(f, &self.basis).1
+    }
+
+    /// Ideal membership test: f is in I iff its normal form vanishes
+    pub fn contains(&self, f: &MultiPoly<T, O>) -> bool {
+        self.normal_form(f).is_zero()
+    }
+
+    /// Check whether the ideal is the whole ring, i.e. the generators have no common zero
+    pub fn is_unit(&self) -> bool {
+        self.basis.iter().any(|g| g.total_degree() == Some(0))
+    }
+}
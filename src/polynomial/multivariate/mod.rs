@@ -0,0 +1,266 @@
+//! Multivariate Polynomial Algebra
+//! Sparse polynomials in the variables x_0, ..., x_{n-1} whose terms are kept sorted by a monomial order.
+
+use std::ops::{Add, AddAssign, Mul};
+
+use crate::ring::RingBase;
+
+pub mod groebner;
+pub mod ideal;
+pub mod monomial;
+pub mod ordering;
+
+use monomial::Monomial;
+use ordering::{GRevLex, MonomialOrder};
+
+/// Sparse Multivariate Polynomial
+/// Terms are stored in decreasing order with respect to the monomial order `O`,
+/// without zero coefficients nor repeated monomials, so the leading term is the first one.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MultiPoly<T, O: MonomialOrder = GRevLex> {
+    pub(crate) nvars: usize,
+    pub(crate) terms: Vec<(Monomial, T)>,
+    pub(crate) order: O,
+}
+
+impl<T: RingBase, O: MonomialOrder> MultiPoly<T, O> {
+    /// Zero polynomial in n variables
+    pub fn zero(nvars: usize, order: O) -> Self {
+        Self {
+            nvars,
+            terms: vec![],
+            order,
+        }
+    }
+
+    /// Constant polynomial in n variables
+    pub fn constant(nvars: usize, c: T, order: O) -> Self {
+        let terms = if c == T::zero() {
+            vec![]
+        } else {
+            vec![(Monomial::one(nvars), c)]
+        };
+        Self {
+            nvars,
+            terms,
+            order,
+        }
+    }
+
+    /// Single variable polynomial x_i in n variables
+    pub fn var(nvars: usize, i: usize, order: O) -> Self {
+        Self {
+            nvars,
+            terms: vec![(Monomial::var(nvars, i), T::one())],
+            order,
+        }
+    }
+
+    /// Number of variables
+    pub fn nvars(&self) -> usize {
+        self.nvars
+    }
+
+    /// Monomial order
+    pub fn order(&self) -> &O {
+        &self.order
+    }
+
+    /// Terms in decreasing monomial order
+    pub fn terms(&self) -> &[(Monomial, T)] {
+        &self.terms
+    }
+
+    /// Check for the zero polynomial
+    pub fn is_zero(&self) -> bool {
+        self.terms.is_empty()
+    }
+
+    /// Leading term with respect to the monomial order
+    pub fn leading_term(&self) -> Option<(&Monomial, &T)> {
+        self.terms.first().map(|(m, c)| (m, c))
+    }
+
+    /// Leading monomial with respect to the monomial order
+    pub fn leading_monomial(&self) -> Option<&Monomial> {
+        self.terms.first().map(|(m, _)| m)
+    }
+
+    /// Leading coefficient with respect to the monomial order
+    pub fn leading_coeff(&self) -> Option<&T> {
+        self.terms.first().map(|(_, c)| c)
+    }
+
+    /// Total degree: maximum total degree of the monomials
+    /// Returns `None` for the zero polynomial.
+    pub fn total_degree(&self) -> Option<u32> {
+        self.terms.iter().map(|(m, _)| m.degree()).max()
+    }
+
+    /// Degree in the variable x_var
+    /// Returns `None` for the zero polynomial, panics if the variable is out of range.
+    pub fn degree_in(&self, var: usize) -> Option<u32> {
+        assert!(
+            var < self.nvars,
+            "Variable x{var} out of range for {} variables",
+            self.nvars
+        );
+        self.terms.iter().map(|(m, _)| m.exps[var]).max()
+    }
+
+    /// Check whether every term has the same total degree
+    /// The zero polynomial is considered homogeneous.
+    pub fn is_homogeneous(&self) -> bool {
+        self.terms
+            .windows(2)
+            .all(|pair| pair[0].0.degree() == pair[1].0.degree())
+    }
+
+    /// Coefficient of a given monomial
+    pub fn coeff(&self, monomial: &Monomial) -> T
+    where
+        T: Copy,
+    {
+        self.terms
+            .iter()
+            .find(|(m, _)| m == monomial)
+            .map_or(T::zero(), |(_, c)| *c)
+    }
+}
+
+impl<T, O> MultiPoly<T, O>
+where
+    T: RingBase + Copy + AddAssign,
+    O: MonomialOrder,
+{
+    /// New polynomial from (monomial, coefficient) pairs in any order
+    /// Repeated monomials are combined and zero terms dropped.
+    pub fn from_terms(nvars: usize, terms: Vec<(Monomial, T)>, order: O) -> Self {
+        assert!(
+            terms.iter().all(|(m, _)| m.nvars() == nvars),
+            "Monomials must have {nvars} variables"
+        );
+        let mut poly = Self {
+            nvars,
+            terms,
+            order,
+        };
+        poly.normalize();
+        poly
+    }
+
+    /// Sort terms by decreasing monomial, combine repeated monomials and drop zero terms
+    pub(crate) fn normalize(&mut self) {
+        let order = &self.order;
+        self.terms.sort_by(|(a, _), (b, _)| order.cmp(b, a));
+
+        let mut terms: Vec<(Monomial, T)> = Vec::with_capacity(self.terms.len());
+        for (m, c) in self.terms.drain(..) {
+            match terms.last_mut() {
+                Some((last, acc)) if *last == m => *acc += c,
+                _ => terms.push((m, c)),
+            }
+        }
+        terms.retain(|(_, c)| *c != T::zero());
+        self.terms = terms;
+    }
+
+    /// Same polynomial viewed in one extra variable x_n, appended after the existing ones
+    pub fn add_var(&self) -> Self {
+        let terms = self
+            .terms
+            .iter()
+            .map(|(m, c)| {
+                let mut exps = m.exps.clone();
+                exps.push(0);
+                (Monomial::new(exps), *c)
+            })
+            .collect();
+        Self::from_terms(self.nvars + 1, terms, self.order.clone())
+    }
+
+    /// Homogenize with respect to the variable x_var
+    /// Every term c m is multiplied by x_var^{d - deg(m)}, where d is the total degree of the polynomial.
+    pub fn homogenize(&self, var: usize) -> Self {
+        assert!(var < self.nvars, "Variable index out of range");
+        let Some(degree) = self.total_degree() else {
+            return self.clone();
+        };
+        let terms = self
+            .terms
+            .iter()
+            .map(|(m, c)| {
+                let mut exps = m.exps.clone();
+                exps[var] += degree - m.degree();
+                (Monomial::new(exps), *c)
+            })
+            .collect();
+        Self::from_terms(self.nvars, terms, self.order.clone())
+    }
+
+    /// Dehomogenize by setting the variable x_var to 1
+    /// The number of variables is kept, x_var simply no longer appears.
+    pub fn dehomogenize(&self, var: usize) -> Self {
+        assert!(var < self.nvars, "Variable index out of range");
+        let terms = self
+            .terms
+            .iter()
+            .map(|(m, c)| {
+                let mut exps = m.exps.clone();
+                exps[var] = 0;
+                (Monomial::new(exps), *c)
+            })
+            .collect();
+        Self::from_terms(self.nvars, terms, self.order.clone())
+    }
+
+    /// Same polynomial with terms sorted by another monomial order
+    pub fn with_order<O2: MonomialOrder>(self, order: O2) -> MultiPoly<T, O2> {
+        MultiPoly::from_terms(self.nvars, self.terms, order)
+    }
+}
+
+impl<T, O> MultiPoly<T, O>
+where
+    T: RingBase + Copy + AddAssign + Mul<Output = T>,
+    O: MonomialOrder,
+{
+    /// Multiply by a single term c m
+    pub fn mul_term(&self, monomial: &Monomial, c: T) -> Self {
+        // Multiplying by a monomial preserves the order of the terms
+        let mut terms = self
+            .terms
+            .iter()
+            .map(|(m, a)| (m.mul(monomial), *a * c))
+            .collect::<Vec<_>>();
+        terms.retain(|(_, c)| *c != T::zero());
+        Self {
+            nvars: self.nvars,
+            terms,
+            order: self.order.clone(),
+        }
+    }
+
+    /// Evaluate the polynomial at a given point
+    pub fn evaluate(&self, point: &[T]) -> T
+    where
+        T: Add<Output = T>,
+    {
+        assert_eq!(
+            point.len(),
+            self.nvars,
+            "Point must have {} coordinates",
+            self.nvars
+        );
+        self.terms.iter().fold(T::zero(), |acc, (m, c)| {
+            let value = m
+                .exps
+                .iter()
+                .zip(point)
+                .fold(*c, |acc, (e, x)| (0..*e).fold(acc, |acc, _| acc * *x));
+            acc + value
+        })
+    }
+}
+
+mod trait_impls;
@@ -0,0 +1,126 @@
+//! Monomials
+//! A monomial x_0^{a_0} x_1^{a_1} ... x_{n-1}^{a_{n-1}} is represented by its exponent vector (a_0, ..., a_{n-1}).
+
+use std::fmt::Display;
+
+/// Monomial in a fixed number of variables
+/// Operations on monomials in different numbers of variables panic.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Monomial {
+    pub(crate) exps: Vec<u32>,
+}
+
+impl Monomial {
+    /// Monomial with given exponents
+    pub fn new(exps: Vec<u32>) -> Self {
+        Self { exps }
+    }
+
+    /// Unit monomial 1 in n variables
+    pub fn one(nvars: usize) -> Self {
+        Self {
+            exps: vec![0; nvars],
+        }
+    }
+
+    /// Single variable monomial x_i in n variables
+    pub fn var(nvars: usize, i: usize) -> Self {
+        assert!(
+            i < nvars,
+            "Variable x{i} out of range for {nvars} variables"
+        );
+        let mut exps = vec![0; nvars];
+        exps[i] = 1;
+        Self { exps }
+    }
+
+    /// Exponent vector
+    pub fn exponents(&self) -> &[u32] {
+        &self.exps
+    }
+
+    /// Number of variables
+    pub fn nvars(&self) -> usize {
+        self.exps.len()
+    }
+
+    /// Total degree a_0 + a_1 + ... + a_{n-1}
+    pub fn degree(&self) -> u32 {
+        self.exps.iter().sum()
+    }
+
+    /// Panics unless both monomials have the same number of variables
+    pub(crate) fn check_nvars(&self, other: &Self) {
+        assert_eq!(
+            self.nvars(),
+            other.nvars(),
+            "Monomials must have the same number of variables"
+        );
+    }
+
+    /// Product of monomials: exponents add
+    pub fn mul(&self, other: &Self) -> Self {
+        self.check_nvars(other);
+        Self {
+            exps: self
+                .exps
+                .iter()
+                .zip(&other.exps)
+                .map(|(a, b)| a + b)
+                .collect(),
+        }
+    }
+
+    /// Check whether self divides other
+    pub fn divides(&self, other: &Self) -> bool {
+        self.check_nvars(other);
+        self.exps.iter().zip(&other.exps).all(|(a, b)| a <= b)
+    }
+
+    /// Quotient self / other, if other divides self
+    pub fn div(&self, other: &Self) -> Option<Self> {
+        self.check_nvars(other);
+        self.exps
+            .iter()
+            .zip(&other.exps)
+            .map(|(a, b)| a.checked_sub(*b))
+            .collect::<Option<_>>()
+            .map(|exps| Self { exps })
+    }
+
+    /// Least common multiple: exponent-wise maximum
+    pub fn lcm(&self, other: &Self) -> Self {
+        self.check_nvars(other);
+        Self {
+            exps: self
+                .exps
+                .iter()
+                .zip(&other.exps)
+                .map(|(a, b)| *a.max(b))
+                .collect(),
+        }
+    }
+}
+
+/// Formats as "x0^2 x2", or "1" for the unit monomial.
+impl Display for Monomial {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let repr = self
+            .exps
+            .iter()
+            .enumerate()
+            .filter(|(_, e)| **e != 0)
+            .map(|(i, e)| match e {
+                1 => format!("x{i}"),
+                _ => format!("x{i}^{e}"),
+            })
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        if repr.is_empty() {
+            write!(f, "1")
+        } else {
+            write!(f, "{repr}")
+        }
+    }
+}
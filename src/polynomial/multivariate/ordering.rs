@@ -0,0 +1,104 @@
+//! Monomial Orderings
+//! https://en.wikipedia.org/wiki/Monomial_order
+//! A monomial order is a total order on monomials compatible with multiplication (a < b implies a c < b c)
+//! and such that 1 is the least monomial.
+//! The order fixes what the leading term of a multivariate polynomial is, which drives division and Gröbner bases.
+
+use std::cmp::Ordering;
+
+use super::monomial::Monomial;
+
+/// Monomial order
+/// Orders are values rather than bare types so that parametrized orders (e.g. weighted) can carry their data.
+pub trait MonomialOrder: Clone {
+    /// Compare two monomials in the same number of variables
+    /// Panics if the numbers of variables differ.
+    fn cmp(&self, a: &Monomial, b: &Monomial) -> Ordering;
+}
+
+/// Lexicographic order
+/// Compares the exponent of x_0 first, then x_1, and so on.
+/// Suited for elimination: a Gröbner basis in lex order eliminates the variables one at a time.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Lex;
+
+impl MonomialOrder for Lex {
+    fn cmp(&self, a: &Monomial, b: &Monomial) -> Ordering {
+        a.check_nvars(b);
+        a.exps.cmp(&b.exps)
+    }
+}
+
+/// Graded lexicographic order
+/// Compares total degree first, ties are broken lexicographically.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct GrLex;
+
+impl MonomialOrder for GrLex {
+    fn cmp(&self, a: &Monomial, b: &Monomial) -> Ordering {
+        a.check_nvars(b);
+        a.degree().cmp(&b.degree()).then_with(|| Lex.cmp(a, b))
+    }
+}
+
+/// Graded reverse lexicographic order
+/// Compares total degree first, ties are broken by the last variable: the monomial with the smaller exponent
+/// in the last differing variable is the larger one.
+/// Usually the most efficient order for Gröbner basis computations.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct GRevLex;
+
+impl MonomialOrder for GRevLex {
+    fn cmp(&self, a: &Monomial, b: &Monomial) -> Ordering {
+        a.check_nvars(b);
+        a.degree().cmp(&b.degree()).then_with(|| {
+            a.exps
+                .iter()
+                .zip(&b.exps)
+                .rev()
+                .find(|(x, y)| x != y)
+                .map_or(Ordering::Equal, |(x, y)| y.cmp(x))
+        })
+    }
+}
+
+/// Weighted order
+/// Compares the weighted degree \sum w_i a_i first, ties are broken by another order.
+/// With positive weights this is a monomial order for any tie-breaking monomial order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Weighted<O: MonomialOrder = GRevLex> {
+    weights: Vec<u64>,
+    tie_break: O,
+}
+
+impl<O: MonomialOrder> Weighted<O> {
+    /// Weighted order with the given weights, one per variable, and tie-breaking order
+    /// Panics unless every weight is positive.
+    pub fn new(weights: Vec<u64>, tie_break: O) -> Self {
+        assert!(weights.iter().all(|w| *w > 0), "Weights must be positive");
+        Self { weights, tie_break }
+    }
+
+    /// Weighted degree of a monomial
+    /// Panics unless the monomial has one variable per weight.
+    pub fn weighted_degree(&self, a: &Monomial) -> u64 {
+        assert_eq!(
+            self.weights.len(),
+            a.nvars(),
+            "Weighted order needs one weight per variable"
+        );
+        self.weights
+            .iter()
+            .zip(&a.exps)
+            .map(|(w, e)| w * *e as u64)
+            .sum()
+    }
+}
+
+impl<O: MonomialOrder> MonomialOrder for Weighted<O> {
+    fn cmp(&self, a: &Monomial, b: &Monomial) -> Ordering {
+        self.weighted_degree(a)
+            .cmp(&self.weighted_degree(b))
+            .then_with(|| self.tie_break.cmp(a, b))
+    }
+}
@@ -0,0 +1,189 @@
+use super::{ordering::MonomialOrder, MultiPoly};
+use crate::ring::RingBase;
+use std::fmt::Display;
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// Formats as "c_1 m_1 + c_2 m_2 + ..." in decreasing monomial order.
+impl<T, O> Display for MultiPoly<T, O>
+where
+    T: RingBase + Display,
+    O: MonomialOrder,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.terms.is_empty() {
+            return write!(f, "0");
+        }
+
+        let repr = self
+            .terms
+            .iter()
+            .map(|(m, c)| match m.degree() {
+                0 => format!("{c}"),
+                _ => format!("{c} {m}"),
+            })
+            .collect::<Vec<_>>()
+            .join(" + ");
+
+        write!(f, "{repr}")
+    }
+}
+
+// --- Neg ---
+
+/// -P
+impl<T, O> Neg for MultiPoly<T, O>
+where
+    T: RingBase + Copy + Neg<Output = T>,
+    O: MonomialOrder,
+{
+    type Output = Self;
+
+    fn neg(mut self) -> Self {
+        for (_, c) in self.terms.iter_mut() {
+            *c = -(*c);
+        }
+        self
+    }
+}
+
+// --- AddAssign / SubAssign ---
+
+/// In-place addition: P += Q
+impl<'a, T, O> AddAssign<&'a MultiPoly<T, O>> for MultiPoly<T, O>
+where
+    T: RingBase + Copy + AddAssign,
+    O: MonomialOrder,
+{
+    fn add_assign(&mut self, rhs: &'a MultiPoly<T, O>) {
+        assert_eq!(self.nvars, rhs.nvars, "Number of variables mismatch");
+        self.terms.extend(rhs.terms.iter().cloned());
+        self.normalize();
+    }
+}
+
+impl<T, O> AddAssign for MultiPoly<T, O>
+where
+    T: RingBase + Copy + AddAssign,
+    O: MonomialOrder,
+{
+    fn add_assign(&mut self, rhs: Self) {
+        *self += &rhs;
+    }
+}
+
+/// In-place subtraction: P -= Q
+impl<'a, T, O> SubAssign<&'a MultiPoly<T, O>> for MultiPoly<T, O>
+where
+    T: RingBase + Copy + AddAssign + Neg<Output = T>,
+    O: MonomialOrder,
+{
+    fn sub_assign(&mut self, rhs: &'a MultiPoly<T, O>) {
+        assert_eq!(self.nvars, rhs.nvars, "Number of variables mismatch");
+        self.terms
+            .extend(rhs.terms.iter().map(|(m, c)| (m.clone(), -(*c))));
+        self.normalize();
+    }
+}
+
+impl<T, O> SubAssign for MultiPoly<T, O>
+where
+    T: RingBase + Copy + AddAssign + Neg<Output = T>,
+    O: MonomialOrder,
+{
+    fn sub_assign(&mut self, rhs: Self) {
+        *self -= &rhs;
+    }
+}
+
+// --- MulAssign ---
+
+/// In-place multiplication: P *= Q
+impl<'a, T, O> MulAssign<&'a MultiPoly<T, O>> for MultiPoly<T, O>
+where
+    T: RingBase + Copy + AddAssign + Mul<Output = T>,
+    O: MonomialOrder,
+{
+    fn mul_assign(&mut self, rhs: &'a MultiPoly<T, O>) {
+        assert_eq!(self.nvars, rhs.nvars, "Number of variables mismatch");
+        self.terms = self
+            .terms
+            .iter()
+            .flat_map(|(m1, c1)| rhs.terms.iter().map(|(m2, c2)| (m1.mul(m2), *c1 * *c2)))
+            .collect();
+        self.normalize();
+    }
+}
+
+impl<T, O> MulAssign for MultiPoly<T, O>
+where
+    T: RingBase + Copy + AddAssign + Mul<Output = T>,
+    O: MonomialOrder,
+{
+    fn mul_assign(&mut self, rhs: Self) {
+        *self *= &rhs;
+    }
+}
+
+/// In-place scalar multiplication: P *= c
+impl<T, O> MulAssign<T> for MultiPoly<T, O>
+where
+    T: RingBase + Copy + MulAssign,
+    O: MonomialOrder,
+{
+    fn mul_assign(&mut self, rhs: T) {
+        for (_, c) in self.terms.iter_mut() {
+            *c *= rhs;
+        }
+        self.terms.retain(|(_, c)| *c != T::zero());
+    }
+}
+
+// --- Add / Sub / Mul ---
+
+macro_rules! impl_multipoly_binops {
+    ($trait:ident, $method:ident, $assignMethod:ident, $($bound:tt)*) => {
+        /// P op Q
+        impl<T, O> $trait for MultiPoly<T, O>
+        where
+            T: RingBase + Copy + AddAssign + $($bound)*,
+            O: MonomialOrder,
+        {
+            type Output = Self;
+            fn $method(mut self, rhs: Self) -> Self {
+                self.$assignMethod(&rhs);
+                self
+            }
+        }
+
+        /// &P op &Q
+        impl<'b, T, O> $trait<&'b MultiPoly<T, O>> for &MultiPoly<T, O>
+        where
+            T: RingBase + Copy + AddAssign + $($bound)*,
+            O: MonomialOrder,
+        {
+            type Output = MultiPoly<T, O>;
+            fn $method(self, rhs: &'b MultiPoly<T, O>) -> MultiPoly<T, O> {
+                let mut result = self.clone();
+                result.$assignMethod(rhs);
+                result
+            }
+        }
+    };
+}
+
+impl_multipoly_binops!(Add, add, add_assign, Sized);
+impl_multipoly_binops!(Sub, sub, sub_assign, Neg<Output = T>);
+impl_multipoly_binops!(Mul, mul, mul_assign, Mul<Output = T>);
+
+/// P * c
+impl<T, O> Mul<T> for MultiPoly<T, O>
+where
+    T: RingBase + Copy + MulAssign,
+    O: MonomialOrder,
+{
+    type Output = Self;
+    fn mul(mut self, rhs: T) -> Self {
+        self *= rhs;
+        self
+    }
+}
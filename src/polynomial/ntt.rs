@@ -0,0 +1,405 @@
+//! Number Theoretic Transform
+//! https://en.wikipedia.org/wiki/Discrete_Fourier_transform_over_a_ring
+//! Discrete Fourier transform over Fp
+//! $$ X_k = \sum_{j=0}^{n-1} a_j w^{jk} $$
+//! with w a primitive n-th root of unity, which exists iff n divides P - 1.
+//! Any such length is split by mixed-radix Cooley-Tukey over its prime factors. Large prime radices go
+//! through Bluestein's algorithm, which rewrites them as a convolution of a smoother length,
+//! so convolutions only pad to the next divisor of P - 1 instead of the next power of two.
+//! Products over Z or a modulus without large roots of unity are computed modulo three NTT primes
+//! and reconstructed by the Chinese Remainder Theorem.
+
+use super::{
+    genfunc::{schoolbook_convolution, Convolution},
+    Polynomial,
+};
+use crate::{
+    field::{finite_field::Fp, FieldBase},
+    group::multiplicative::FpUnit,
+    number::{factorize, mul_mod},
+    ring::RingBase,
+};
+
+/// NTT primes below 2^62 for exact products by CRT, P - 1 divisible by 2^57, 2^53 and 2^51
+/// Their product exceeds 2^185, so coefficients of products of u64 sequences are recovered exactly
+/// as long as the shorter operand has fewer than 2^57 terms.
+pub const CRT_PRIMES: [u64; 3] = [CRT_P0, CRT_P1, CRT_P2];
+
+const CRT_P0: u64 = 29 * (1 << 57) + 1;
+const CRT_P1: u64 = 501 * (1 << 53) + 1;
+const CRT_P2: u64 = 2019 * (1 << 51) + 1;
+
+/// Prime radices up to this size are transformed directly
+const NAIVE_RADIX: usize = 16;
+
+/// Below this operand length schoolbook multiplication is faster than three transforms
+const SCHOOLBOOK_THRESHOLD: usize = 32;
+
+/// Crossover of `mul_fast`, higher since it may need three transforms per CRT prime
+const MUL_FAST_THRESHOLD: usize = 128;
+
+/// Primitive n-th root of unity in Fp, a fixed power of the generator
+/// None unless n divides P - 1.
+pub fn root_of_unity<const P: u64>(n: usize) -> Option<Fp<P>> {
+    (n > 0 && (P - 1).is_multiple_of(n as u64))
+        .then(|| FpUnit::<P>::generator().element().pow((P - 1) / n as u64))
+}
+
+/// Divisors of P - 1 in increasing order
+fn group_order_divisors<const P: u64>() -> Vec<usize> {
+    let mut divisors = factorize(P - 1)
+        .into_iter()
+        .fold(vec![1usize], |divisors, (q, e)| {
+            divisors
+                .iter()
+                .flat_map(|d| (0..=e).map(move |k| d * (q as usize).pow(k)))
+                .collect()
+        });
+    divisors.sort_unstable();
+    divisors
+}
+
+/// Prime factors of n with multiplicity
+pub(super) fn prime_factors(n: usize) -> Vec<usize> {
+    factorize(n as u64)
+        .into_iter()
+        .flat_map(|(q, e)| std::iter::repeat_n(q as usize, e as usize))
+        .collect()
+}
+
+/// Smallest transform length at least n, the smallest divisor of P - 1 that is >= n
+pub fn transform_length<const P: u64>(n: usize) -> Option<usize> {
+    group_order_divisors::<P>().into_iter().find(|&d| d >= n)
+}
+
+/// Mixed-radix decimation in time
+/// For n = r s, the subsequences a_t, a_{t+r}, ... are transformed with w^r, and
+/// $$ X_{k + sq} = \sum_{t<r} (w^{tk} Y_t[k]) (w^s)^{tq} $$
+/// is a length r transform for each k < s.
+pub(super) fn dft<const P: u64>(a: &[Fp<P>], root: Fp<P>, factors: &[usize]) -> Vec<Fp<P>> {
+    let n = a.len();
+    if n > 1 && n.is_power_of_two() {
+        let mut out = a.to_vec();
+        radix2_dft(&mut out, root);
+        return out;
+    }
+    let Some((&r, rest)) = factors.split_first() else {
+        return a.to_vec();
+    };
+    let s = n / r;
+    let sub_root = root.pow(r as u64);
+    let subs = (0..r)
+        .map(|t| {
+            let sub = a.iter().skip(t).step_by(r).copied().collect::<Vec<_>>();
+            dft(&sub, sub_root, rest)
+        })
+        .collect::<Vec<_>>();
+
+    let radix_root = root.pow(s as u64);
+    let mut out = vec![Fp::zero(); n];
+    let mut twiddle = Fp::one();
+    let mut z = vec![Fp::zero(); r];
+    for k in 0..s {
+        let mut w = Fp::one();
+        for (z, sub) in z.iter_mut().zip(&subs) {
+            *z = sub[k] * w;
+            w *= twiddle;
+        }
+        for (q, x) in radix_dft(&z, radix_root).into_iter().enumerate() {
+            out[k + s * q] = x;
+        }
+        twiddle *= root;
+    }
+    out
+}
+
+/// In-place iterative radix-2 transform for power of two lengths
+/// Bit-reversal permutation, then log2 n stages of butterflies (x, y) -> (x + w y, x - w y)
+/// with one table of twiddles per stage instead of a recursion allocating at every level.
+fn radix2_dft<const P: u64>(a: &mut [Fp<P>], root: Fp<P>) {
+    let n = a.len();
+    let mut j = 0;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j |= bit;
+        if i < j {
+            a.swap(i, j);
+        }
+    }
+
+    let mut len = 2;
+    while len <= n {
+        let half = len / 2;
+        let w = root.pow((n / len) as u64);
+        let twiddles = std::iter::successors(Some(Fp::one()), |t| Some(*t * w))
+            .take(half)
+            .collect::<Vec<_>>();
+        for chunk in a.chunks_exact_mut(len) {
+            let (lo, hi) = chunk.split_at_mut(half);
+            for ((x, y), t) in lo.iter_mut().zip(hi.iter_mut()).zip(&twiddles) {
+                let v = *y * *t;
+                *y = *x - v;
+                *x += v;
+            }
+        }
+        len <<= 1;
+    }
+}
+
+/// Transform of prime length r with a primitive r-th root w
+fn radix_dft<const P: u64>(z: &[Fp<P>], w: Fp<P>) -> Vec<Fp<P>> {
+    let r = z.len();
+    if r > NAIVE_RADIX {
+        if let Some(transform) = bluestein(z, w) {
+            return transform;
+        }
+    }
+    let mut out = Vec::with_capacity(r);
+    let mut wq = Fp::one();
+    for _ in 0..r {
+        let mut x = Fp::zero();
+        for c in z.iter().rev() {
+            x = x * wq + *c;
+        }
+        out.push(x);
+        wq *= w;
+    }
+    out
+}
+
+/// Bluestein's algorithm with the identity jk = T(j + k) - T(j) - T(k), T(m) = m (m - 1) / 2
+/// $$ X_k = w^{-T(k)} \sum_j (a_j w^{-T(j)}) w^{T(j+k)} $$
+/// a correlation computed as a cyclic convolution of length m >= 2r - 1, where m divides P - 1
+/// and only has prime factors smaller than r. None if there is no such length.
+fn bluestein<const P: u64>(z: &[Fp<P>], w: Fp<P>) -> Option<Vec<Fp<P>>> {
+    let r = z.len();
+    let m = group_order_divisors::<P>()
+        .into_iter()
+        .find(|&d| d >= 2 * r - 1 && prime_factors(d).iter().all(|&q| q < r))?;
+
+    // w^{T(k)} for k < 2r - 1, with T(k + 1) = T(k) + k
+    let mut chirp = Vec::with_capacity(2 * r - 1);
+    let (mut c, mut step) = (Fp::<P>::one(), Fp::<P>::one());
+    for _ in 0..2 * r - 1 {
+        chirp.push(c);
+        c *= step;
+        step *= w;
+    }
+    let mut b = z
+        .iter()
+        .zip(&chirp)
+        .rev()
+        .map(|(a, c)| *a * c.inverse())
+        .collect::<Vec<_>>();
+    b.resize(m, Fp::zero());
+    chirp.resize(m, Fp::zero());
+
+    // Wrapped terms land below index r - 1, which is never read
+    let correlation = cyclic_convolution(&b, &chirp);
+    Some(
+        (0..r)
+            .map(|k| correlation[r - 1 + k] * chirp[k].inverse())
+            .collect(),
+    )
+}
+
+/// Cyclic convolution of two sequences of the same length n dividing P - 1
+fn cyclic_convolution<const P: u64>(a: &[Fp<P>], b: &[Fp<P>]) -> Vec<Fp<P>> {
+    let fa = ntt(a);
+    let fb = ntt(b);
+    inverse_ntt(
+        &fa.into_iter()
+            .zip(fb)
+            .map(|(x, y)| x * y)
+            .collect::<Vec<_>>(),
+    )
+}
+
+/// Forward transform X_k = \sum_j a_j w^{jk}
+/// Panics unless the length divides P - 1.
+pub fn ntt<const P: u64>(a: &[Fp<P>]) -> Vec<Fp<P>> {
+    if a.is_empty() {
+        return vec![];
+    }
+    let root = root_of_unity::<P>(a.len()).expect("Transform length must divide P - 1");
+    dft(a, root, &prime_factors(a.len()))
+}
+
+/// Inverse transform a_j = n^{-1} \sum_k X_k w^{-jk}
+/// Panics unless the length divides P - 1.
+pub fn inverse_ntt<const P: u64>(a: &[Fp<P>]) -> Vec<Fp<P>> {
+    if a.is_empty() {
+        return vec![];
+    }
+    let n = a.len();
+    let root = root_of_unity::<P>(n).expect("Transform length must divide P - 1");
+    let n_inv = Fp::new(n as u64).inverse();
+    dft(a, root.inverse(), &prime_factors(n))
+        .into_iter()
+        .map(|x| x * n_inv)
+        .collect()
+}
+
+/// Linear convolution through a cyclic convolution of length the next divisor of P - 1
+/// Falls back to schoolbook multiplication for short operands, and when P - 1 has no divisor
+/// within a factor 2 of the result length.
+pub fn convolution_ntt<const P: u64>(a: &[Fp<P>], b: &[Fp<P>]) -> Vec<Fp<P>> {
+    if a.len().min(b.len()) <= SCHOOLBOOK_THRESHOLD {
+        return schoolbook_convolution(a, b);
+    }
+    let len = a.len() + b.len() - 1;
+    match transform_length::<P>(len).filter(|&n| n <= 2 * len) {
+        None => schoolbook_convolution(a, b),
+        Some(n) => {
+            let (mut a, mut b) = (a.to_vec(), b.to_vec());
+            a.resize(n, Fp::zero());
+            b.resize(n, Fp::zero());
+            let mut c = cyclic_convolution(&a, &b);
+            c.truncate(len);
+            c
+        }
+    }
+}
+
+/// Convolution in O(n log n) for large operands
+/// Pads to a power of two when it divides P - 1, then to the next divisor of P - 1 if that is within
+/// a factor 2 of the product length, and otherwise multiplies modulo the three CRT primes.
+/// Short operands use schoolbook multiplication.
+pub fn convolution_fast<const P: u64>(a: &[Fp<P>], b: &[Fp<P>]) -> Vec<Fp<P>> {
+    if a.len().min(b.len()) <= MUL_FAST_THRESHOLD {
+        return schoolbook_convolution(a, b);
+    }
+    let len = a.len() + b.len() - 1;
+    let n = len.next_power_of_two();
+    if (P - 1).is_multiple_of(n as u64) {
+        let (mut a, mut b) = (a.to_vec(), b.to_vec());
+        a.resize(n, Fp::zero());
+        b.resize(n, Fp::zero());
+        let mut c = cyclic_convolution(&a, &b);
+        c.truncate(len);
+        return c;
+    }
+    match transform_length::<P>(len).filter(|&t| t <= 2 * len) {
+        Some(_) => convolution_ntt(a, b),
+        None => {
+            let values = |v: &[Fp<P>]| v.iter().map(Fp::value).collect::<Vec<_>>();
+            convolution_mod(&values(a), &values(b), P)
+                .into_iter()
+                .map(Fp::new)
+                .collect()
+        }
+    }
+}
+
+/// Generating functions over Fp multiply by `convolution_fast`
+impl<const P: u64> Convolution for Fp<P> {
+    fn convolve(a: &[Self], b: &[Self]) -> Vec<Self> {
+        convolution_fast(a, b)
+    }
+}
+
+/// Convolution of u64 sequences modulo each of the CRT primes
+fn crt_residues(a: &[u64], b: &[u64]) -> (Vec<Fp<CRT_P0>>, Vec<Fp<CRT_P1>>, Vec<Fp<CRT_P2>>) {
+    // Every CRT prime has 2^51 | P - 1, so pad to powers of two rather than the smallest divisor
+    fn modulo<const P: u64>(a: &[u64], b: &[u64]) -> Vec<Fp<P>> {
+        let lift = |v: &[u64]| v.iter().map(|&x| Fp::new(x)).collect::<Vec<_>>();
+        let (mut a, mut b) = (lift(a), lift(b));
+        if a.len().min(b.len()) <= SCHOOLBOOK_THRESHOLD {
+            return schoolbook_convolution(&a, &b);
+        }
+        let len = a.len() + b.len() - 1;
+        a.resize(len.next_power_of_two(), Fp::zero());
+        b.resize(len.next_power_of_two(), Fp::zero());
+        let mut c = cyclic_convolution(&a, &b);
+        c.truncate(len);
+        c
+    }
+    (modulo(a, b), modulo(a, b), modulo(a, b))
+}
+
+/// Garner's algorithm: mixed-radix digits (r_0, t_1, t_2) of the x < p_0 p_1 p_2 with residues r_i
+/// $$ x = r_0 + p_0 (t_1 + p_1 t_2) $$
+/// where t_1 = (r_1 - r_0) p_0^{-1} mod p_1 and t_2 = ((r_2 - r_0) p_0^{-1} - t_1) p_1^{-1} mod p_2.
+fn garner(a: &[u64], b: &[u64]) -> Vec<(u64, u64, u64)> {
+    let (r0, r1, r2) = crt_residues(a, b);
+    let p0_inv_1 = Fp::<CRT_P1>::new(CRT_P0).inverse();
+    let p0_inv_2 = Fp::<CRT_P2>::new(CRT_P0).inverse();
+    let p1_inv_2 = Fp::<CRT_P2>::new(CRT_P1).inverse();
+    r0.into_iter()
+        .zip(r1)
+        .zip(r2)
+        .map(|((r0, r1), r2)| {
+            let r0 = r0.value();
+            let t1 = (r1 - Fp::new(r0)) * p0_inv_1;
+            let t2 = ((r2 - Fp::new(r0)) * p0_inv_2 - Fp::new(t1.value())) * p1_inv_2;
+            (r0, t1.value(), t2.value())
+        })
+        .collect()
+}
+
+/// Exact convolution of u64 sequences over Z
+/// The true coefficients are below n 2^128 for n the length of the shorter operand, so they always fit
+/// for 32 bit inputs. Returns None if some coefficient does not fit in a u128.
+pub fn convolution_u64(a: &[u64], b: &[u64]) -> Option<Vec<u128>> {
+    if a.is_empty() || b.is_empty() {
+        return Some(vec![]);
+    }
+    garner(a, b)
+        .into_iter()
+        .map(|(r0, t1, t2)| {
+            // t1 + p1 t2 < p1 p2 < 2^124, only the last two steps can overflow
+            let high = t1 as u128 + CRT_P1 as u128 * t2 as u128;
+            (CRT_P0 as u128).checked_mul(high)?.checked_add(r0 as u128)
+        })
+        .collect()
+}
+
+/// Convolution modulo any m > 0, through the exact integer convolution of the reduced inputs
+pub fn convolution_mod(a: &[u64], b: &[u64], m: u64) -> Vec<u64> {
+    assert!(m > 0, "Modulus must be positive");
+    if a.is_empty() || b.is_empty() {
+        return vec![];
+    }
+    let reduce = |v: &[u64]| v.iter().map(|x| x % m).collect::<Vec<_>>();
+    let p0 = CRT_P0 % m;
+    let p0_p1 = ((CRT_P0 as u128 * CRT_P1 as u128) % m as u128) as u64;
+    garner(&reduce(a), &reduce(b))
+        .into_iter()
+        .map(|(r0, t1, t2)| {
+            let x = (r0 % m) as u128
+                + mul_mod(p0, t1 % m, m) as u128
+                + mul_mod(p0_p1, t2 % m, m) as u128;
+            (x % m as u128) as u64
+        })
+        .collect()
+}
+
+impl Polynomial<u64> {
+    /// Exact product over Z by three-prime NTT
+    /// Returns None if some coefficient does not fit in a u128, as in `convolution_u64`.
+    pub fn mul_crt(&self, other: &Self) -> Option<Polynomial<u128>> {
+        convolution_u64(&self.coeffs, &other.coeffs).map(Polynomial::from_coeffs)
+    }
+}
+
+impl<const P: u64> Polynomial<Fp<P>> {
+    /// Product in O(n log n) for large operands, as in `convolution_fast`
+    pub fn mul_fast(&self, other: &Self) -> Self {
+        Polynomial::from_coeffs(convolution_fast(&self.coeffs, &other.coeffs))
+    }
+
+    /// Product by three-prime NTT, for any P including those with few roots of unity
+    pub fn mul_crt(&self, other: &Self) -> Self {
+        let values = |p: &Self| p.coeffs.iter().map(Fp::value).collect::<Vec<_>>();
+        Polynomial::from_coeffs(
+            convolution_mod(&values(self), &values(other), P)
+                .into_iter()
+                .map(Fp::new)
+                .collect(),
+        )
+    }
+}
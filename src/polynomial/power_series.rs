@@ -0,0 +1,266 @@
+//! Formal Power Series
+//! https://en.wikipedia.org/wiki/Formal_power_series
+//! Power series truncated at a fixed precision, f(x) mod x^n.
+
+use std::fmt::Display;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use super::{
+    genfunc::{convolution, Convolution},
+    Polynomial,
+};
+use crate::{field::FieldBase, ring::RingBase};
+
+/// Truncated formal power series
+/// $$ f(x) = \sum_{k=0}^{n-1} a_k x^k + O(x^n) $$
+/// Exactly n = precision coefficients are stored.
+/// Binary operations on series of different precisions keep the smaller one.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PowerSeries<T> {
+    pub(crate) coeffs: Vec<T>,
+}
+
+impl<T: RingBase> PowerSeries<T> {
+    /// New power series from its leading coefficients, truncated or padded with zeros to the given precision
+    pub fn new(mut coeffs: Vec<T>, precision: usize) -> Self {
+        coeffs.resize(precision, T::zero());
+        Self { coeffs }
+    }
+
+    /// Zero series 0 + O(x^n)
+    pub fn zero(precision: usize) -> Self {
+        Self::new(Vec::new(), precision)
+    }
+
+    /// Unit series 1 + O(x^n)
+    pub fn one(precision: usize) -> Self {
+        Self::new(vec![T::one()], precision)
+    }
+
+    /// Power series of a polynomial p(x) + O(x^n)
+    pub fn from_polynomial(poly: &Polynomial<T>, precision: usize) -> Self {
+        Self::new(poly.coeffs().to_vec(), precision)
+    }
+
+    /// Truncation to a polynomial of degree < n
+    pub fn to_polynomial(&self) -> Polynomial<T> {
+        Polynomial::from_coeffs(self.coeffs.clone())
+    }
+
+    /// Number of known coefficients n in f(x) + O(x^n)
+    pub fn precision(&self) -> usize {
+        self.coeffs.len()
+    }
+
+    /// Coefficients a_0, ..., a_{n-1}
+    pub fn coeffs(&self) -> &[T] {
+        &self.coeffs
+    }
+
+    /// Coefficient [x^k] f(x)
+    /// Panics if k is beyond the precision.
+    pub fn coeff(&self, k: usize) -> &T {
+        assert!(k < self.precision(), "Coefficient beyond series precision");
+        &self.coeffs[k]
+    }
+
+    /// Reduce the precision to n, keeping at most the current one
+    pub fn truncate(&self, precision: usize) -> Self {
+        Self {
+            coeffs: self.coeffs[..precision.min(self.precision())].to_vec(),
+        }
+    }
+}
+
+/// Product a b mod x^n
+fn mul_truncated<T>(a: &[T], b: &[T], n: usize) -> Vec<T>
+where
+    T: Convolution,
+{
+    let mut c = convolution(&a[..n.min(a.len())], &b[..n.min(b.len())]);
+    c.resize(n, T::zero());
+    c
+}
+
+impl<T> PowerSeries<T>
+where
+    T: Convolution,
+{
+    /// Composition f(g(x)) by the Brent-Kung baby-step giant-step method
+    /// With m ~ sqrt(n), f is split into blocks of m coefficients,
+    /// $$ f(g) = \sum_j \left( \sum_{i<m} a_{jm+i} g^i \right) (g^m)^j $$
+    /// so the inner sums reuse the powers g, ..., g^m and the outer sum is a Horner scheme in g^m,
+    /// about 2 sqrt(n) series products instead of n.
+    /// The precision is the smaller of both.
+    /// Panics if g has a non-zero constant term.
+    pub fn compose(&self, g: &Self) -> Self {
+        let n = self.precision().min(g.precision());
+        if n == 0 {
+            return Self::zero(0);
+        }
+        assert!(
+            g.coeffs[0] == T::zero(),
+            "Composition with non-zero constant term"
+        );
+
+        let m = (1..=n).find(|m| m * m >= n).unwrap();
+        // Baby steps g^0, ..., g^m
+        let mut powers = vec![Self::one(n).coeffs];
+        for i in 0..m {
+            powers.push(mul_truncated(&powers[i], &g.coeffs, n));
+        }
+        let giant = powers.pop().unwrap();
+
+        // Giant steps, highest block first
+        let mut result = vec![T::zero(); n];
+        for block in self.coeffs[..n].chunks(m).rev() {
+            result = mul_truncated(&result, &giant, n);
+            for (a, power) in block.iter().zip(&powers) {
+                for (r, p) in result.iter_mut().zip(power) {
+                    *r = *r + *a * *p;
+                }
+            }
+        }
+        Self { coeffs: result }
+    }
+}
+
+impl<T> PowerSeries<T>
+where
+    T: FieldBase + Convolution + Sub<Output = T>,
+{
+    /// Multiplicative inverse 1 / f(x), by Newton iteration g <- g (2 - f g)
+    /// Panics if the constant term is zero.
+    pub fn inverse(&self) -> Self {
+        let n = self.precision();
+        if n == 0 {
+            return Self::zero(0);
+        }
+        let two = T::one() + T::one();
+        let mut inv = vec![self.coeffs[0].inverse()];
+        while inv.len() < n {
+            let len = (2 * inv.len()).min(n);
+            let mut fg = convolution(&self.coeffs[..len], &inv);
+            fg.truncate(len);
+            let mut correction = fg.into_iter().map(|c| T::zero() - c).collect::<Vec<_>>();
+            correction[0] = correction[0] + two;
+            inv = convolution(&inv, &correction);
+            inv.truncate(len);
+        }
+        Self { coeffs: inv }
+    }
+
+    /// Compositional inverse g with f(g(x)) = g(f(x)) = x, by Lagrange inversion
+    /// $$ [x^k] g = \frac{1}{k} [x^{k-1}] \left( \frac{x}{f(x)} \right)^k $$
+    /// where the powers of x / f(x) are kept to precision n - 1.
+    /// Requires f(0) = 0 and f'(0) invertible, and the integers 1, ..., n - 1 invertible in T
+    /// (characteristic zero or larger than the precision), otherwise panics.
+    pub fn reverse(&self) -> Self {
+        let n = self.precision();
+        if n <= 1 {
+            return Self::zero(n);
+        }
+        assert!(self.coeffs[0] == T::zero(), "Reversion requires f(0) = 0");
+
+        // x / f(x), known to precision n - 1
+        let h = Self {
+            coeffs: self.coeffs[1..].to_vec(),
+        }
+        .inverse();
+
+        let mut coeffs = vec![T::zero(); n];
+        let mut power = vec![T::one()];
+        let mut k_t = T::zero();
+        for k in 1..n {
+            power = mul_truncated(&power, &h.coeffs, n - 1);
+            k_t = k_t + T::one();
+            coeffs[k] = power[k - 1] * k_t.inverse();
+        }
+        Self { coeffs }
+    }
+}
+
+impl<T: Display> Display for PowerSeries<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (k, c) in self.coeffs.iter().enumerate() {
+            match k {
+                0 => write!(f, "{c}")?,
+                1 => write!(f, " + {c} x")?,
+                _ => write!(f, " + {c} x^{k}")?,
+            }
+        }
+        if self.coeffs.is_empty() {
+            write!(f, "O(1)")
+        } else {
+            write!(f, " + O(x^{})", self.coeffs.len())
+        }
+    }
+}
+
+impl<T: Copy + Neg<Output = T>> Neg for PowerSeries<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            coeffs: self.coeffs.into_iter().map(|c| -c).collect(),
+        }
+    }
+}
+
+impl<T: Copy + Add<Output = T>> Add for PowerSeries<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            coeffs: self
+                .coeffs
+                .into_iter()
+                .zip(other.coeffs)
+                .map(|(a, b)| a + b)
+                .collect(),
+        }
+    }
+}
+
+impl<T: Copy + Sub<Output = T>> Sub for PowerSeries<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self {
+            coeffs: self
+                .coeffs
+                .into_iter()
+                .zip(other.coeffs)
+                .map(|(a, b)| a - b)
+                .collect(),
+        }
+    }
+}
+
+impl<T> Mul for PowerSeries<T>
+where
+    T: Convolution,
+{
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        let precision = self.precision().min(other.precision());
+        let mut coeffs = convolution(&self.coeffs[..precision], &other.coeffs[..precision]);
+        coeffs.resize(precision, T::zero());
+        Self { coeffs }
+    }
+}
+
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T> Div for PowerSeries<T>
+where
+    T: FieldBase + Convolution + Sub<Output = T>,
+{
+    type Output = Self;
+
+    /// Panics if the divisor has zero constant term.
+    fn div(self, other: Self) -> Self {
+        let precision = self.precision().min(other.precision());
+        self.truncate(precision) * other.truncate(precision).inverse()
+    }
+}
@@ -0,0 +1,357 @@
+//! Polynomials over the Rationals
+//! Utilities to move between Q[x] and Z[x] by clearing denominators.
+//! Exact algorithms (gcd, square-free decomposition, factorization) run over Z[x] on primitive polynomials,
+//! where coefficient growth is easier to control than with rational arithmetic.
+//! Integer coefficients are i128, so computations panic on overflow rather than returning wrong results.
+//! The `checked_` variants return None instead.
+
+use crate::number::{gcd_u128, Rational};
+use crate::polynomial::{rational_roots::divisors, Polynomial};
+
+/// Write f = p / d with p in Z[x] and d > 0 the least common denominator of the coefficients
+/// Panics if d or a coefficient of p overflows i128, see `checked_clear_denominators`.
+pub fn clear_denominators(f: &Polynomial<Rational>) -> (Polynomial<i128>, i128) {
+    checked_clear_denominators(f).expect("Common denominator overflow")
+}
+
+/// Write f = p / d as in `clear_denominators`, None if d or a coefficient of p overflows i128
+pub fn checked_clear_denominators(f: &Polynomial<Rational>) -> Option<(Polynomial<i128>, i128)> {
+    let den = f.coeffs.iter().try_fold(1_i128, |lcm, a| {
+        let g = gcd_u128(lcm as u128, a.den() as u128) as i128;
+        (lcm / g).checked_mul(a.den())
+    })?;
+    let num = f
+        .coeffs
+        .iter()
+        .map(|a| a.num().checked_mul(den / a.den()))
+        .collect::<Option<_>>()?;
+    Some((Polynomial::from_coeffs(num), den))
+}
+
+/// Polynomial p / d over the rationals
+pub fn from_integer(p: &Polynomial<i128>, den: i128) -> Polynomial<Rational> {
+    Polynomial::from_coeffs(p.coeffs.iter().map(|a| Rational::new(*a, den)).collect())
+}
+
+/// Content: gcd of the coefficients, signed like the leading coefficient
+/// The content of the zero polynomial is 0.
+pub fn content(p: &Polynomial<i128>) -> i128 {
+    let g = p
+        .coeffs
+        .iter()
+        .fold(0, |g, a| gcd_u128(g, a.unsigned_abs())) as i128;
+    match p.coeffs.last() {
+        Some(lc) if *lc < 0 => -g,
+        _ => g,
+    }
+}
+
+/// Primitive part: p divided by its content
+/// The result has coprime coefficients and a positive leading coefficient.
+pub fn primitive_part(p: &Polynomial<i128>) -> Polynomial<i128> {
+    let c = content(p);
+    if c == 0 {
+        return Polynomial::zero();
+    }
+    Polynomial::from_coeffs(p.coeffs.iter().map(|a| a / c).collect())
+}
+
+/// Canonical representative of f up to a rational factor: the primitive integer polynomial with positive leading coefficient
+/// Panics if clearing the denominators overflows i128.
+pub fn primitive_associate(f: &Polynomial<Rational>) -> Polynomial<i128> {
+    primitive_part(&clear_denominators(f).0)
+}
+
+/// Monic associate of f over the rationals
+pub fn monic(f: &Polynomial<Rational>) -> Polynomial<Rational> {
+    match f.coeffs.last() {
+        None => Polynomial::zero(),
+        Some(lc) => {
+            let lc = *lc;
+            Polynomial::from_coeffs(f.coeffs.iter().map(|a| *a / lc).collect())
+        }
+    }
+}
+
+/// Formal derivative in Z[x]
+fn derivative(p: &Polynomial<i128>) -> Polynomial<i128> {
+    Polynomial::from_coeffs(
+        p.coeffs
+            .iter()
+            .enumerate()
+            .skip(1)
+            .map(|(k, a)| a.checked_mul(k as i128).expect("Coefficient overflow"))
+            .collect(),
+    )
+}
+
+/// Primitive part of the pseudo-remainder of f by g, None on overflow
+/// Only the class of the remainder up to a rational factor matters for gcd computations.
+fn primitive_pseudo_rem(f: &Polynomial<i128>, g: &Polynomial<i128>) -> Option<Polynomial<i128>> {
+    let g_lc = *g.coeffs.last().expect("Division by the zero polynomial");
+    let mut r = f.clone();
+    while r.coeffs.len() >= g.coeffs.len() {
+        // r <- lc(g) r - lc(r) x^k g cancels the leading term
+        let shift = r.coeffs.len() - g.coeffs.len();
+        let r_lc = *r.coeffs.last().unwrap();
+        let mut coeffs = r
+            .coeffs
+            .iter()
+            .map(|a| a.checked_mul(g_lc))
+            .collect::<Option<Vec<_>>>()?;
+        for (i, b) in g.coeffs.iter().enumerate() {
+            coeffs[i + shift] = coeffs[i + shift].checked_sub(r_lc.checked_mul(*b)?)?;
+        }
+        r = primitive_part(&Polynomial::from_coeffs(coeffs));
+    }
+    Some(r)
+}
+
+/// Exact quotient f / g in Z[x]
+/// Panics if g does not divide f over the integers.
+fn exact_div(f: &Polynomial<i128>, g: &Polynomial<i128>) -> Polynomial<i128> {
+    checked_exact_div(f, g).expect("Inexact polynomial division")
+}
+
+/// Exact quotient f / g in Z[x], None if g does not divide f over the integers or on overflow
+fn checked_exact_div(f: &Polynomial<i128>, g: &Polynomial<i128>) -> Option<Polynomial<i128>> {
+    let g_lc = *g.coeffs.last().expect("Division by the zero polynomial");
+    let mut r = f.coeffs.clone();
+    if r.len() < g.coeffs.len() {
+        return f.coeffs.is_empty().then(Polynomial::zero);
+    }
+    let mut q = vec![0; r.len() + 1 - g.coeffs.len()];
+    for shift in (0..q.len()).rev() {
+        let lead = r[shift + g.coeffs.len() - 1];
+        if lead % g_lc != 0 {
+            return None;
+        }
+        q[shift] = lead / g_lc;
+        for (i, b) in g.coeffs.iter().enumerate() {
+            r[i + shift] = r[i + shift].checked_sub(q[shift].checked_mul(*b)?)?;
+        }
+    }
+    r.iter()
+        .all(|a| *a == 0)
+        .then(|| Polynomial::from_coeffs(q))
+}
+
+/// Greatest common divisor in Z[x]
+/// Computed with the primitive polynomial remainder sequence.
+/// The result has a positive leading coefficient and its content is the gcd of the contents.
+/// Panics if a pseudo-remainder overflows i128, see `checked_integer_gcd`.
+pub fn integer_gcd(f: &Polynomial<i128>, g: &Polynomial<i128>) -> Polynomial<i128> {
+    checked_integer_gcd(f, g).expect("Coefficient overflow")
+}
+
+/// Greatest common divisor in Z[x] as in `integer_gcd`, None if a pseudo-remainder overflows i128
+pub fn checked_integer_gcd(f: &Polynomial<i128>, g: &Polynomial<i128>) -> Option<Polynomial<i128>> {
+    let c = gcd_u128(content(f).unsigned_abs(), content(g).unsigned_abs()) as i128;
+    let (mut a, mut b) = (primitive_part(f), primitive_part(g));
+    while !b.coeffs.is_empty() {
+        (a, b) = (b.clone(), primitive_pseudo_rem(&a, &b)?);
+    }
+    let coeffs = a
+        .coeffs
+        .iter()
+        .map(|x| x.checked_mul(c))
+        .collect::<Option<_>>()?;
+    Some(Polynomial::from_coeffs(coeffs))
+}
+
+/// Greatest common divisor in Q[x], normalized to be monic
+/// Panics if clearing the denominators or a pseudo-remainder overflows i128, see `checked_gcd`.
+pub fn gcd(f: &Polynomial<Rational>, g: &Polynomial<Rational>) -> Polynomial<Rational> {
+    checked_gcd(f, g).expect("Coefficient overflow")
+}
+
+/// Greatest common divisor in Q[x] as in `gcd`, None on overflow of the integer computation
+pub fn checked_gcd(
+    f: &Polynomial<Rational>,
+    g: &Polynomial<Rational>,
+) -> Option<Polynomial<Rational>> {
+    let f = primitive_part(&checked_clear_denominators(f)?.0);
+    let g = primitive_part(&checked_clear_denominators(g)?.0);
+    Some(monic(&from_integer(&checked_integer_gcd(&f, &g)?, 1)))
+}
+
+/// Square-free decomposition of a primitive polynomial using Yun's algorithm
+/// Returns the pairs (a_i, i) with f = \prod a_i^i, the a_i square-free, pairwise coprime and primitive.
+fn squarefree_decomposition(f: &Polynomial<i128>) -> Vec<(Polynomial<i128>, usize)> {
+    let mut factors = vec![];
+    let df = derivative(f);
+    let a = integer_gcd(f, &df);
+    let mut b = exact_div(f, &a);
+    let mut c = exact_div(&df, &a);
+    let mut d = &c - &derivative(&b);
+    let mut i = 1;
+
+    while b.degree().is_some_and(|deg| deg > 0) {
+        let a = primitive_part(&integer_gcd(&b, &d));
+        b = exact_div(&b, &a);
+        c = exact_div(&d, &a);
+        d = &c - &derivative(&b);
+        if a.degree().is_some_and(|deg| deg > 0) {
+            factors.push((a, i));
+        }
+        i += 1;
+    }
+
+    factors
+}
+
+/// Value p(x) at an integer point
+fn evaluate(p: &Polynomial<i128>, x: i128) -> i128 {
+    p.coeffs.iter().rev().fold(0, |acc, a| {
+        acc.checked_mul(x)
+            .and_then(|v| v.checked_add(*a))
+            .expect("Coefficient overflow")
+    })
+}
+
+/// Integer polynomial of degree less than n through n points with distinct abscissas
+/// Newton interpolation over Q, None unless every coefficient is an integer or on overflow.
+fn integer_interpolation(points: &[(i128, i128)]) -> Option<Polynomial<i128>> {
+    let n = points.len();
+    let mut c = points
+        .iter()
+        .map(|(_, y)| Rational::integer(*y))
+        .collect::<Vec<_>>();
+    for j in 1..n {
+        for i in (j..n).rev() {
+            let dx = Rational::integer(points[i].0 - points[i - j].0);
+            c[i] = c[i].checked_sub(&c[i - 1])?.checked_div(&dx)?;
+        }
+    }
+
+    // Expand c_0 + (x - x_0)(c_1 + (x - x_1)(c_2 + ...)) from the inside out
+    let mut coeffs = vec![c[n - 1]];
+    for k in (0..n - 1).rev() {
+        let x_k = Rational::integer(points[k].0);
+        let mut next = vec![Rational::integer(0); coeffs.len() + 1];
+        for (i, a) in coeffs.iter().enumerate() {
+            next[i + 1] = next[i + 1].checked_add(a)?;
+            next[i] = next[i].checked_sub(&a.checked_mul(&x_k)?)?;
+        }
+        next[0] = next[0].checked_add(&c[k])?;
+        coeffs = next;
+    }
+    coeffs
+        .iter()
+        .map(|a| a.is_integer().then(|| a.num()))
+        .collect::<Option<Vec<_>>>()
+        .map(Polynomial::from_coeffs)
+}
+
+/// Factor g of degree d of f and the cofactor f / g, by Kronecker's method
+/// A factor g takes a value dividing f(x_i) at every integer x_i, so trying every choice of divisors
+/// at d + 1 points and interpolating finds g if it exists. The points with the fewest divisors are used.
+/// f must be primitive without rational roots, so it does not vanish at any integer.
+fn kronecker_factor(
+    f: &Polynomial<i128>,
+    d: usize,
+) -> Option<(Polynomial<i128>, Polynomial<i128>)> {
+    // Candidate points 0, 1, -1, 2, -2, ...
+    let mut points = (0..2 * d as i128 + 2)
+        .map(|k| match k % 2 {
+            0 => -k / 2,
+            _ => k / 2 + 1,
+        })
+        .map(|x| {
+            let divisors = divisors(evaluate(f, x).unsigned_abs())
+                .into_iter()
+                .map(|a| a as i128)
+                .collect::<Vec<_>>();
+            (x, divisors)
+        })
+        .collect::<Vec<_>>();
+    points.sort_by_key(|(_, divisors)| divisors.len());
+    points.truncate(d + 1);
+    // Up to sign g(x_0) > 0, the other values take both signs
+    let choices = points
+        .iter()
+        .enumerate()
+        .map(|(i, (_, divisors))| match i {
+            0 => divisors.clone(),
+            _ => divisors.iter().flat_map(|a| [*a, -a]).collect(),
+        })
+        .collect::<Vec<Vec<_>>>();
+
+    let mut index = vec![0; d + 1];
+    loop {
+        let values = points
+            .iter()
+            .zip(&choices)
+            .zip(&index)
+            .map(|(((x, _), values), i)| (*x, values[*i]))
+            .collect::<Vec<_>>();
+        if let Some(g) = integer_interpolation(&values).filter(|g| g.degree() == Some(d)) {
+            if let Some(q) = checked_exact_div(f, &g) {
+                return Some((primitive_part(&g), primitive_part(&q)));
+            }
+        }
+
+        // Next choice of values, as an odometer
+        let mut i = 0;
+        loop {
+            if i > d {
+                return None;
+            }
+            index[i] += 1;
+            if index[i] < choices[i].len() {
+                break;
+            }
+            index[i] = 0;
+            i += 1;
+        }
+    }
+}
+
+/// Irreducible factors of a square-free primitive polynomial without rational roots
+/// Factors are split off by increasing degree, so each one found is irreducible,
+/// and what remains once no factor of degree at most half its own is left is irreducible too.
+fn irreducible_factors(f: Polynomial<i128>) -> Vec<Polynomial<i128>> {
+    let mut factors = vec![];
+    let mut rest = f;
+    let mut d = 2;
+    while rest.degree().is_some_and(|deg| deg >= 2 * d) {
+        match kronecker_factor(&rest, d) {
+            Some((g, q)) => {
+                factors.push(g);
+                rest = q;
+            }
+            None => d += 1,
+        }
+    }
+    factors.push(primitive_part(&rest));
+    factors
+}
+
+/// Factorization over Q
+/// Returns (c, [(p_1, e_1), ..., (p_k, e_k)]) with f = c \prod p_i^{e_i}, each p_i irreducible and primitive in Z[x]
+/// with positive leading coefficient.
+/// The factors come from the square-free decomposition, with every linear factor q x - p (from a rational root p / q)
+/// split off first. The remaining parts are split with Kronecker's method, whose cost grows exponentially
+/// with the degree, so this is only practical for polynomials of small degree.
+/// Panics on the zero polynomial, and if an intermediate coefficient overflows i128.
+pub fn factor(f: &Polynomial<Rational>) -> (Rational, Vec<(Polynomial<i128>, usize)>) {
+    let (p, den) = clear_denominators(f);
+    assert!(!p.coeffs.is_empty(), "Cannot factor the zero polynomial");
+    let c = Rational::new(content(&p), den);
+    let p = primitive_part(&p);
+
+    let mut factors = vec![];
+    for (a, e) in squarefree_decomposition(&p) {
+        let mut rest = a;
+        for root in from_integer(&rest, 1).rational_roots() {
+            let linear = Polynomial::from_coeffs(vec![-root.num(), root.den()]);
+            rest = exact_div(&rest, &linear);
+            factors.push((linear, e));
+        }
+        if rest.degree().is_some_and(|deg| deg > 0) {
+            factors.extend(irreducible_factors(rest).into_iter().map(|g| (g, e)));
+        }
+    }
+
+    (c, factors)
+}
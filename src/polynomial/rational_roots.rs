@@ -0,0 +1,131 @@
+//! Rational Roots
+//! Exact root analysis for polynomials with integer or rational coefficients.
+
+use crate::number::{factorize, gcd_u128, Rational};
+use crate::polynomial::{rational::clear_denominators, Polynomial};
+
+/// Positive divisors of n
+/// Panics if n does not fit in a u64.
+pub(crate) fn divisors(n: u128) -> Vec<u128> {
+    let n = u64::try_from(n).expect("Coefficients too large to enumerate their divisors");
+    factorize(n).into_iter().fold(vec![1], |divisors, (p, e)| {
+        divisors
+            .iter()
+            .flat_map(|d| (0..=e).map(move |k| d * (p as u128).pow(k)))
+            .collect()
+    })
+}
+
+/// Check whether p / q is a root of the integer polynomial a_0 + a_1 x + ... + a_n x^n
+/// Performs the synthetic division by (q x - p), which is exact iff p / q is a root.
+/// The quotient by a factor q x - p has small integer coefficients, so a candidate whose intermediate values
+/// overflow is not a root.
+fn is_root(coeffs: &[i128], p: i128, q: i128) -> bool {
+    let mut c = 0_i128;
+    for a in coeffs.iter().skip(1).rev() {
+        let Some(sum) = p.checked_mul(c).and_then(|pc| pc.checked_add(*a)) else {
+            return false;
+        };
+        if sum % q != 0 {
+            return false;
+        }
+        c = sum / q;
+    }
+    p.checked_mul(c)
+        .and_then(|pc| pc.checked_add(coeffs[0]))
+        .is_some_and(|value| value == 0)
+}
+
+/// Rational roots of a polynomial with integer coefficients (lowest degree first)
+/// By the rational root theorem every root p / q in lowest terms has p | a_0 and q | a_n.
+fn integer_rational_roots(coeffs: &[i128]) -> Vec<Rational> {
+    let mut roots = vec![];
+
+    // Factor out x^k
+    let Some(k) = coeffs.iter().position(|a| *a != 0) else {
+        return roots;
+    };
+    if k > 0 {
+        roots.push(Rational::integer(0));
+    }
+    let coeffs = &coeffs[k..];
+
+    let (a_0, a_n) = (coeffs[0], coeffs[coeffs.len() - 1]);
+    let qs = divisors(a_n.unsigned_abs());
+    for p in divisors(a_0.unsigned_abs()) {
+        for q in qs.iter().copied() {
+            if gcd_u128(p, q) != 1 {
+                continue;
+            }
+            let (p, q) = (p as i128, q as i128);
+            for p in [p, -p] {
+                if is_root(coeffs, p, q) {
+                    roots.push(Rational::new(p, q));
+                }
+            }
+        }
+    }
+
+    roots.sort();
+    roots
+}
+
+/// Number of sign changes in a sequence, ignoring zeros
+fn sign_changes(signs: impl Iterator<Item = i128>) -> usize {
+    signs
+        .filter(|s| *s != 0)
+        .fold((0, 0), |(changes, last), s| {
+            if last != 0 && s != last {
+                (changes + 1, s)
+            } else {
+                (changes, s)
+            }
+        })
+        .0
+}
+
+/// Descartes' rule of signs for a sequence of coefficient signs (lowest degree first)
+fn descartes(signs: Vec<i128>) -> (usize, usize) {
+    let positive = sign_changes(signs.iter().copied());
+    // Roots of p(-x): flip the sign of odd degree coefficients
+    let negative = sign_changes(
+        signs
+            .iter()
+            .enumerate()
+            .map(|(i, s)| if i % 2 == 1 { -s } else { *s }),
+    );
+    (positive, negative)
+}
+
+impl Polynomial<i64> {
+    /// Distinct rational roots, in increasing order
+    /// Candidates p / q with p dividing the constant term and q dividing the leading coefficient are
+    /// verified exactly. The zero polynomial has no listed roots.
+    pub fn rational_roots(&self) -> Vec<Rational> {
+        integer_rational_roots(&self.coeffs.iter().map(|a| *a as i128).collect::<Vec<_>>())
+    }
+
+    /// Descartes' rule of signs
+    /// Returns upper bounds (positive, negative) on the number of positive and negative real roots,
+    /// counted with multiplicity. Each bound exceeds the actual count by an even number.
+    pub fn descartes_sign_bound(&self) -> (usize, usize) {
+        descartes(self.coeffs.iter().map(|a| a.signum() as i128).collect())
+    }
+}
+
+impl Polynomial<Rational> {
+    /// Distinct rational roots, in increasing order
+    /// Denominators are cleared first, then the rational root theorem applies to the integer polynomial.
+    /// Panics if the common denominator overflows i128, see `checked_clear_denominators`.
+    pub fn rational_roots(&self) -> Vec<Rational> {
+        let (p, _) = clear_denominators(self);
+        integer_rational_roots(&p.coeffs)
+    }
+
+    /// Descartes' rule of signs
+    /// Returns upper bounds (positive, negative) on the number of positive and negative real roots,
+    /// counted with multiplicity. Each bound exceeds the actual count by an even number.
+    pub fn descartes_sign_bound(&self) -> (usize, usize) {
+        descartes(self.coeffs.iter().map(|a| a.num().signum()).collect())
+    }
+}
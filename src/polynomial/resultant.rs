@@ -0,0 +1,184 @@
+//! Resultants
+//! https://en.wikipedia.org/wiki/Resultant
+//! The resultant of f and g is the determinant of their Sylvester matrix. It is a polynomial expression
+//! in the coefficients that vanishes iff f and g have a common root or both leading coefficients vanish.
+//! Taking it with respect to one variable eliminates that variable from a pair of bivariate equations.
+
+use std::fmt::Display;
+use std::ops::{Add, Mul, Sub};
+
+use super::{
+    multivariate::{ordering::MonomialOrder, MultiPoly},
+    roots::RootField,
+    Polynomial,
+};
+use crate::{matrix::Matrix, ring::RingBase};
+
+const NEWTON_STEPS: usize = 3;
+
+/// The system has no finite set of solutions
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EliminationError {
+    /// The polynomials share a non-constant factor, so the resultant vanishes identically
+    CommonFactor,
+}
+
+impl Display for EliminationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EliminationError::CommonFactor => write!(f, "Polynomials have a common factor"),
+        }
+    }
+}
+
+impl std::error::Error for EliminationError {}
+
+/// Sylvester matrix of f of degree m and g of degree n
+/// The first n rows hold the coefficients of f, highest degree first, shifted one column per row,
+/// the last m rows those of g. Zero polynomials are taken as degree 0.
+pub fn sylvester_matrix<R: RingBase>(f: &Polynomial<R>, g: &Polynomial<R>) -> Matrix<R> {
+    let (m, n) = (f.degree().unwrap_or(0), g.degree().unwrap_or(0));
+    let coeff = |p: &Polynomial<R>, k: usize| p.coeffs.get(k).cloned().unwrap_or_else(R::zero);
+    Matrix::from_fn(m + n, m + n, |i, j| {
+        if i < n {
+            (j >= i && j <= i + m).then(|| coeff(f, m + i - j))
+        } else {
+            let i = i - n;
+            (j >= i && j <= i + n).then(|| coeff(g, n + i - j))
+        }
+        .unwrap_or_else(R::zero)
+    })
+}
+
+/// Resultant Res(f, g) = det Sylvester(f, g)
+/// Equals lc(f)^n lc(g)^m \prod (a_i - b_j) over the roots a_i of f and b_j of g.
+/// Zero if either polynomial is zero.
+pub fn resultant<R>(f: &Polynomial<R>, g: &Polynomial<R>) -> R
+where
+    R: RingBase + Add<Output = R> + Sub<Output = R> + Mul<Output = R>,
+{
+    if f.degree().is_none() || g.degree().is_none() {
+        return R::zero();
+    }
+    sylvester_matrix(f, g).berkowitz_determinant()
+}
+
+/// Bivariate polynomial as a polynomial in the first variable with coefficients in the second
+fn in_first_variable<T, O>(f: &MultiPoly<T, O>) -> Polynomial<Polynomial<T>>
+where
+    T: RingBase + Copy,
+    O: MonomialOrder,
+{
+    assert_eq!(f.nvars(), 2, "Polynomials must be bivariate");
+    let mut coeffs: Vec<Vec<T>> = vec![];
+    for (monomial, c) in f.terms() {
+        let (i, j) = (
+            monomial.exponents()[0] as usize,
+            monomial.exponents()[1] as usize,
+        );
+        if coeffs.len() <= i {
+            coeffs.resize(i + 1, vec![]);
+        }
+        if coeffs[i].len() <= j {
+            coeffs[i].resize(j + 1, T::zero());
+        }
+        coeffs[i][j] = *c;
+    }
+    Polynomial::from_coeffs(coeffs.into_iter().map(Polynomial::from_coeffs).collect())
+}
+
+/// Content in y: the gcd of the coefficients of p as a polynomial in x
+fn content<T: RootField>(p: &Polynomial<Polynomial<T>>) -> Polynomial<T> {
+    p.coeffs
+        .iter()
+        .fold(Polynomial::zero(), |content, c| content.gcd(c))
+}
+
+/// Value of p(x, y) and its partial derivatives in x and y, by Horner's scheme
+fn evaluate_with_gradient<T: RootField>(p: &Polynomial<Polynomial<T>>, x: T, y: T) -> (T, T, T) {
+    let zero = T::zero();
+    p.coeffs
+        .iter()
+        .rev()
+        .fold((zero, zero, zero), |(value, dx, dy), c| {
+            let (c, dc) = c
+                .coeffs
+                .iter()
+                .rev()
+                .fold((zero, zero), |(v, dv), a| (v * y + *a, dv * y + v));
+            (value * x + c, dx * x + value, dy * x + dc)
+        })
+}
+
+/// Newton steps on the system f = g = 0 from (x, y)
+/// Exact solutions are left unchanged, numerical ones from multiple roots of the resultant regain full precision.
+fn newton_refine<T: RootField>(
+    f: &Polynomial<Polynomial<T>>,
+    g: &Polynomial<Polynomial<T>>,
+    (mut x, mut y): (T, T),
+) -> (T, T) {
+    for _ in 0..NEWTON_STEPS {
+        let (fv, fx, fy) = evaluate_with_gradient(f, x, y);
+        let (gv, gx, gy) = evaluate_with_gradient(g, x, y);
+        let det = fx * gy - fy * gx;
+        if det == T::zero() {
+            break;
+        }
+        let det_inv = det.inverse();
+        x -= (gy * fv - fy * gv) * det_inv;
+        y -= (fx * gv - gx * fv) * det_inv;
+    }
+    (x, y)
+}
+
+/// Common solutions (x, y) of f(x, y) = g(x, y) = 0
+/// Eliminates x with the resultant r(y) = Res_x(f, g), whose roots are the y coordinates of the solutions
+/// (and of common zeros at infinity). Each root y_0 is substituted back and the roots of f(x, y_0)
+/// are kept where g(x, y_0) also vanishes, then polished by a few Newton steps on the system.
+/// The solution set is finite iff f and g have no common factor, which is reported as an error
+/// (even over finite fields, where the solutions remain finite in number).
+/// Panics unless both polynomials have exactly two variables.
+pub fn solve_bivariate<T, O>(
+    f: &MultiPoly<T, O>,
+    g: &MultiPoly<T, O>,
+) -> Result<Vec<(T, T)>, EliminationError>
+where
+    T: RootField,
+    O: MonomialOrder,
+{
+    let (fx, gx) = (in_first_variable(f), in_first_variable(g));
+    let r = resultant(&fx, &gx);
+    if r.degree().is_none() {
+        return Err(EliminationError::CommonFactor);
+    }
+    // Res_x misses common factors that do not involve x, which divide both contents
+    if content(&fx).gcd(&content(&gx)).degree() > Some(0) {
+        return Err(EliminationError::CommonFactor);
+    }
+
+    let substitute = |p: &Polynomial<Polynomial<T>>, y: T| {
+        Polynomial::from_coeffs(
+            p.coeffs
+                .iter()
+                .map(|c| c.coeffs.iter().rev().fold(T::zero(), |acc, a| acc * y + *a))
+                .collect(),
+        )
+    };
+    let mut solutions = vec![];
+    for y in T::roots(&r) {
+        let (fy, gy) = (substitute(&fx, y), substitute(&gx, y));
+        // Both vanishing would make y - y_0 a common factor, excluded above up to rounding
+        let (p, q) = match (fy.degree(), gy.degree()) {
+            (None, None) => return Err(EliminationError::CommonFactor),
+            (Some(_), _) => (fy, gy),
+            (None, Some(_)) => (gy, fy),
+        };
+        solutions.extend(
+            T::roots(&p)
+                .into_iter()
+                .filter(|x| T::is_root(&q, *x))
+                .map(|x| newton_refine(&fx, &gx, (x, y))),
+        );
+    }
+    Ok(solutions)
+}
@@ -0,0 +1,136 @@
+//! Polynomial Roots
+//! https://en.wikipedia.org/wiki/Durand%E2%80%93Kerner_method
+//! Roots of univariate polynomials over fields where they can be found:
+//! exactly over finite fields from the linear factors, numerically over the complex numbers
+//! by the Durand-Kerner (Weierstrass) simultaneous iteration.
+
+use std::ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign};
+
+use super::{
+    factor::{factor, FactorAlgorithm},
+    Polynomial,
+};
+use crate::{field::finite_field::Fp, field::FieldBase, number::Complex, ring::RingBase};
+
+/// Relative residual below which a complex point counts as a root
+const ROOT_TOLERANCE: f64 = 1e-6;
+
+/// Relative distance below which approximations are taken as the same multiple root
+const CLUSTER_TOLERANCE: f64 = 1e-5;
+
+const MAX_ITERATIONS: usize = 1000;
+
+/// Fields in which univariate polynomials can be solved
+pub trait RootField:
+    FieldBase
+    + Copy
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + AddAssign
+    + SubAssign
+    + MulAssign
+{
+    /// Distinct roots of a non-zero polynomial
+    fn roots(f: &Polynomial<Self>) -> Vec<Self>;
+
+    /// Whether f(x) = 0, up to rounding for floating point fields
+    fn is_root(f: &Polynomial<Self>, x: Self) -> bool;
+}
+
+fn horner<T: RingBase + Copy + Add<Output = T> + Mul<Output = T>>(f: &Polynomial<T>, x: T) -> T {
+    f.coeffs.iter().rev().fold(T::zero(), |acc, c| acc * x + *c)
+}
+
+/// Roots are the constant terms of the linear factors
+impl<const P: u64> RootField for Fp<P> {
+    fn roots(f: &Polynomial<Self>) -> Vec<Self> {
+        let (_, factors) = factor(f, FactorAlgorithm::Auto);
+        factors
+            .into_iter()
+            .filter(|(p, _)| p.degree() == Some(1))
+            .map(|(p, _)| Self::zero() - p.coeffs[0])
+            .collect()
+    }
+
+    fn is_root(f: &Polynomial<Self>, x: Self) -> bool {
+        horner(f, x) == Self::zero()
+    }
+}
+
+/// Durand-Kerner iteration z_k <- z_k - f(z_k) / \prod_{j != k} (z_k - z_j) on the monic f,
+/// started on a circle enclosing all roots.
+/// Multiple roots converge only linearly and to about eps^{1/m}, their approximations are merged.
+impl RootField for Complex {
+    fn roots(f: &Polynomial<Self>) -> Vec<Self> {
+        let scale = f.coeffs.iter().map(Complex::abs).fold(0.0, f64::max);
+        assert!(scale > 0.0, "Roots of the zero polynomial");
+
+        // Leading coefficients at rounding level are cancellation leftovers
+        let mut coeffs = f.coeffs.clone();
+        while coeffs
+            .last()
+            .is_some_and(|c| c.abs() <= 16.0 * f64::EPSILON * scale)
+        {
+            coeffs.pop();
+        }
+        let lc_inv = coeffs.last().unwrap().inverse();
+        let monic = Polynomial {
+            coeffs: coeffs.iter().map(|c| *c * lc_inv).collect::<Vec<_>>(),
+        };
+        let n = monic.coeffs.len() - 1;
+        if n == 0 {
+            return vec![];
+        }
+
+        // Cauchy bound on the moduli of the roots
+        let radius = 1.0
+            + monic.coeffs[..n]
+                .iter()
+                .map(Complex::abs)
+                .fold(0.0, f64::max);
+        let mut z = (0..n)
+            .map(|k| {
+                let angle = 2.0 * std::f64::consts::PI * k as f64 / n as f64 + 0.4;
+                Complex::new(radius * angle.cos(), radius * angle.sin())
+            })
+            .collect::<Vec<_>>();
+        for _ in 0..MAX_ITERATIONS {
+            let mut max_step: f64 = 0.0;
+            for k in 0..n {
+                let denominator = (0..n)
+                    .filter(|&j| j != k)
+                    .fold(Complex::one(), |acc, j| acc * (z[k] - z[j]));
+                if denominator == Complex::zero() {
+                    continue;
+                }
+                let step = horner(&monic, z[k]) / denominator;
+                z[k] -= step;
+                max_step = max_step.max(step.abs() / (1.0 + z[k].abs()));
+            }
+            if max_step <= 4.0 * f64::EPSILON {
+                break;
+            }
+        }
+
+        z.into_iter().fold(Vec::new(), |mut roots, r| {
+            if !roots
+                .iter()
+                .any(|s: &Complex| (*s - r).abs() <= CLUSTER_TOLERANCE * (1.0 + r.abs()))
+            {
+                roots.push(r);
+            }
+            roots
+        })
+    }
+
+    /// |f(x)| small relative to \sum |a_i| |x|^i, the size of the terms that cancel
+    fn is_root(f: &Polynomial<Self>, x: Self) -> bool {
+        let magnitude = f
+            .coeffs
+            .iter()
+            .rev()
+            .fold(0.0, |acc, c| acc * x.abs() + c.abs());
+        horner(f, x).abs() <= ROOT_TOLERANCE * magnitude
+    }
+}
@@ -1,7 +1,8 @@
 use super::Polynomial;
+use crate::field::FieldBase;
 use crate::ring::RingBase;
 use std::fmt::Display;
-use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, Sub, SubAssign};
 
 // ============================================================================
 // 1. Unital and Display Implementations
@@ -86,7 +87,7 @@ where
 }
 
 /// Negate a polynomial reference: -&P(x)
-impl<'a, T> Neg for &'a Polynomial<T>
+impl<T> Neg for &Polynomial<T>
 where
     T: RingBase + Neg<Output = T> + Copy,
 {
@@ -176,6 +177,7 @@ where
 // --- MulAssign ---
 
 /// In-place multiplication: P(x) *= Q(x)
+/// Schoolbook O(n m), see `Polynomial::mul_fast` for large polynomials over Fp.
 impl<T> MulAssign for Polynomial<T>
 where
     T: RingBase + Copy + MulAssign<T> + Mul<Output = T> + AddAssign<T>,
@@ -233,7 +235,7 @@ where
 }
 
 /// &P(x) + &Q(x)
-impl<'a, 'b, T> Add<&'b Polynomial<T>> for &'a Polynomial<T>
+impl<'b, T> Add<&'b Polynomial<T>> for &Polynomial<T>
 where
     T: RingBase + Copy + AddAssign,
 {
@@ -258,7 +260,7 @@ where
 }
 
 /// &P(x) + Q(x)
-impl<'a, T> Add<Polynomial<T>> for &'a Polynomial<T>
+impl<T> Add<Polynomial<T>> for &Polynomial<T>
 where
     T: RingBase + Copy + AddAssign,
 {
@@ -284,7 +286,7 @@ where
 }
 
 /// &P(x) - &Q(x)
-impl<'a, 'b, T> Sub<&'b Polynomial<T>> for &'a Polynomial<T>
+impl<'b, T> Sub<&'b Polynomial<T>> for &Polynomial<T>
 where
     T: RingBase + Copy + SubAssign,
 {
@@ -309,7 +311,7 @@ where
 }
 
 /// &P(x) - Q(x)
-impl<'a, T> Sub<Polynomial<T>> for &'a Polynomial<T>
+impl<T> Sub<Polynomial<T>> for &Polynomial<T>
 where
     T: RingBase + Copy + SubAssign + Neg<Output = T>,
 {
@@ -337,7 +339,7 @@ where
 }
 
 /// &P(x) * &Q(x)
-impl<'a, 'b, T> Mul<&'b Polynomial<T>> for &'a Polynomial<T>
+impl<'b, T> Mul<&'b Polynomial<T>> for &Polynomial<T>
 where
     T: RingBase + Copy + MulAssign<T> + Mul<Output = T> + AddAssign<T>,
 {
@@ -362,7 +364,7 @@ where
 }
 
 /// &P(x) * Q(x)
-impl<'a, T> Mul<Polynomial<T>> for &'a Polynomial<T>
+impl<T> Mul<Polynomial<T>> for &Polynomial<T>
 where
     T: RingBase + Copy + MulAssign<T> + Mul<Output = T> + AddAssign<T>,
 {
@@ -373,6 +375,54 @@ where
     }
 }
 
+// --- Div and Rem ---
+
+/// Quotient of Euclidean division P(x) / Q(x)
+/// Panics if Q is the zero polynomial.
+impl<T> Div for Polynomial<T>
+where
+    T: FieldBase + Copy + Mul<Output = T> + Sub<Output = T>,
+{
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self {
+        self.div_rem(&rhs).0
+    }
+}
+
+/// &P(x) / &Q(x)
+impl<'b, T> Div<&'b Polynomial<T>> for &Polynomial<T>
+where
+    T: FieldBase + Copy + Mul<Output = T> + Sub<Output = T>,
+{
+    type Output = Polynomial<T>;
+    fn div(self, rhs: &'b Polynomial<T>) -> Polynomial<T> {
+        self.div_rem(rhs).0
+    }
+}
+
+/// Remainder of Euclidean division P(x) mod Q(x)
+/// Panics if Q is the zero polynomial.
+impl<T> Rem for Polynomial<T>
+where
+    T: FieldBase + Copy + Mul<Output = T> + Sub<Output = T>,
+{
+    type Output = Self;
+    fn rem(self, rhs: Self) -> Self {
+        self.div_rem(&rhs).1
+    }
+}
+
+/// &P(x) mod &Q(x)
+impl<'b, T> Rem<&'b Polynomial<T>> for &Polynomial<T>
+where
+    T: FieldBase + Copy + Mul<Output = T> + Sub<Output = T>,
+{
+    type Output = Polynomial<T>;
+    fn rem(self, rhs: &'b Polynomial<T>) -> Polynomial<T> {
+        self.div_rem(rhs).1
+    }
+}
+
 // ============================================================================
 // 4. Polynomial-Scalar Operations
 // ============================================================================
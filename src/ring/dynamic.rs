@@ -0,0 +1,598 @@
+//! Dynamic Rings
+//! Object-safe counterparts of `RingBase` and `FieldBase`, for coefficient domains chosen at runtime.
+//! Elements are boxed behind `DynElement` and carry their ring, so values from different rings can live in
+//! one collection and operations between them report a mismatch instead of failing to compile.
+
+use std::any::Any;
+use std::fmt::{Debug, Display};
+use std::ops::{Add, Mul, Neg, Sub};
+
+use crate::{
+    error::AlgebraError,
+    field::{finite_field::zn::Zn, FieldBase},
+    number::{is_prime, Complex, Rational},
+    ring::RingBase,
+};
+
+/// Failed operation on dynamic elements
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DynRingError {
+    /// The operands belong to different rings
+    RingMismatch,
+    /// The result does not fit the representation of the ring
+    Overflow,
+    /// Arithmetic failure within the ring, such as inverting zero or another non-unit
+    Algebra(AlgebraError),
+}
+
+impl Display for DynRingError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DynRingError::RingMismatch => write!(f, "Operands belong to different rings"),
+            DynRingError::Overflow => write!(f, "Arithmetic overflow"),
+            DynRingError::Algebra(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl std::error::Error for DynRingError {}
+
+impl From<AlgebraError> for DynRingError {
+    fn from(e: AlgebraError) -> Self {
+        DynRingError::Algebra(e)
+    }
+}
+
+/// Element of a ring chosen at runtime
+/// Binary operations downcast the other operand to the same concrete type.
+pub trait DynElement: Debug {
+    /// Ring containing the element
+    fn ring(&self) -> Box<dyn DynRing>;
+
+    /// Concrete value, for downcasting
+    fn as_any(&self) -> &dyn Any;
+
+    fn clone_box(&self) -> Box<dyn DynElement>;
+
+    /// Human readable value
+    fn fmt_dyn(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result;
+
+    fn is_zero(&self) -> bool;
+
+    /// Equality, false for elements of different rings
+    fn eq_dyn(&self, other: &dyn DynElement) -> bool;
+
+    fn neg_dyn(&self) -> Result<Box<dyn DynElement>, DynRingError>;
+
+    fn add_dyn(&self, other: &dyn DynElement) -> Result<Box<dyn DynElement>, DynRingError>;
+
+    fn sub_dyn(&self, other: &dyn DynElement) -> Result<Box<dyn DynElement>, DynRingError>;
+
+    fn mul_dyn(&self, other: &dyn DynElement) -> Result<Box<dyn DynElement>, DynRingError>;
+
+    /// Multiplicative inverse, defined for the units of the ring
+    fn inverse_dyn(&self) -> Result<Box<dyn DynElement>, DynRingError>;
+}
+
+/// Ring chosen at runtime
+pub trait DynRing: Debug {
+    /// Short name such as "Q" or "F101"
+    fn name(&self) -> String;
+
+    fn zero(&self) -> Element;
+
+    fn one(&self) -> Element;
+
+    /// Image of an integer under the unique ring map from Z
+    fn integer(&self, n: i128) -> Element;
+
+    /// Whether every non-zero element is invertible
+    fn is_field(&self) -> bool;
+}
+
+impl Display for dyn DynRing {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl PartialEq for dyn DynRing {
+    fn eq(&self, other: &Self) -> bool {
+        self.name() == other.name()
+    }
+}
+
+/// Boxed element of a dynamic ring
+/// The arithmetic operators panic when the operands belong to different rings or on overflow,
+/// the `try_` methods return the error instead.
+#[derive(Debug)]
+pub struct Element(Box<dyn DynElement>);
+
+impl Element {
+    /// Box a concrete element
+    pub fn new(x: impl DynElement + 'static) -> Self {
+        Self(Box::new(x))
+    }
+
+    /// Underlying dynamic element
+    pub fn inner(&self) -> &dyn DynElement {
+        self.0.as_ref()
+    }
+
+    /// Concrete value if the element has type T
+    pub fn downcast_ref<T: 'static>(&self) -> Option<&T> {
+        self.0.as_any().downcast_ref()
+    }
+
+    /// Ring containing the element
+    pub fn ring(&self) -> Box<dyn DynRing> {
+        self.0.ring()
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+
+    pub fn try_neg(&self) -> Result<Self, DynRingError> {
+        self.0.neg_dyn().map(Self)
+    }
+
+    pub fn try_add(&self, other: &Self) -> Result<Self, DynRingError> {
+        self.0.add_dyn(other.inner()).map(Self)
+    }
+
+    pub fn try_sub(&self, other: &Self) -> Result<Self, DynRingError> {
+        self.0.sub_dyn(other.inner()).map(Self)
+    }
+
+    pub fn try_mul(&self, other: &Self) -> Result<Self, DynRingError> {
+        self.0.mul_dyn(other.inner()).map(Self)
+    }
+
+    /// Multiplicative inverse, an error for non-units
+    pub fn try_inverse(&self) -> Result<Self, DynRingError> {
+        self.0.inverse_dyn().map(Self)
+    }
+
+    /// Quotient a b^{-1}
+    pub fn try_div(&self, other: &Self) -> Result<Self, DynRingError> {
+        self.try_mul(&other.try_inverse()?)
+    }
+
+    /// Power by square and multiply, negative exponents through the inverse
+    pub fn try_pow(&self, exp: i64) -> Result<Self, DynRingError> {
+        let mut base = if exp < 0 {
+            self.try_inverse()?
+        } else {
+            self.clone()
+        };
+        let mut result = self.ring().one();
+        let mut e = exp.unsigned_abs();
+        while e > 0 {
+            if e & 1 == 1 {
+                result = result.try_mul(&base)?;
+            }
+            e >>= 1;
+            // Squaring past the last bit could overflow for a result that fits
+            if e > 0 {
+                base = base.try_mul(&base)?;
+            }
+        }
+        Ok(result)
+    }
+}
+
+impl Clone for Element {
+    fn clone(&self) -> Self {
+        Self(self.0.clone_box())
+    }
+}
+
+impl Display for Element {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt_dyn(f)
+    }
+}
+
+impl PartialEq for Element {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.eq_dyn(other.inner())
+    }
+}
+
+impl Neg for &Element {
+    type Output = Element;
+
+    fn neg(self) -> Element {
+        self.try_neg().unwrap_or_else(|err| panic!("{err}"))
+    }
+}
+
+macro_rules! impl_element_ops {
+    ($trait:ident, $method:ident, $try_method:ident) => {
+        impl $trait for &Element {
+            type Output = Element;
+
+            fn $method(self, other: Self) -> Element {
+                self.$try_method(other)
+                    .unwrap_or_else(|err| panic!("{err}"))
+            }
+        }
+
+        impl $trait for Element {
+            type Output = Element;
+
+            fn $method(self, other: Self) -> Element {
+                (&self).$method(&other)
+            }
+        }
+    };
+}
+
+impl_element_ops!(Add, add, try_add);
+impl_element_ops!(Sub, sub, try_sub);
+impl_element_ops!(Mul, mul, try_mul);
+
+/// Image of n in a ring by double and add
+/// Negative n accumulate -1 instead of negating at the end, so that i128::MIN stays in range.
+fn integer_image<T>(n: i128) -> T
+where
+    T: RingBase + Copy + Add<Output = T> + Neg<Output = T>,
+{
+    let unit = if n < 0 { -T::one() } else { T::one() };
+    let mut result = T::zero();
+    for bit in (0..128 - n.unsigned_abs().leading_zeros()).rev() {
+        result = result + result;
+        if (n.unsigned_abs() >> bit) & 1 == 1 {
+            result = result + unit;
+        }
+    }
+    result
+}
+
+/// Arithmetic reporting overflow of the representation
+/// Floating point values saturate to infinities instead, so their operations always succeed.
+trait CheckedArithmetic: Sized {
+    fn neg_checked(&self) -> Option<Self>;
+
+    fn add_checked(&self, other: &Self) -> Option<Self>;
+
+    fn sub_checked(&self, other: &Self) -> Option<Self>;
+
+    fn mul_checked(&self, other: &Self) -> Option<Self>;
+}
+
+impl CheckedArithmetic for i128 {
+    fn neg_checked(&self) -> Option<Self> {
+        self.checked_neg()
+    }
+
+    fn add_checked(&self, other: &Self) -> Option<Self> {
+        self.checked_add(*other)
+    }
+
+    fn sub_checked(&self, other: &Self) -> Option<Self> {
+        self.checked_sub(*other)
+    }
+
+    fn mul_checked(&self, other: &Self) -> Option<Self> {
+        self.checked_mul(*other)
+    }
+}
+
+impl CheckedArithmetic for Rational {
+    fn neg_checked(&self) -> Option<Self> {
+        self.checked_neg()
+    }
+
+    fn add_checked(&self, other: &Self) -> Option<Self> {
+        self.checked_add(other)
+    }
+
+    fn sub_checked(&self, other: &Self) -> Option<Self> {
+        self.checked_sub(other)
+    }
+
+    fn mul_checked(&self, other: &Self) -> Option<Self> {
+        self.checked_mul(other)
+    }
+}
+
+macro_rules! impl_unchecked_arithmetic {
+    ($($t:ty),*) => {
+        $(
+            impl CheckedArithmetic for $t {
+                fn neg_checked(&self) -> Option<Self> {
+                    Some(-*self)
+                }
+
+                fn add_checked(&self, other: &Self) -> Option<Self> {
+                    Some(*self + *other)
+                }
+
+                fn sub_checked(&self, other: &Self) -> Option<Self> {
+                    Some(*self - *other)
+                }
+
+                fn mul_checked(&self, other: &Self) -> Option<Self> {
+                    Some(*self * *other)
+                }
+            }
+        )*
+    };
+}
+
+impl_unchecked_arithmetic!(f64, Complex);
+
+/// Implements DynElement for a Copy type with checked arithmetic, given its ring and inverse
+macro_rules! impl_dyn_element {
+    ($t:ty, $ring:expr, |$x:ident| $inverse:expr, |$y:ident, $f:ident| $fmt:expr) => {
+        impl DynElement for $t {
+            fn ring(&self) -> Box<dyn DynRing> {
+                Box::new($ring)
+            }
+
+            fn as_any(&self) -> &dyn Any {
+                self
+            }
+
+            fn clone_box(&self) -> Box<dyn DynElement> {
+                Box::new(*self)
+            }
+
+            fn fmt_dyn(&self, $f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                let $y = self;
+                $fmt
+            }
+
+            fn is_zero(&self) -> bool {
+                *self == <$t as RingBase>::zero()
+            }
+
+            fn eq_dyn(&self, other: &dyn DynElement) -> bool {
+                other.as_any().downcast_ref::<Self>() == Some(self)
+            }
+
+            fn neg_dyn(&self) -> Result<Box<dyn DynElement>, DynRingError> {
+                let value = self.neg_checked().ok_or(DynRingError::Overflow)?;
+                Ok(Box::new(value))
+            }
+
+            fn add_dyn(&self, other: &dyn DynElement) -> Result<Box<dyn DynElement>, DynRingError> {
+                let other = other
+                    .as_any()
+                    .downcast_ref::<Self>()
+                    .ok_or(DynRingError::RingMismatch)?;
+                let value = self.add_checked(other).ok_or(DynRingError::Overflow)?;
+                Ok(Box::new(value))
+            }
+
+            fn sub_dyn(&self, other: &dyn DynElement) -> Result<Box<dyn DynElement>, DynRingError> {
+                let other = other
+                    .as_any()
+                    .downcast_ref::<Self>()
+                    .ok_or(DynRingError::RingMismatch)?;
+                let value = self.sub_checked(other).ok_or(DynRingError::Overflow)?;
+                Ok(Box::new(value))
+            }
+
+            fn mul_dyn(&self, other: &dyn DynElement) -> Result<Box<dyn DynElement>, DynRingError> {
+                let other = other
+                    .as_any()
+                    .downcast_ref::<Self>()
+                    .ok_or(DynRingError::RingMismatch)?;
+                let value = self.mul_checked(other).ok_or(DynRingError::Overflow)?;
+                Ok(Box::new(value))
+            }
+
+            fn inverse_dyn(&self) -> Result<Box<dyn DynElement>, DynRingError> {
+                let $x = self;
+                $inverse.map(|x| Box::new(x) as Box<dyn DynElement>)
+            }
+        }
+    };
+}
+
+/// Field inverse, an error for zero or on overflow
+fn field_inverse<T: FieldBase>(x: &T) -> Result<T, DynRingError> {
+    match *x == T::zero() {
+        true => Err(AlgebraError::DivisionByZero.into()),
+        false => x.checked_inverse().ok_or(DynRingError::Overflow),
+    }
+}
+
+impl_dyn_element!(
+    i128,
+    Integers,
+    |x| match *x {
+        1 | -1 => Ok(*x),
+        0 => Err(AlgebraError::DivisionByZero.into()),
+        _ => Err(AlgebraError::NotInvertible.into()),
+    },
+    |x, f| write!(f, "{x}")
+);
+
+impl_dyn_element!(Rational, Rationals, |x| field_inverse(x), |x, f| write!(
+    f,
+    "{x}"
+));
+
+impl_dyn_element!(f64, Reals, |x| field_inverse(x), |x, f| write!(f, "{x}"));
+
+impl_dyn_element!(Complex, Complexes, |x| field_inverse(x), |x, f| write!(
+    f,
+    "{x}"
+));
+
+/// Ring of integers Z, as i128
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Integers;
+
+/// Field of rationals Q
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rationals;
+
+/// Real numbers R, as f64
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Reals;
+
+/// Complex numbers C, as pairs of f64
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Complexes;
+
+macro_rules! impl_dyn_ring {
+    ($ring:ty, $t:ty, $name:expr, $is_field:expr) => {
+        impl DynRing for $ring {
+            fn name(&self) -> String {
+                $name.to_string()
+            }
+
+            fn zero(&self) -> Element {
+                Element::new(<$t as RingBase>::zero())
+            }
+
+            fn one(&self) -> Element {
+                Element::new(<$t as RingBase>::one())
+            }
+
+            fn integer(&self, n: i128) -> Element {
+                Element::new(integer_image::<$t>(n))
+            }
+
+            fn is_field(&self) -> bool {
+                $is_field
+            }
+        }
+    };
+}
+
+impl_dyn_ring!(Integers, i128, "Z", false);
+impl_dyn_ring!(Rationals, Rational, "Q", true);
+impl_dyn_ring!(Reals, f64, "R", true);
+impl_dyn_ring!(Complexes, Complex, "C", true);
+
+/// Integers modulo n, Z/nZ, with the modulus chosen at runtime
+/// Named F_p when n is prime.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IntegersMod {
+    modulus: u64,
+}
+
+impl IntegersMod {
+    /// Panics if the modulus is zero, see `TryFrom<u64>`.
+    pub fn new(modulus: u64) -> Self {
+        assert!(modulus > 0, "Modulus must be positive");
+        Self { modulus }
+    }
+
+    pub fn modulus(&self) -> u64 {
+        self.modulus
+    }
+
+    /// Residue class of n
+    pub fn element(&self, n: i128) -> Zn {
+        Zn::from_integer(n, self.modulus)
+    }
+}
+
+impl TryFrom<u64> for IntegersMod {
+    type Error = AlgebraError;
+
+    fn try_from(modulus: u64) -> Result<Self, AlgebraError> {
+        match modulus {
+            0 => Err(AlgebraError::ZeroModulus),
+            n => Ok(Self::new(n)),
+        }
+    }
+}
+
+impl DynRing for IntegersMod {
+    fn name(&self) -> String {
+        match is_prime(self.modulus) {
+            true => format!("F{}", self.modulus),
+            false => format!("Z/{}", self.modulus),
+        }
+    }
+
+    fn zero(&self) -> Element {
+        Element::new(self.element(0))
+    }
+
+    fn one(&self) -> Element {
+        Element::new(self.element(1))
+    }
+
+    fn integer(&self, n: i128) -> Element {
+        Element::new(self.element(n))
+    }
+
+    fn is_field(&self) -> bool {
+        is_prime(self.modulus)
+    }
+}
+
+/// Other operand as a residue of the same modulus
+fn same_modulus<'a>(a: &Zn, other: &'a dyn DynElement) -> Result<&'a Zn, DynRingError> {
+    other
+        .as_any()
+        .downcast_ref::<Zn>()
+        .filter(|b| b.modulus() == a.modulus())
+        .ok_or(DynRingError::RingMismatch)
+}
+
+/// Residues are the elements of `IntegersMod`
+/// Panics for the constants from `RingBase`, which belong to no `IntegersMod`.
+impl DynElement for Zn {
+    fn ring(&self) -> Box<dyn DynRing> {
+        Box::new(IntegersMod::new(
+            self.modulus()
+                .expect("Constants without modulus have no ring"),
+        ))
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn clone_box(&self) -> Box<dyn DynElement> {
+        Box::new(*self)
+    }
+
+    fn fmt_dyn(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.value())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.value() == 0
+    }
+
+    fn eq_dyn(&self, other: &dyn DynElement) -> bool {
+        same_modulus(self, other).is_ok_and(|other| other == self)
+    }
+
+    fn neg_dyn(&self) -> Result<Box<dyn DynElement>, DynRingError> {
+        Ok(Box::new(-*self))
+    }
+
+    fn add_dyn(&self, other: &dyn DynElement) -> Result<Box<dyn DynElement>, DynRingError> {
+        Ok(Box::new(*self + *same_modulus(self, other)?))
+    }
+
+    fn sub_dyn(&self, other: &dyn DynElement) -> Result<Box<dyn DynElement>, DynRingError> {
+        Ok(Box::new(*self - *same_modulus(self, other)?))
+    }
+
+    fn mul_dyn(&self, other: &dyn DynElement) -> Result<Box<dyn DynElement>, DynRingError> {
+        Ok(Box::new(*self * *same_modulus(self, other)?))
+    }
+
+    fn inverse_dyn(&self) -> Result<Box<dyn DynElement>, DynRingError> {
+        match self.checked_inverse() {
+            Some(inverse) => Ok(Box::new(inverse)),
+            None if self.value() == 0 && self.modulus() > Some(1) => {
+                Err(AlgebraError::DivisionByZero.into())
+            }
+            None => Err(AlgebraError::NotInvertible.into()),
+        }
+    }
+}
@@ -0,0 +1,205 @@
+//! Endomorphism Ring
+//! https://ncatlab.org/nlab/show/endomorphism+ring
+//! The linear maps f: M -> M of a module M form a ring End(M) under pointwise addition (f + g)(v) = f(v) + g(v)
+//! and composition (f g)(v) = f(g(v)). For a free module M = R^N, maps are N x N matrices and composition is
+//! matrix multiplication. For N > 1 this ring is not commutative and has zero divisors.
+
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::group::action::{Action, Linear};
+use crate::matrix::Matrix;
+use crate::ring::RingBase;
+
+/// Free module R^N with its standard basis
+/// The rank is part of the type, so that the zero and identity maps of `RingBase` know their size.
+pub trait FreeModule: Clone {
+    /// Ring of scalars R
+    type Scalar: RingBase;
+
+    /// Rank N
+    const RANK: usize;
+
+    /// Coordinates in the standard basis
+    fn coords(&self) -> &[Self::Scalar];
+
+    /// Element with the given coordinates
+    /// Panics unless there are N of them.
+    fn from_coords(coords: Vec<Self::Scalar>) -> Self;
+}
+
+/// R^N as arrays of coordinates
+impl<T: RingBase, const N: usize> FreeModule for [T; N] {
+    type Scalar = T;
+
+    const RANK: usize = N;
+
+    fn coords(&self) -> &[T] {
+        self
+    }
+
+    fn from_coords(coords: Vec<T>) -> Self {
+        coords
+            .try_into()
+            .unwrap_or_else(|_| panic!("Expected {N} coordinates"))
+    }
+}
+
+/// Endomorphism of a free module M
+/// Stored as the matrix whose column j is the image of the basis vector e_j.
+#[derive(Debug, Clone)]
+pub struct Endo<M: FreeModule> {
+    matrix: Matrix<M::Scalar>,
+}
+
+impl<M: FreeModule> PartialEq for Endo<M> {
+    fn eq(&self, other: &Self) -> bool {
+        self.matrix == other.matrix
+    }
+}
+
+impl<M: FreeModule> Endo<M> {
+    /// Endomorphism with the given matrix
+    /// Panics unless the matrix is N x N.
+    pub fn from_matrix(matrix: Matrix<M::Scalar>) -> Self {
+        assert_eq!(
+            (matrix.rows(), matrix.cols()),
+            (M::RANK, M::RANK),
+            "Endomorphisms of a module of rank {} are {0} x {0} matrices",
+            M::RANK
+        );
+        Self { matrix }
+    }
+
+    /// Matrix of the endomorphism
+    pub fn matrix(&self) -> &Matrix<M::Scalar> {
+        &self.matrix
+    }
+
+    /// Scalar map v -> c v
+    pub fn scalar(c: M::Scalar) -> Self {
+        Self {
+            matrix: Matrix::from_fn(M::RANK, M::RANK, |i, j| match i == j {
+                true => c.clone(),
+                false => M::Scalar::zero(),
+            }),
+        }
+    }
+}
+
+impl<M: FreeModule> Endo<M>
+where
+    M::Scalar: Add<Output = M::Scalar> + Mul<Output = M::Scalar>,
+{
+    /// Image f(v) of a vector
+    pub fn apply(&self, v: &M) -> M {
+        M::from_coords(Linear::act(&self.matrix, &v.coords().to_vec()))
+    }
+}
+
+impl<M: FreeModule> RingBase for Endo<M> {
+    /// Zero map
+    fn zero() -> Self {
+        Self::scalar(M::Scalar::zero())
+    }
+
+    /// Identity map
+    fn one() -> Self {
+        Self::scalar(M::Scalar::one())
+    }
+}
+
+/// Pointwise addition (f + g)(v) = f(v) + g(v)
+impl<M: FreeModule> Add for Endo<M>
+where
+    for<'a> &'a M::Scalar: Add<&'a M::Scalar, Output = M::Scalar>,
+{
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            matrix: self.matrix + other.matrix,
+        }
+    }
+}
+
+/// Pointwise subtraction (f - g)(v) = f(v) - g(v)
+impl<M: FreeModule> Sub for Endo<M>
+where
+    for<'a> &'a M::Scalar: Sub<&'a M::Scalar, Output = M::Scalar>,
+{
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self {
+            matrix: self.matrix - other.matrix,
+        }
+    }
+}
+
+/// Pointwise negation (-f)(v) = -f(v)
+impl<M: FreeModule> Neg for Endo<M>
+where
+    for<'a> &'a M::Scalar: Sub<&'a M::Scalar, Output = M::Scalar>,
+{
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            matrix: -&self.matrix,
+        }
+    }
+}
+
+/// Composition (f g)(v) = f(g(v))
+impl<M: FreeModule> Mul for Endo<M>
+where
+    for<'a> &'a M::Scalar: Add<&'a M::Scalar, Output = M::Scalar>,
+    for<'a> &'a M::Scalar: Mul<&'a M::Scalar, Output = M::Scalar>,
+{
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        Self {
+            matrix: &self.matrix * &other.matrix,
+        }
+    }
+}
+
+impl<M: FreeModule> AddAssign for Endo<M>
+where
+    for<'a> &'a M::Scalar: Add<&'a M::Scalar, Output = M::Scalar>,
+{
+    fn add_assign(&mut self, other: Self) {
+        self.matrix = &self.matrix + &other.matrix;
+    }
+}
+
+impl<M: FreeModule> SubAssign for Endo<M>
+where
+    for<'a> &'a M::Scalar: Sub<&'a M::Scalar, Output = M::Scalar>,
+{
+    fn sub_assign(&mut self, other: Self) {
+        self.matrix = &self.matrix - &other.matrix;
+    }
+}
+
+impl<M: FreeModule> MulAssign for Endo<M>
+where
+    for<'a> &'a M::Scalar: Add<&'a M::Scalar, Output = M::Scalar>,
+    for<'a> &'a M::Scalar: Mul<&'a M::Scalar, Output = M::Scalar>,
+{
+    fn mul_assign(&mut self, other: Self) {
+        self.matrix = &self.matrix * &other.matrix;
+    }
+}
+
+/// The endomorphism ring acts linearly on its module
+// Bounds on owned arithmetic, as for the matrix action, so that selecting the impl does not recurse
+impl<M: FreeModule> Action<Endo<M>, M> for Linear
+where
+    M::Scalar: Add<Output = M::Scalar> + Mul<Output = M::Scalar>,
+{
+    fn act(g: &Endo<M>, x: &M) -> M {
+        g.apply(x)
+    }
+}
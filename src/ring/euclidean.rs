@@ -0,0 +1,105 @@
+//! Euclidean Domains
+//! https://ncatlab.org/nlab/show/Euclidean+domain
+//! An integral domain R with a size function such that for every a and every b != 0
+//! there are q, r in R with a = q b + r and either r = 0 or size(r) < size(b).
+//! Examples are the integers (size |a|) and polynomials over a field (size deg a).
+//! Every field is one too, with exact division and size 0, which gives matrices over fields the same
+//! determinant and rank. The primitive integers implement `FieldBase` with truncating division, so fields are
+//! listed one by one rather than covered by a blanket impl. Zn is left out, it is not a domain for composite n.
+
+use std::ops::{Mul, Sub};
+
+use crate::{
+    field::{
+        finite_field::{
+            binary::Gf2e,
+            extension::{ExtensionModulus, FpExt},
+            montgomery::FpMont,
+            Fp,
+        },
+        FieldBase,
+    },
+    number::Rational,
+    polynomial::Polynomial,
+    ring::RingBase,
+};
+
+/// Euclidean domain
+pub trait EuclideanDomain: RingBase {
+    /// Division with remainder: (q, r) with self = q other + r
+    /// Panics if other is zero.
+    fn div_rem(&self, other: &Self) -> (Self, Self);
+
+    /// Euclidean size of a non-zero element
+    fn euclidean_size(&self) -> usize;
+
+    /// Canonical associate: the unique element u self, for a unit u, chosen as representative
+    /// (e.g. the absolute value of an integer, the monic associate of a polynomial)
+    fn normalize(&self) -> Self;
+}
+
+macro_rules! impl_euclidean_for_signed {
+    ($($t:ty),*) => {
+        $(
+            impl EuclideanDomain for $t {
+                fn div_rem(&self, other: &Self) -> (Self, Self) {
+                    (self.div_euclid(*other), self.rem_euclid(*other))
+                }
+
+                fn euclidean_size(&self) -> usize {
+                    self.unsigned_abs() as usize
+                }
+
+                fn normalize(&self) -> Self {
+                    self.abs()
+                }
+            }
+        )*
+    };
+}
+
+impl_euclidean_for_signed!(i8, i16, i32, i64, i128, isize);
+
+macro_rules! impl_euclidean_for_field {
+    ([$($generics:tt)*] $t:ty) => {
+        impl<$($generics)*> EuclideanDomain for $t {
+            fn div_rem(&self, other: &Self) -> (Self, Self) {
+                (*self / *other, Self::zero())
+            }
+
+            fn euclidean_size(&self) -> usize {
+                0
+            }
+
+            fn normalize(&self) -> Self {
+                match *self == Self::zero() {
+                    true => Self::zero(),
+                    false => Self::one(),
+                }
+            }
+        }
+    };
+}
+
+impl_euclidean_for_field!([const P: u64] Fp<P>);
+impl_euclidean_for_field!([const P: u64] FpMont<P>);
+impl_euclidean_for_field!([const N: u32] Gf2e<N>);
+impl_euclidean_for_field!([const P: u64, const N: usize, M: ExtensionModulus<P, N>] FpExt<P, N, M>);
+impl_euclidean_for_field!([] Rational);
+
+impl<T> EuclideanDomain for Polynomial<T>
+where
+    T: FieldBase + Copy + Mul<Output = T> + Sub<Output = T>,
+{
+    fn div_rem(&self, other: &Self) -> (Self, Self) {
+        Polynomial::div_rem(self, other)
+    }
+
+    fn euclidean_size(&self) -> usize {
+        self.degree().unwrap_or(0)
+    }
+
+    fn normalize(&self) -> Self {
+        self.monic()
+    }
+}
@@ -7,6 +7,12 @@
 
 use std::ops::{Add, Mul};
 
+pub mod dynamic;
+
+pub mod endomorphism;
+
+pub mod euclidean;
+
 /// Base trait for Ring Algebra
 /// Used to avoid rewriting HRTBs on every impl
 pub trait RingBase: Sized + Clone + PartialEq {
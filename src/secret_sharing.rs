@@ -0,0 +1,298 @@
+//! Secret Sharing
+//! https://en.wikipedia.org/wiki/Shamir%27s_secret_sharing
+//! Shamir's scheme hides a secret s in the constant term of a random polynomial f of degree t - 1 over a field
+//! and hands out the points (x, f(x)). Any t shares determine f by interpolation, fewer reveal nothing about s.
+//! Feldman's verifiable scheme also publishes commitments g^{a_k} to the coefficients, in a group of prime order q
+//! where discrete logarithms are hard, so each shareholder can check their share without learning the secret.
+
+use std::{fmt::Display, ops::Sub};
+
+use rand::{
+    distr::{Distribution, StandardUniform},
+    Rng,
+};
+
+use crate::{
+    field::{batch_inverse, finite_field::Fp, Field},
+    number::is_prime,
+    ring::RingBase,
+};
+
+/// Consecutive rejected draws after which a field is taken to have too few non-zero elements for the shares
+const ABSCISSA_DRAWS: usize = 1000;
+
+/// Share
+/// The label is an opaque identifier of the party holding the share, `()` when unused.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Share<T, L = ()> {
+    pub x: T,
+    pub y: T,
+    pub label: L,
+}
+
+impl<T: Display, L> Display for Share<T, L> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({x}, {y})", x = self.x, y = self.y)
+    }
+}
+
+impl<T, L> Share<T, L> {
+    pub fn into_pair(self) -> (T, T) {
+        (self.x, self.y)
+    }
+}
+
+/// Invalid sharing parameters
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShareError {
+    /// A threshold of zero shares
+    ZeroThreshold,
+    /// Fewer abscissas than the share threshold
+    TooFewShares,
+    /// The share at x = 0 would be the secret itself
+    ZeroAbscissa,
+    /// Two parties were given the same abscissa
+    RepeatedAbscissa,
+    /// The field has fewer non-zero elements than there are shares
+    TooManyShares,
+    /// The commitment generator does not have prime order Q
+    InvalidGenerator,
+}
+
+impl Display for ShareError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ShareError::ZeroThreshold => write!(f, "Share threshold must be positive"),
+            ShareError::TooFewShares => {
+                write!(f, "Share threshold must be less than the number of shares")
+            }
+            ShareError::ZeroAbscissa => write!(f, "Share abscissas must be non-zero"),
+            ShareError::RepeatedAbscissa => write!(f, "Share abscissas must be distinct"),
+            ShareError::TooManyShares => {
+                write!(f, "Field has too few non-zero elements for the shares")
+            }
+            ShareError::InvalidGenerator => {
+                write!(f, "Generator must have prime order Q modulo P")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ShareError {}
+
+/// Fails unless the abscissas are non-zero and distinct
+fn check_abscissas<'a, T: RingBase + 'a>(
+    abscissas: impl Iterator<Item = &'a T> + Clone,
+) -> Result<(), ShareError> {
+    for (i, x) in abscissas.clone().enumerate() {
+        if *x == T::zero() {
+            return Err(ShareError::ZeroAbscissa);
+        }
+        if abscissas.clone().take(i).any(|y| y == x) {
+            return Err(ShareError::RepeatedAbscissa);
+        }
+    }
+    Ok(())
+}
+
+/// Distinct random non-zero abscissas
+/// Drawing them works in any characteristic, where counting 1, 1 + 1, ... would wrap around to zero.
+fn random_abscissas<T, R: Rng + ?Sized>(
+    number_of_shares: usize,
+    rng: &mut R,
+) -> Result<Vec<T>, ShareError>
+where
+    T: RingBase,
+    StandardUniform: Distribution<T>,
+{
+    let mut abscissas = Vec::with_capacity(number_of_shares);
+    let mut rejected = 0;
+    while abscissas.len() < number_of_shares {
+        let x = rng.random();
+        if x == T::zero() || abscissas.contains(&x) {
+            rejected += 1;
+            if rejected == ABSCISSA_DRAWS {
+                return Err(ShareError::TooManyShares);
+            }
+            continue;
+        }
+        rejected = 0;
+        abscissas.push(x);
+    }
+    Ok(abscissas)
+}
+
+/// Coefficients of a random polynomial of degree threshold - 1 with the secret as constant term
+fn random_coefficients<T, R: Rng + ?Sized>(secret: T, share_threshold: usize, rng: &mut R) -> Vec<T>
+where
+    T: RingBase,
+    StandardUniform: Distribution<T>,
+{
+    let mut coeffs = vec![secret];
+    coeffs.extend((1..share_threshold).map(|_| rng.random()));
+
+    // A zero leading coefficient would lower the threshold
+    if share_threshold > 1 {
+        while coeffs[share_threshold - 1] == T::zero() {
+            coeffs[share_threshold - 1] = rng.random();
+        }
+    }
+    coeffs
+}
+
+/// Shares of the polynomial with the given coefficients at the parties' abscissas
+fn evaluate_shares<T, L>(coeffs: &[T], points: Vec<(T, L)>) -> Vec<Share<T, L>>
+where
+    T: Field + Copy,
+{
+    points
+        .into_iter()
+        .map(|(x, label)| Share {
+            x,
+            y: coeffs.iter().rev().fold(T::zero(), |acc, c| acc * x + *c),
+            label,
+        })
+        .collect()
+}
+
+/// Validated parties and random polynomial of a sharing
+#[allow(clippy::type_complexity)]
+fn prepare<T, L, R: Rng + ?Sized>(
+    secret: T,
+    share_threshold: usize,
+    points: impl IntoIterator<Item = (T, L)>,
+    rng: &mut R,
+) -> Result<(Vec<T>, Vec<(T, L)>), ShareError>
+where
+    T: RingBase,
+    StandardUniform: Distribution<T>,
+{
+    let points = points.into_iter().collect::<Vec<_>>();
+    if share_threshold == 0 {
+        return Err(ShareError::ZeroThreshold);
+    }
+    if share_threshold > points.len() {
+        return Err(ShareError::TooFewShares);
+    }
+    check_abscissas(points.iter().map(|(x, _)| x))?;
+    Ok((random_coefficients(secret, share_threshold, rng), points))
+}
+
+/// Split a secret into shares at n distinct random non-zero abscissas
+pub fn split_secret<T, R: Rng + ?Sized>(
+    secret: T,
+    share_threshold: usize,
+    number_of_shares: usize,
+    rng: &mut R,
+) -> Result<Vec<Share<T>>, ShareError>
+where
+    T: Field + Copy,
+    StandardUniform: Distribution<T>,
+{
+    let points = random_abscissas(number_of_shares, rng)?
+        .into_iter()
+        .map(|x| (x, ()));
+    split_secret_at(secret, share_threshold, points, rng)
+}
+
+/// Split a secret into shares at caller supplied abscissas, each share carrying the label of its party
+/// Keeping the same abscissa for a party across resharings keeps the party-to-point mapping stable.
+pub fn split_secret_at<T, L, R: Rng + ?Sized>(
+    secret: T,
+    share_threshold: usize,
+    points: impl IntoIterator<Item = (T, L)>,
+    rng: &mut R,
+) -> Result<Vec<Share<T, L>>, ShareError>
+where
+    T: Field + Copy,
+    StandardUniform: Distribution<T>,
+{
+    let (coeffs, points) = prepare(secret, share_threshold, points, rng)?;
+    Ok(evaluate_shares(&coeffs, points))
+}
+
+/// Reconstruct a secret from shares
+/// Lagrange interpolation at zero:
+/// $$ s = \sum_i y_i \prod_{j \neq i} \frac{x_j}{x_j - x_i} = \Big( \prod_j x_j \Big) \sum_i \frac{y_i}{x_i \prod_{j \neq i} (x_j - x_i)} $$
+/// with all the denominators inverted at once by `batch_inverse`.
+/// Fewer shares than the threshold give an unrelated value, which cannot be detected.
+pub fn reconstruct_secret<T, L>(shares: &[Share<T, L>]) -> Result<T, ShareError>
+where
+    T: Field + Copy + Sub<Output = T>,
+{
+    if shares.is_empty() {
+        return Err(ShareError::TooFewShares);
+    }
+    check_abscissas(shares.iter().map(|share| &share.x))?;
+    let denominators = shares
+        .iter()
+        .enumerate()
+        .map(|(i, share_i)| {
+            shares
+                .iter()
+                .enumerate()
+                .filter(|(j, _)| *j != i)
+                .fold(share_i.x, |acc, (_, share_j)| acc * (share_j.x - share_i.x))
+        })
+        .collect::<Vec<_>>();
+    let product = shares.iter().fold(T::one(), |acc, share| acc * share.x);
+    let sum = shares
+        .iter()
+        .zip(batch_inverse(&denominators))
+        .fold(T::zero(), |acc, (share, inverse)| acc + share.y * inverse);
+    Ok(product * sum)
+}
+
+/// Feldman's verifiable secret sharing
+/// https://en.wikipedia.org/wiki/Verifiable_secret_sharing#Feldman's_scheme
+/// Secrets and shares live in Fq, commitments in the subgroup of order q of Fp^* generated by g,
+/// so Q must be a prime dividing P - 1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Feldman<const P: u64, const Q: u64> {
+    generator: Fp<P>,
+}
+
+impl<const P: u64, const Q: u64> Feldman<P, Q> {
+    /// Scheme with commitments g^{a_k}
+    /// Fails unless Q is prime and g has order Q, i.e. g != 1 and g^Q = 1.
+    pub fn new(generator: Fp<P>) -> Result<Self, ShareError> {
+        match is_prime(Q) && generator != Fp::one() && generator.pow(Q) == Fp::one() {
+            true => Ok(Self { generator }),
+            false => Err(ShareError::InvalidGenerator),
+        }
+    }
+
+    pub fn generator(&self) -> Fp<P> {
+        self.generator
+    }
+
+    /// Shares at the parties' abscissas and the commitments g^{a_0}, ..., g^{a_{t-1}} to the coefficients
+    /// The first commitment g^s is public, so the secret must have enough entropy on its own.
+    #[allow(clippy::type_complexity)]
+    pub fn split_secret_at<L, R: Rng + ?Sized>(
+        &self,
+        secret: Fp<Q>,
+        share_threshold: usize,
+        points: impl IntoIterator<Item = (Fp<Q>, L)>,
+        rng: &mut R,
+    ) -> Result<(Vec<Share<Fp<Q>, L>>, Vec<Fp<P>>), ShareError> {
+        let (coeffs, points) = prepare(secret, share_threshold, points, rng)?;
+        let commitments = coeffs
+            .iter()
+            .map(|a| self.generator.pow(a.value()))
+            .collect();
+        Ok((evaluate_shares(&coeffs, points), commitments))
+    }
+
+    /// Check a share against the commitments
+    /// $$ g^y = \prod_k C_k^{x^k} $$
+    /// The right-hand side is evaluated with Horner's rule in the exponent.
+    pub fn verify<L>(&self, share: &Share<Fp<Q>, L>, commitments: &[Fp<P>]) -> bool {
+        let x = share.x.value();
+        let expected = commitments
+            .iter()
+            .rev()
+            .fold(Fp::one(), |acc, c| acc.pow(x) * *c);
+        self.generator.pow(share.y.value()) == expected
+    }
+}
@@ -0,0 +1,233 @@
+//! Axiom Verification
+//! https://en.wikipedia.org/wiki/Field_(mathematics)#Classic_definition
+//! Exhaustive checks of the ring and field axioms over every element of a small finite structure,
+//! e.g. every residue of Fp<P> or a hand-written `RingBase` implementation.
+//! Each check stops at the first tuple of elements that breaks an axiom and reports it.
+//! Triple axioms take O(n^3) operations, so this is meant for structures with at most a few hundred elements.
+
+use std::fmt::{Debug, Display};
+use std::ops::{Add, Mul, Sub};
+
+use crate::{field::FieldBase, ring::RingBase};
+
+/// Property checked by the verifier
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Axiom {
+    /// a + b and a * b are among the elements
+    Closure,
+    /// (a + b) + c = a + (b + c)
+    AdditiveAssociativity,
+    /// a + b = b + a
+    AdditiveCommutativity,
+    /// a + 0 = a
+    AdditiveIdentity,
+    /// (a - b) + b = a
+    Subtraction,
+    /// (a b) c = a (b c)
+    MultiplicativeAssociativity,
+    /// a b = b a
+    MultiplicativeCommutativity,
+    /// a 1 = 1 a = a
+    MultiplicativeIdentity,
+    /// a (b + c) = a b + a c and (a + b) c = a c + b c
+    Distributivity,
+    /// 0 != 1
+    NonTrivial,
+    /// a a^{-1} = 1 for a != 0
+    Inverse,
+    /// a^k equals the product of k copies of a
+    Power,
+    /// a^{-1} = a^{q-2} in a field of order q
+    InversePower,
+}
+
+impl Display for Axiom {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Axiom::Closure => "closure",
+            Axiom::AdditiveAssociativity => "additive associativity",
+            Axiom::AdditiveCommutativity => "additive commutativity",
+            Axiom::AdditiveIdentity => "additive identity",
+            Axiom::Subtraction => "subtraction",
+            Axiom::MultiplicativeAssociativity => "multiplicative associativity",
+            Axiom::MultiplicativeCommutativity => "multiplicative commutativity",
+            Axiom::MultiplicativeIdentity => "multiplicative identity",
+            Axiom::Distributivity => "distributivity",
+            Axiom::NonTrivial => "0 != 1",
+            Axiom::Inverse => "inverse",
+            Axiom::Power => "power",
+            Axiom::InversePower => "inverse as power",
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// First failure found: the axiom and the elements it fails for
+#[derive(Debug, Clone, PartialEq)]
+pub struct Counterexample<T> {
+    pub axiom: Axiom,
+    pub elements: Vec<T>,
+}
+
+impl<T: Debug> Display for Counterexample<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} fails for {:?}", self.axiom, self.elements)
+    }
+}
+
+impl<T: Debug> std::error::Error for Counterexample<T> {}
+
+/// Fails with the given elements unless the condition holds
+fn check<T: Clone>(holds: bool, axiom: Axiom, elements: &[&T]) -> Result<(), Counterexample<T>> {
+    match holds {
+        true => Ok(()),
+        false => Err(Counterexample {
+            axiom,
+            elements: elements.iter().map(|x| (*x).clone()).collect(),
+        }),
+    }
+}
+
+/// Ring axioms, without commutativity of multiplication
+pub fn verify_ring<T>(elements: &[T]) -> Result<(), Counterexample<T>>
+where
+    T: RingBase + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+{
+    let (zero, one) = (T::zero(), T::one());
+    check(elements.contains(&zero), Axiom::Closure, &[&zero])?;
+    check(elements.contains(&one), Axiom::Closure, &[&one])?;
+
+    for a in elements {
+        check(
+            a.clone() + zero.clone() == *a,
+            Axiom::AdditiveIdentity,
+            &[a],
+        )?;
+        check(
+            a.clone() * one.clone() == *a && one.clone() * a.clone() == *a,
+            Axiom::MultiplicativeIdentity,
+            &[a],
+        )?;
+        for b in elements {
+            let (sum, product) = (a.clone() + b.clone(), a.clone() * b.clone());
+            check(
+                elements.contains(&sum) && elements.contains(&product),
+                Axiom::Closure,
+                &[a, b],
+            )?;
+            check(
+                sum == b.clone() + a.clone(),
+                Axiom::AdditiveCommutativity,
+                &[a, b],
+            )?;
+            check(
+                (a.clone() - b.clone()) + b.clone() == *a,
+                Axiom::Subtraction,
+                &[a, b],
+            )?;
+        }
+    }
+
+    // Triples only once the pairwise checks hold, so results are known to be elements
+    for a in elements {
+        for b in elements {
+            let (sum, product) = (a.clone() + b.clone(), a.clone() * b.clone());
+            for c in elements {
+                check(
+                    sum.clone() + c.clone() == a.clone() + (b.clone() + c.clone()),
+                    Axiom::AdditiveAssociativity,
+                    &[a, b, c],
+                )?;
+                check(
+                    product.clone() * c.clone() == a.clone() * (b.clone() * c.clone()),
+                    Axiom::MultiplicativeAssociativity,
+                    &[a, b, c],
+                )?;
+                check(
+                    a.clone() * (b.clone() + c.clone()) == product.clone() + a.clone() * c.clone()
+                        && sum.clone() * c.clone() == a.clone() * c.clone() + b.clone() * c.clone(),
+                    Axiom::Distributivity,
+                    &[a, b, c],
+                )?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Ring axioms and a b = b a
+pub fn verify_commutative_ring<T>(elements: &[T]) -> Result<(), Counterexample<T>>
+where
+    T: RingBase + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+{
+    verify_ring(elements)?;
+    for a in elements {
+        for b in elements {
+            check(
+                a.clone() * b.clone() == b.clone() * a.clone(),
+                Axiom::MultiplicativeCommutativity,
+                &[a, b],
+            )?;
+        }
+    }
+    Ok(())
+}
+
+/// Commutative ring axioms, 0 != 1, and a a^{-1} = 1 for every a != 0
+pub fn verify_field<T>(elements: &[T]) -> Result<(), Counterexample<T>>
+where
+    T: FieldBase + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+{
+    let (zero, one) = (T::zero(), T::one());
+    check(zero != one, Axiom::NonTrivial, &[&zero, &one])?;
+    verify_commutative_ring(elements)?;
+    for a in elements.iter().filter(|a| **a != zero) {
+        // Types such as Zn implement FieldBase for every modulus, and panic in inverse() for non-units
+        let inverse = a
+            .checked_inverse()
+            .filter(|inverse| elements.contains(inverse) && a.clone() * inverse.clone() == one);
+        check(inverse.is_some(), Axiom::Inverse, &[a])?;
+    }
+    Ok(())
+}
+
+/// A power map agrees with repeated multiplication for every exponent up to max_exp
+pub fn verify_pow<T>(
+    elements: &[T],
+    pow: impl Fn(&T, u64) -> T,
+    max_exp: u64,
+) -> Result<(), Counterexample<T>>
+where
+    T: RingBase + Mul<Output = T>,
+{
+    for a in elements {
+        let mut power = T::one();
+        for k in 0..=max_exp {
+            check(pow(a, k) == power, Axiom::Power, &[a])?;
+            power = power * a.clone();
+        }
+    }
+    Ok(())
+}
+
+/// Field axioms, the power map up to the field order q, and a^{-1} = a^{q-2}
+/// The elements must be the whole field.
+pub fn verify_finite_field<T>(
+    elements: &[T],
+    pow: impl Fn(&T, u64) -> T,
+) -> Result<(), Counterexample<T>>
+where
+    T: FieldBase + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+{
+    verify_field(elements)?;
+    let q = elements.len() as u64;
+    verify_pow(elements, &pow, q)?;
+    for a in elements.iter().filter(|a| **a != T::zero()) {
+        check(
+            a.checked_inverse() == Some(pow(a, q - 2)),
+            Axiom::InversePower,
+            &[a],
+        )?;
+    }
+    Ok(())
+}
@@ -0,0 +1,78 @@
+use algebra::{
+    field::{finite_field::binary::Gf2e, FieldBase},
+    ring::RingBase,
+};
+
+type F16 = Gf2e<4>;
+type F256 = Gf2e<8>;
+
+#[test]
+fn test_field_axioms_gf16() {
+    for a in F16::elements() {
+        assert_eq!(a + a, F16::zero());
+        assert_eq!(a * F16::one(), a);
+        if a != F16::zero() {
+            assert_eq!(a * a.inverse(), F16::one());
+            assert_eq!(a.pow(15), F16::one());
+        }
+        assert_eq!(a.sqrt() * a.sqrt(), a);
+        for b in F16::elements() {
+            assert_eq!(a * b, b * a);
+            for c in F16::elements() {
+                assert_eq!(a * (b + c), a * b + a * c);
+                assert_eq!((a * b) * c, a * (b * c));
+            }
+        }
+    }
+}
+
+#[test]
+fn test_aes_field() {
+    // FIPS-197 example: {57} * {83} = {c1}
+    assert_eq!(F256::new(0x57) * F256::new(0x83), F256::new(0xc1));
+    assert_eq!(F256::new(0x53).inverse(), F256::new(0xca));
+    // Reduction of the defining polynomial
+    assert_eq!(F256::new(0x11b), F256::zero());
+    assert_eq!(F256::x().pow(8), F256::new(0x1b));
+    assert_eq!(
+        F256::new(0x57) / F256::new(0x83) * F256::new(0x83),
+        F256::new(0x57)
+    );
+}
+
+#[test]
+fn test_frobenius_and_trace() {
+    let a = Gf2e::<10>::new(0x2a7);
+    assert_eq!(a.frobenius(), a * a);
+    // Frobenius has order N
+    assert_eq!((0..10).fold(a, |x, _| x.frobenius()), a);
+    // Half of the elements have trace zero
+    let traceless = Gf2e::<6>::elements().filter(|x| !x.trace()).count();
+    assert_eq!(traceless, 32);
+    for x in Gf2e::<6>::elements() {
+        assert_eq!(x.trace(), x.frobenius().trace());
+    }
+}
+
+#[test]
+fn test_multiplicative_group_is_cyclic() {
+    // Some element has order 2^N - 1
+    for n in [12_u64, 16] {
+        let order = (1 << n) - 1;
+        let found = match n {
+            12 => (2..64).any(|v| {
+                let g = Gf2e::<12>::new(v);
+                [3, 5, 7, 13]
+                    .iter()
+                    .all(|p| g.pow(order / p) != Gf2e::one())
+            }),
+            _ => (2..64).any(|v| {
+                let g = Gf2e::<16>::new(v);
+                [3, 5, 17, 257]
+                    .iter()
+                    .all(|p| g.pow(order / p) != Gf2e::one())
+            }),
+        };
+        assert!(found);
+    }
+}
@@ -0,0 +1,126 @@
+use algebra::{
+    field::finite_field::{
+        binary::{clmul64, Gf2e},
+        Fp,
+    },
+    polynomial::{binary::BitPolynomial, Polynomial},
+};
+
+/// Deterministic xorshift generator
+struct XorShift(u64);
+
+impl XorShift {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn bits(&mut self, n: usize) -> Vec<bool> {
+        (0..n).map(|_| self.next() & 1 == 1).collect()
+    }
+
+    fn gf2e<const N: u32>(&mut self, n: usize) -> Polynomial<Gf2e<N>> {
+        Polynomial::from_coeffs((0..n).map(|_| Gf2e::new(self.next() as u32)).collect())
+    }
+}
+
+fn gf2(bits: &[bool]) -> Polynomial<Fp<2>> {
+    Polynomial::from_coeffs(bits.iter().map(|&b| Fp::new(b as u64)).collect())
+}
+
+#[test]
+fn test_clmul64() {
+    let naive = |a: u64, b: u64| {
+        (0..64)
+            .filter(|i| (b >> i) & 1 == 1)
+            .fold(0u128, |acc, i| acc ^ ((a as u128) << i))
+    };
+    let mut rng = XorShift(0x2545f4914f6cdd1d);
+    for _ in 0..1000 {
+        let (a, b) = (rng.next(), rng.next());
+        assert_eq!(clmul64(a, b), naive(a, b));
+    }
+    assert_eq!(clmul64(u64::MAX, u64::MAX), naive(u64::MAX, u64::MAX));
+    // (x + 1)^2 = x^2 + 1
+    assert_eq!(clmul64(0b11, 0b11), 0b101);
+}
+
+#[test]
+fn test_bit_polynomial_basics() {
+    let f = BitPolynomial::from_bits(&[true, true, false, true]);
+    assert_eq!(f.degree(), Some(3));
+    assert_eq!(f.to_string(), "1 + x + x^3");
+    assert_eq!(BitPolynomial::zero().to_string(), "0");
+    assert_eq!(BitPolynomial::zero().degree(), None);
+    assert_eq!(BitPolynomial::monomial(130).degree(), Some(130));
+    assert_eq!(&f + &f, BitPolynomial::zero());
+    assert_eq!(f.bits(), vec![true, true, false, true]);
+
+    let p = gf2(&[true, false, true, true, false, true]);
+    assert_eq!(Polynomial::<Fp<2>>::from(&BitPolynomial::from(&p)), p);
+}
+
+#[test]
+fn test_bit_polynomial_multiplication() {
+    let mut rng = XorShift(0x9e3779b97f4a7c15);
+    // Sizes on both sides of the Karatsuba threshold, balanced and unbalanced
+    for (n, m) in [
+        (1, 1),
+        (63, 65),
+        (200, 130),
+        (1600, 1700),
+        (3000, 900),
+        (5000, 64),
+    ] {
+        let (a, b) = (rng.bits(n), rng.bits(m));
+        let expected = &gf2(&a) * &gf2(&b);
+        let product = &BitPolynomial::from_bits(&a) * &BitPolynomial::from_bits(&b);
+        assert_eq!(Polynomial::<Fp<2>>::from(&product), expected);
+        assert_eq!(gf2(&a).mul_clmul(&gf2(&b)), expected);
+    }
+}
+
+#[test]
+fn test_bit_polynomial_square_and_division() {
+    let mut rng = XorShift(0x1234_5678_9abc_def1);
+    for (n, m) in [(10, 3), (300, 70), (2000, 1999), (5, 40)] {
+        let a = BitPolynomial::from_bits(&rng.bits(n));
+        let mut b = rng.bits(m);
+        b.push(true);
+        let b = BitPolynomial::from_bits(&b);
+        assert_eq!(a.square(), &a * &a);
+
+        let (q, r) = a.div_rem(&b);
+        assert!(r.degree() < b.degree());
+        assert_eq!(&(&q * &b) + &r, a);
+    }
+
+    // gcd((x + 1)^3 x, (x + 1)^2 (x^2 + x + 1)) = (x + 1)^2
+    let x1 = BitPolynomial::from_bits(&[true, true]);
+    let a = &(&x1.square() * &x1) * &BitPolynomial::monomial(1);
+    let b = &x1.square() * &BitPolynomial::from_bits(&[true, true, true]);
+    assert_eq!(a.gcd(&b), x1.square());
+}
+
+fn check_kronecker<const N: u32>(rng: &mut XorShift) {
+    for (n, m) in [(1, 1), (5, 9), (40, 33), (300, 250)] {
+        let (a, b) = (rng.gf2e::<N>(n), rng.gf2e::<N>(m));
+        assert_eq!(a.mul_clmul(&b), &a * &b);
+    }
+    assert_eq!(
+        rng.gf2e::<N>(4).mul_clmul(&Polynomial::zero()),
+        Polynomial::zero()
+    );
+}
+
+#[test]
+fn test_gf2e_polynomial_multiplication() {
+    let mut rng = XorShift(0xdead_beef_cafe_f00d);
+    check_kronecker::<1>(&mut rng);
+    check_kronecker::<4>(&mut rng);
+    check_kronecker::<8>(&mut rng);
+    check_kronecker::<13>(&mut rng);
+    check_kronecker::<20>(&mut rng);
+}
@@ -0,0 +1,83 @@
+use algebra::{
+    error::AlgebraError,
+    field::{
+        finite_field::{binary::Gf2e, zn::Zn, Fp},
+        FieldBase,
+    },
+    number::{Rational, UnsignedRational},
+    polynomial::Polynomial,
+    ring::{dynamic::IntegersMod, RingBase},
+};
+
+type F7 = Fp<7>;
+
+#[test]
+fn test_checked_inverse() {
+    assert_eq!(F7::zero().checked_inverse(), None);
+    assert_eq!(F7::new(3).checked_inverse(), Some(F7::new(5)));
+    assert_eq!(Gf2e::<8>::zero().checked_inverse(), None);
+    let a = Gf2e::<8>::new(0x53);
+    assert_eq!(a.checked_inverse(), Some(Gf2e::new(0xCA)));
+    assert_eq!(Rational::zero().checked_inverse(), None);
+    assert_eq!(
+        Rational::new(-2, 3).checked_inverse(),
+        Some(Rational::new(-3, 2))
+    );
+    assert_eq!(0.0_f64.checked_inverse(), None);
+
+    // Zn also rejects non-zero non-units
+    assert_eq!(Zn::new(4, 12).checked_inverse(), None);
+    assert_eq!(Zn::new(5, 12).checked_inverse(), Some(Zn::new(5, 12)));
+}
+
+#[test]
+fn test_checked_div() {
+    assert_eq!(F7::new(3).checked_div(&F7::zero()), None);
+    assert_eq!(F7::new(3).checked_div(&F7::new(2)), Some(F7::new(5)));
+    assert_eq!(
+        Rational::integer(1).checked_div(&Rational::integer(4)),
+        Some(Rational::new(1, 4))
+    );
+    assert_eq!(Zn::new(3, 12).checked_div(&Zn::new(3, 12)), None);
+
+    let p = Polynomial::from_coeffs(vec![F7::new(1), F7::new(2), F7::new(1)]);
+    assert_eq!(
+        p.checked_div_rem(&Polynomial::zero()),
+        Err(AlgebraError::DivisionByZero)
+    );
+    let divisor = Polynomial::from_coeffs(vec![F7::new(1), F7::new(1)]);
+    assert_eq!(
+        p.checked_div_rem(&divisor),
+        Ok((divisor.clone(), Polynomial::zero()))
+    );
+}
+
+#[test]
+fn test_try_from_constructors() {
+    assert_eq!(
+        Rational::try_from((3, 0)),
+        Err(AlgebraError::ZeroDenominator)
+    );
+    assert_eq!(Rational::try_from((4, -6)), Ok(Rational::new(-2, 3)));
+    assert!(matches!(
+        UnsignedRational::try_from((1, 0)),
+        Err(AlgebraError::ZeroDenominator)
+    ));
+    assert!(UnsignedRational::try_from((2, 4)).is_ok());
+
+    assert_eq!(Zn::try_from((13, 0)), Err(AlgebraError::ZeroModulus));
+    assert_eq!(Zn::try_from((13, 5)), Ok(Zn::new(3, 5)));
+    assert_eq!(IntegersMod::try_from(0), Err(AlgebraError::ZeroModulus));
+    assert_eq!(IntegersMod::try_from(12), Ok(IntegersMod::new(12)));
+
+    assert_eq!(
+        AlgebraError::ZeroDenominator.to_string(),
+        "Denominator must be non-zero"
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_zero_denominator_panics() {
+    UnsignedRational::new(1, 0);
+}
@@ -0,0 +1,154 @@
+use algebra::{
+    error::AlgebraError,
+    number::{Complex, Rational},
+    ring::dynamic::{
+        Complexes, DynRing, DynRingError, Element, Integers, IntegersMod, Rationals, Reals,
+    },
+};
+
+/// Evaluate an integer polynomial at x in whatever ring x lives in
+fn evaluate(coeffs: &[i128], x: &Element) -> Element {
+    let ring = x.ring();
+    coeffs
+        .iter()
+        .rev()
+        .fold(ring.zero(), |acc, c| &(&acc * x) + &ring.integer(*c))
+}
+
+#[test]
+fn test_runtime_rings() {
+    let rings: Vec<Box<dyn DynRing>> = vec![
+        Box::new(Integers),
+        Box::new(Rationals),
+        Box::new(Reals),
+        Box::new(Complexes),
+        Box::new(IntegersMod::new(101)),
+        Box::new(IntegersMod::new(12)),
+    ];
+    let names = rings.iter().map(|r| r.to_string()).collect::<Vec<_>>();
+    assert_eq!(names, ["Z", "Q", "R", "C", "F101", "Z/12"]);
+    let fields = rings.iter().map(|r| r.is_field()).collect::<Vec<_>>();
+    assert_eq!(fields, [false, true, true, true, true, false]);
+
+    // 3x^2 - 5x + 7 at x = 4 is 35 in every ring
+    let values = rings
+        .iter()
+        .map(|r| evaluate(&[7, -5, 3], &r.integer(4)).to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(values, ["35", "35", "35", "35 + 0 i", "35", "11"]);
+}
+
+#[test]
+fn test_heterogeneous_collection() {
+    let elements = [
+        Element::new(Rational::new(3, 4)),
+        Element::new(2.5f64),
+        Element::new(IntegersMod::new(7).element(-1)),
+        Element::new(Complex::new(0.0, 1.0)),
+    ];
+    let squares = elements.iter().map(|x| x * x).collect::<Vec<_>>();
+    assert_eq!(
+        squares[0].downcast_ref::<Rational>(),
+        Some(&Rational::new(9, 16))
+    );
+    assert_eq!(squares[1].downcast_ref::<f64>(), Some(&6.25));
+    assert_eq!(squares[2].to_string(), "1");
+    assert_eq!(squares[3], Element::new(Complex::new(-1.0, 0.0)));
+
+    // Elements of different rings never compare equal or combine
+    assert_ne!(Rationals.integer(1), Reals.integer(1));
+    assert_eq!(
+        elements[0].try_add(&elements[1]),
+        Err(DynRingError::RingMismatch)
+    );
+    let (a, b) = (
+        IntegersMod::new(7).element(3),
+        IntegersMod::new(11).element(3),
+    );
+    assert_eq!(
+        Element::new(a).try_mul(&Element::new(b)),
+        Err(DynRingError::RingMismatch)
+    );
+}
+
+#[test]
+fn test_inverses() {
+    let f101 = IntegersMod::new(101);
+    let x = f101.integer(5).try_inverse().unwrap();
+    assert_eq!(x.to_string(), "81");
+    assert_eq!(&x * &f101.integer(5), f101.one());
+    assert_eq!(
+        f101.integer(0).try_inverse(),
+        Err(DynRingError::Algebra(AlgebraError::DivisionByZero))
+    );
+
+    let z12 = IntegersMod::new(12);
+    assert_eq!(
+        z12.integer(4).try_inverse(),
+        Err(DynRingError::Algebra(AlgebraError::NotInvertible))
+    );
+    assert_eq!(z12.integer(5).try_inverse(), Ok(z12.integer(5)));
+
+    assert_eq!(Integers.integer(-1).try_inverse(), Ok(Integers.integer(-1)));
+    assert_eq!(
+        Integers.integer(2).try_inverse(),
+        Err(DynRingError::Algebra(AlgebraError::NotInvertible))
+    );
+    assert_eq!(
+        Rationals.integer(0).try_inverse(),
+        Err(DynRingError::Algebra(AlgebraError::DivisionByZero))
+    );
+
+    let q = Rationals.integer(2).try_div(&Rationals.integer(6)).unwrap();
+    assert_eq!(q.to_string(), "1/3");
+    assert_eq!(q.try_pow(-3).unwrap().to_string(), "27");
+    assert_eq!(f101.integer(2).try_pow(100), Ok(f101.one()));
+    assert_eq!((-&Rationals.integer(3)).to_string(), "-3");
+}
+
+#[test]
+fn test_overflow() {
+    let max = Integers.integer(i128::MAX);
+    assert_eq!(max.try_add(&Integers.one()), Err(DynRingError::Overflow));
+    assert_eq!(
+        Integers.integer(i128::MIN).try_neg(),
+        Err(DynRingError::Overflow)
+    );
+    assert_eq!(
+        max.try_mul(&Integers.integer(2)),
+        Err(DynRingError::Overflow)
+    );
+    assert_eq!(
+        Integers.integer(2).try_pow(127),
+        Err(DynRingError::Overflow)
+    );
+    assert_eq!(
+        Integers.integer(2).try_pow(126).unwrap().to_string(),
+        (1_i128 << 126).to_string()
+    );
+
+    let big = Rationals.integer(i128::MAX);
+    assert_eq!(big.try_add(&Rationals.one()), Err(DynRingError::Overflow));
+    assert_eq!(
+        Rationals.integer(i128::MIN).try_inverse(),
+        Err(DynRingError::Overflow)
+    );
+    assert_eq!(
+        Rationals
+            .one()
+            .try_sub(&big)
+            .and_then(|x| x.try_sub(&Rationals.integer(3))),
+        Err(DynRingError::Overflow)
+    );
+    // Reals saturate instead
+    assert_eq!(
+        Reals.integer(1 << 100).try_pow(20).unwrap().to_string(),
+        "inf"
+    );
+}
+
+#[test]
+#[should_panic(expected = "Arithmetic overflow")]
+fn test_overflow_operator_panics() {
+    let _ = Integers.integer(i128::MAX) + Integers.one();
+}
@@ -0,0 +1,87 @@
+use algebra::{
+    field::finite_field::Fp,
+    group::action::{Action, Linear},
+    matrix::Matrix,
+    ring::{endomorphism::Endo, Ring, RingBase},
+};
+
+type F2 = Fp<2>;
+type E = Endo<[F2; 2]>;
+
+fn endo<T: RingBase + Copy, const N: usize>(rows: [[T; N]; N]) -> Endo<[T; N]> {
+    Endo::from_matrix(Matrix::from_rows(rows.map(Vec::from).to_vec()))
+}
+
+/// Every endomorphism of F_2^2
+fn all_endomorphisms() -> Vec<E> {
+    (0..16)
+        .map(|bits: u64| {
+            let b = |k: u64| F2::new((bits >> k) & 1);
+            endo([[b(0), b(1)], [b(2), b(3)]])
+        })
+        .collect()
+}
+
+fn assert_ring<R: Ring + std::fmt::Debug>(a: &R, b: &R, c: &R) {
+    let (a, b, c) = (a.clone(), b.clone(), c.clone());
+    assert_eq!(
+        (a.clone() * b.clone()) * c.clone(),
+        a.clone() * (b.clone() * c.clone())
+    );
+    assert_eq!(
+        a.clone() * (b.clone() + c.clone()),
+        a.clone() * b.clone() + a.clone() * c.clone()
+    );
+    assert_eq!(
+        (a.clone() + b.clone()) * c.clone(),
+        a.clone() * c.clone() + b * c
+    );
+    assert_eq!(a.clone() * R::one(), a);
+    assert_eq!(R::one() * a.clone(), a);
+    assert_eq!(a.clone() + R::zero(), a);
+}
+
+#[test]
+fn test_endomorphism_ring_axioms() {
+    let elements = all_endomorphisms();
+    for a in elements.iter() {
+        for b in elements.iter() {
+            for c in elements.iter() {
+                assert_ring(a, b, c);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_endomorphism_ring_is_not_commutative() {
+    let (zero, one) = (F2::new(0), F2::new(1));
+    // Projection onto the first coordinate and the swap map
+    let p = endo([[one, zero], [zero, zero]]);
+    let s = endo([[zero, one], [one, zero]]);
+    assert_ne!(p.clone() * s.clone(), s * p.clone());
+
+    // Zero divisors: the two coordinate projections
+    let q = endo([[zero, zero], [zero, one]]);
+    assert_eq!(p.clone() * q.clone(), E::zero());
+    assert_eq!(p + q, E::one());
+}
+
+#[test]
+fn test_endomorphism_action_is_composition() {
+    let f = endo::<i64, 2>([[1, 2], [3, 4]]);
+    let g = endo::<i64, 2>([[0, 1], [-1, 0]]);
+    let v: [i64; 2] = [5, -7];
+    assert_eq!(
+        Linear::act(&(f.clone() * g.clone()), &v),
+        f.apply(&g.apply(&v))
+    );
+    assert_eq!(f.apply(&v), [-9, -13]);
+    assert_eq!(f.matrix().row(1), &[3, 4]);
+}
+
+#[test]
+#[should_panic]
+fn test_endomorphism_wrong_size() {
+    let _ = E::from_matrix(Matrix::identity(3));
+}
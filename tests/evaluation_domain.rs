@@ -0,0 +1,115 @@
+use algebra::{
+    field::finite_field::Fp,
+    polynomial::{domain::EvaluationDomain, Polynomial},
+};
+
+const P: u64 = 998244353;
+type F = Fp<P>;
+
+/// Deterministic xorshift generator
+struct XorShift(u64);
+
+impl XorShift {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn poly(&mut self, len: usize) -> Polynomial<F> {
+        Polynomial::from_coeffs((0..len).map(|_| F::new(self.next())).collect())
+    }
+}
+
+#[test]
+fn test_domain() {
+    let domain = EvaluationDomain::<P>::new(8).unwrap();
+    assert_eq!(domain.size(), 8);
+    assert_eq!(domain.root().pow(8), F::new(1));
+    assert_ne!(domain.root().pow(4), F::new(1));
+    assert_eq!(domain.elements()[3], domain.root().pow(3));
+    assert!(EvaluationDomain::<P>::new(3).is_none());
+
+    assert_eq!(EvaluationDomain::<P>::containing(100).unwrap().size(), 112);
+    assert_eq!(EvaluationDomain::<P>::containing(0).unwrap().size(), 1);
+
+    let constant = domain.evaluate(&Polynomial::from_coeffs(vec![F::new(5)]));
+    assert!(constant.values().iter().all(|v| *v == F::new(5)));
+    for w in domain.elements() {
+        assert_eq!(domain.vanishing_polynomial().evaluate(w), F::new(0));
+    }
+}
+
+#[test]
+fn test_round_trip() {
+    let mut rng = XorShift(0x853c49e6748fea9b);
+    // Power of two, and mixed radix 2^3 7 17 with a Bluestein step
+    for n in [16, 56, 952] {
+        let domain = EvaluationDomain::<P>::new(n).unwrap();
+        let f = rng.poly(n - 3);
+        let evaluations = domain.evaluate(&f);
+        for (w, v) in domain
+            .elements()
+            .iter()
+            .zip(evaluations.values())
+            .step_by(7)
+        {
+            assert_eq!(f.evaluate(w), *v);
+        }
+        assert_eq!(evaluations.interpolate(), f);
+        assert_eq!(domain.interpolate(&evaluations), f);
+    }
+}
+
+#[test]
+fn test_pointwise_arithmetic() {
+    let mut rng = XorShift(0xda3e39cb94b95bdb);
+    let domain = EvaluationDomain::<P>::containing(100).unwrap();
+    let (f, g, h) = (rng.poly(40), rng.poly(60), rng.poly(70));
+    let (ef, eg, eh) = (
+        domain.evaluate(&f),
+        domain.evaluate(&g),
+        domain.evaluate(&h),
+    );
+
+    assert_eq!((&ef * &eg).interpolate(), &f * &g);
+    assert_eq!((&ef + &eh).interpolate(), &f + &h);
+    assert_eq!((ef.clone() - eg.clone()).interpolate(), &f - &g);
+    let scaled = &ef * &domain.constant(F::new(3));
+    assert_eq!(
+        scaled.interpolate(),
+        &f * &Polynomial::from_coeffs(vec![F::new(3)])
+    );
+
+    // Too large a product wraps around modulo x^n - 1
+    let product = &f * &h;
+    let mut wrapped = vec![F::new(0); domain.size()];
+    for (i, c) in product.coeffs().iter().enumerate() {
+        wrapped[i % domain.size()] += *c;
+    }
+    assert_eq!((&ef * &eh).interpolate(), Polynomial::from_coeffs(wrapped));
+}
+
+#[test]
+fn test_evaluate_at() {
+    let mut rng = XorShift(0x2b992ddfa23249d6);
+    let domain = EvaluationDomain::<P>::new(64).unwrap();
+    let f = rng.poly(50);
+    let evaluations = domain.evaluate(&f);
+    for _ in 0..20 {
+        let x = F::new(rng.next());
+        assert_eq!(evaluations.evaluate_at(x), f.evaluate(&x));
+    }
+    let w = domain.elements()[5];
+    assert_eq!(evaluations.evaluate_at(w), f.evaluate(&w));
+}
+
+#[test]
+#[should_panic]
+fn test_domain_mismatch() {
+    let small = EvaluationDomain::<P>::new(8).unwrap();
+    let large = EvaluationDomain::<P>::new(16).unwrap();
+    let one = Polynomial::from_coeffs(vec![F::new(1)]);
+    let _ = &small.evaluate(&one) + &large.evaluate(&one);
+}
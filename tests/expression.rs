@@ -0,0 +1,141 @@
+use algebra::{
+    error::AlgebraError,
+    expression::{evaluate, parse, parse_ring, Expr, ExpressionError, MAX_DEGREE},
+    number::Rational,
+    ring::dynamic::DynRingError,
+};
+
+fn show(input: &str) -> String {
+    match evaluate(input) {
+        Ok(value) => value.to_string(),
+        Err(e) => format!("error: {e}"),
+    }
+}
+
+#[test]
+fn test_parse_tree() {
+    let statement = parse("-x^2 + 3x").unwrap();
+    let x2 = Expr::Pow(Box::new(Expr::Variable), 2);
+    let three = Expr::Number {
+        mantissa: 3,
+        scale: 0,
+    };
+    assert_eq!(
+        statement.expr,
+        Expr::Add(
+            Box::new(Expr::Neg(Box::new(x2))),
+            Box::new(Expr::Mul(Box::new(three), Box::new(Expr::Variable)))
+        )
+    );
+    assert_eq!(statement.ring.to_string(), "Q");
+
+    assert_eq!(parse("x mod 12").unwrap().ring.to_string(), "Z/12");
+    assert_eq!(parse("x in F101").unwrap().ring.to_string(), "F101");
+    assert!(parse_ring("F12").is_err());
+    assert_eq!(
+        parse_ring("Z/0").err(),
+        Some(AlgebraError::ZeroModulus.into())
+    );
+}
+
+#[test]
+fn test_evaluate() {
+    assert_eq!(
+        show("(3x^2 + 1) * (x - 2) mod 17"),
+        "15 + 1 x + 11 x^2 + 3 x^3"
+    );
+    assert_eq!(show("inverse(5) in F101"), "81");
+    assert_eq!(show("1/3 + 0.25"), "7/12");
+    assert_eq!(show("2^-1 in F7"), "4");
+    assert_eq!(show("2(x + 1)^2 - 2x^2 in Z"), "2 + 4 x");
+    assert_eq!(show("x^3 - x^3"), "0");
+    assert_eq!(show("1.5 * 4 in R"), "6");
+
+    let value = evaluate("(x - 1)(x + 1) / 4").unwrap();
+    assert_eq!(value.degree(), Some(2));
+    assert_eq!(
+        value.coeffs()[2].downcast_ref::<Rational>(),
+        Some(&Rational::new(1, 4))
+    );
+    assert_eq!(value.ring().to_string(), "Q");
+}
+
+#[test]
+fn test_errors() {
+    assert_eq!(
+        evaluate("inverse(4) mod 12"),
+        Err(AlgebraError::NotInvertible.into())
+    );
+    assert_eq!(
+        evaluate("inverse(0) in Q"),
+        Err(ExpressionError::Ring(DynRingError::Algebra(
+            AlgebraError::DivisionByZero
+        )))
+    );
+    assert_eq!(evaluate("1 / x"), Err(ExpressionError::NonConstant));
+    assert_eq!(evaluate("x^-1"), Err(ExpressionError::NonConstant));
+    assert_eq!(
+        evaluate("y + 1"),
+        Err(ExpressionError::UnknownVariable("y".to_string()))
+    );
+    assert_eq!(evaluate("(x + 1"), Err(ExpressionError::UnexpectedEnd));
+    assert_eq!(
+        evaluate("2 ** 3"),
+        Err(ExpressionError::UnexpectedToken("*".to_string()))
+    );
+    assert_eq!(
+        evaluate("x # 2"),
+        Err(ExpressionError::UnexpectedCharacter('#'))
+    );
+    assert!(matches!(
+        evaluate("inverse(1, 2)"),
+        Err(ExpressionError::UnknownFunction(_))
+    ));
+}
+
+#[test]
+fn test_number_literals() {
+    assert_eq!(show("0 mod 1"), "0");
+    assert_eq!(show("7 in Z"), "7");
+    assert_eq!(show("0.5 mod 9"), "5");
+    let invalid = |n: &str| Err(ExpressionError::InvalidNumber(n.to_string()));
+    assert_eq!(evaluate("1.5 in Z"), invalid("15e-1"));
+    assert_eq!(evaluate("1.5 mod 12"), invalid("15e-1"));
+    assert_eq!(evaluate("0.25 mod 5"), invalid("25e-2"));
+}
+
+#[test]
+fn test_degree_limit() {
+    let half = MAX_DEGREE / 2;
+    assert_eq!(
+        evaluate("x^100000000"),
+        Err(ExpressionError::DegreeTooLarge(100_000_000))
+    );
+    assert_eq!(
+        evaluate("(x^3 + 1)^9223372036854775807"),
+        Err(ExpressionError::DegreeTooLarge(usize::MAX))
+    );
+    assert_eq!(
+        evaluate(&format!("x^{half} * x^{} mod 7", half + 1)),
+        Err(ExpressionError::DegreeTooLarge(MAX_DEGREE + 1))
+    );
+    assert_eq!(
+        evaluate(&format!("x^{half} * x^{half} mod 7"))
+            .unwrap()
+            .degree(),
+        Some(MAX_DEGREE)
+    );
+}
+
+#[test]
+fn test_overflow_errors() {
+    let overflow = Err(ExpressionError::Ring(DynRingError::Overflow));
+    assert_eq!(evaluate("2^200"), overflow);
+    assert_eq!(evaluate("2^127 in Z"), overflow);
+    assert_eq!(evaluate("-(2^126) * 2 - 1 in Z"), overflow);
+    assert_eq!(evaluate("(2^64 x + 1)^2"), overflow);
+    assert_eq!(evaluate("1 / 3^81"), overflow);
+    assert_eq!(show("2^126 in Z"), (1_i128 << 126).to_string());
+    assert_eq!(show("2^200 mod 1000000007"), "499445072");
+    assert_eq!(show("2^200"), "error: Arithmetic overflow");
+}
@@ -0,0 +1,115 @@
+use algebra::{
+    field::{
+        finite_field::{
+            binary::Gf2e,
+            extension::{Aes, ExtensionModulus, FpExt},
+            Fp,
+        },
+        FieldBase,
+    },
+    polynomial::Polynomial,
+    ring::RingBase,
+    verify::verify_finite_field,
+};
+
+type GF256 = FpExt<2, 8, Aes>;
+
+/// x^2 + 1 over F3, irreducible since -1 is not a square mod 3
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Gaussian3;
+
+impl ExtensionModulus<3, 2> for Gaussian3 {
+    const COEFFS: [u64; 2] = [1, 0];
+}
+
+/// x^3 + x + 1 over F5, irreducible since it has no root
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Cubic5;
+
+impl ExtensionModulus<5, 3> for Cubic5 {
+    const COEFFS: [u64; 3] = [1, 1, 0];
+}
+
+/// x^2 + 3 x + 2 = (x + 1)(x + 2) over F5
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Reducible5;
+
+impl ExtensionModulus<5, 2> for Reducible5 {
+    const COEFFS: [u64; 2] = [2, 3];
+}
+
+type F9 = FpExt<3, 2, Gaussian3>;
+type F125 = FpExt<5, 3, Cubic5>;
+
+fn byte(b: u8) -> GF256 {
+    GF256::new(std::array::from_fn(|k| Fp::new(((b >> k) & 1) as u64)))
+}
+
+#[test]
+fn test_aes_field() {
+    // FIPS-197 example {57} {83} = {c1}
+    assert_eq!(byte(0x57) * byte(0x83), byte(0xc1));
+    assert_eq!(byte(0x53).inverse(), byte(0xca));
+    assert!(GF256::modulus_is_irreducible());
+
+    // Same field as the bit-vector representation with the same polynomial
+    for a in 0..=255u8 {
+        for b in (0..=255u8).step_by(7) {
+            let product = Gf2e::<8>::new(a as u32) * Gf2e::<8>::new(b as u32);
+            assert_eq!(byte(a) * byte(b), byte(product.value() as u8));
+        }
+    }
+}
+
+#[test]
+fn test_field_axioms() {
+    let f9 = F9::elements().collect::<Vec<_>>();
+    assert_eq!(f9.len(), 9);
+    assert_eq!(verify_finite_field(&f9, F9::pow), Ok(()));
+
+    // x^2 = -1 in F9
+    let i = F9::x();
+    assert_eq!(i * i, -F9::one());
+    assert_eq!(i.to_string(), "GF3^2[0, 1]");
+
+    assert!(F125::modulus_is_irreducible());
+    assert!(!FpExt::<5, 2, Reducible5>::modulus_is_irreducible());
+    for a in F125::elements().skip(1).step_by(3) {
+        assert_eq!(a * a.inverse(), F125::one());
+        assert_eq!(a / a, F125::one());
+        assert_eq!(a.pow(124), F125::one());
+    }
+}
+
+#[test]
+fn test_frobenius() {
+    let x = F125::x();
+    // x is a root of the modulus, and so are its conjugates
+    let f = F125::modulus();
+    let at = |a: F125| {
+        f.coeffs()
+            .iter()
+            .rev()
+            .fold(F125::zero(), |acc, c| acc * a + F125::from(*c))
+    };
+    assert_eq!(at(x), F125::zero());
+    assert_eq!(at(x.frobenius()), F125::zero());
+    assert_ne!(x.frobenius(), x);
+    assert_eq!(x.frobenius().frobenius().frobenius(), x);
+
+    // Fixed field of the Frobenius is the prime field
+    let fixed = F125::elements().filter(|a| a.frobenius() == *a).count();
+    assert_eq!(fixed, 5);
+
+    // Trace and norm of x from the coefficients of x^3 + x + 1: -c_2 and -c_0
+    assert_eq!(x.trace(), Fp::new(0));
+    assert_eq!(x.norm(), Fp::new(4));
+    let a = F125::from_poly(&Polynomial::from_coeffs(vec![
+        Fp::new(1),
+        Fp::new(4),
+        Fp::new(2),
+    ]));
+    let b = F125::x() + F125::one();
+    assert_eq!((a + b).trace(), a.trace() + b.trace());
+    assert_eq!((a * b).norm(), a.norm() * b.norm());
+}
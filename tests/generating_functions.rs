@@ -0,0 +1,212 @@
+use algebra::{
+    field::finite_field::Fp,
+    number::Rational,
+    polynomial::{
+        genfunc::{
+            binomial_transform, coefficient, convolution, expand, hadamard_product,
+            inverse_binomial_transform,
+        },
+        power_series::PowerSeries,
+        Polynomial,
+    },
+};
+
+const P: u64 = 1_000_000_007;
+type F = Fp<P>;
+
+fn poly(coeffs: &[i64]) -> Polynomial<F> {
+    Polynomial::from_coeffs(
+        coeffs
+            .iter()
+            .map(|&c| F::new(c.rem_euclid(P as i64) as u64))
+            .collect(),
+    )
+}
+
+/// Fibonacci F_n mod P by fast doubling
+fn fibonacci(n: u64) -> u64 {
+    fn go(n: u64) -> (u128, u128) {
+        if n == 0 {
+            return (0, 1);
+        }
+        let (a, b) = go(n / 2);
+        let p = P as u128;
+        let c = a * ((2 * b + p - a) % p) % p;
+        let d = (a * a + b * b) % p;
+        if n.is_multiple_of(2) {
+            (c, d)
+        } else {
+            (d, (c + d) % p)
+        }
+    }
+    go(n).0 as u64
+}
+
+#[test]
+fn test_convolution() {
+    let a = [1, 2, 3].map(F::new);
+    let b = [4, 5].map(F::new);
+    assert_eq!(convolution(&a, &b), [4, 13, 22, 15].map(F::new));
+    assert!(convolution::<F>(&a, &[]).is_empty());
+}
+
+#[test]
+fn test_long_convolution() {
+    // Long enough to go through the CRT transforms for P and the NTT for an NTT-friendly prime
+    fn check<const Q: u64>(n: usize) {
+        let a = (0..n)
+            .map(|i| Fp::<Q>::new((i as u64).pow(3) + 7))
+            .collect::<Vec<_>>();
+        let b = (0..n + 37)
+            .map(|i| Fp::<Q>::new(Q - 1 - (i as u64) * 12345))
+            .collect::<Vec<_>>();
+        let mut expected = vec![Fp::<Q>::new(0); a.len() + b.len() - 1];
+        for (i, &x) in a.iter().enumerate() {
+            for (j, &y) in b.iter().enumerate() {
+                expected[i + j] += x * y;
+            }
+        }
+        assert_eq!(convolution(&a, &b), expected);
+    }
+    check::<P>(300);
+    check::<998_244_353>(300);
+}
+
+#[test]
+fn test_coefficient_extraction() {
+    // x / (1 - x - x^2) generates the Fibonacci numbers
+    let num = poly(&[0, 1]);
+    let den = poly(&[1, -1, -1]);
+    let series = expand(&num, &den, 40);
+    for n in 0..40 {
+        assert_eq!(series.coeff(n).value(), fibonacci(n as u64));
+        assert_eq!(coefficient(&num, &den, n as u64), *series.coeff(n));
+    }
+    for n in [1_000, 123_456_789, 1_000_000_000_000_000_000] {
+        assert_eq!(coefficient(&num, &den, n).value(), fibonacci(n));
+    }
+    // Numerator of higher degree than the denominator
+    let num = poly(&[2, 0, 0, 0, 5]);
+    let den = poly(&[1, -3]);
+    let series = expand(&num, &den, 10);
+    for n in 0..10 {
+        assert_eq!(coefficient(&num, &den, n as u64), *series.coeff(n));
+    }
+}
+
+#[test]
+fn test_rational_coefficients() {
+    // 1 / (2 - x) = sum x^n / 2^(n+1)
+    let num = Polynomial::from_coeffs(vec![Rational::integer(1)]);
+    let den = Polynomial::from_coeffs(vec![Rational::integer(2), Rational::integer(-1)]);
+    let series = expand(&num, &den, 10);
+    for n in 0..10 {
+        assert_eq!(*series.coeff(n), Rational::new(1, 1 << (n + 1)));
+    }
+    assert_eq!(coefficient(&num, &den, 30), Rational::new(1, 1 << 31));
+}
+
+#[test]
+fn test_power_series_arithmetic() {
+    let one_minus_x = PowerSeries::from_polynomial(&poly(&[1, -1]), 8);
+    let geometric = one_minus_x.inverse();
+    assert_eq!(geometric, PowerSeries::new(vec![F::new(1); 8], 8));
+    assert_eq!(geometric.clone() * one_minus_x.clone(), PowerSeries::one(8));
+    assert_eq!(PowerSeries::one(8) / geometric.clone(), one_minus_x.clone());
+    assert_eq!(geometric.clone() - geometric.clone(), PowerSeries::zero(8));
+    // Mixed precision keeps the smaller one
+    assert_eq!((geometric + one_minus_x.truncate(3)).precision(), 3);
+    assert_eq!(
+        PowerSeries::new(vec![1, 2], 3).to_string(),
+        "1 + 2 x + 0 x^2 + O(x^3)"
+    );
+}
+
+#[test]
+fn test_hadamard_product() {
+    let a = expand(&poly(&[1]), &poly(&[1, -2]), 12);
+    let b = expand(&poly(&[1]), &poly(&[1, -3]), 12);
+    assert_eq!(
+        hadamard_product(&a, &b),
+        expand(&poly(&[1]), &poly(&[1, -6]), 12)
+    );
+}
+
+#[test]
+fn test_binomial_transform() {
+    // Binomial transform of F_n is F_2n
+    let fib = (0..20).map(|n| F::new(fibonacci(n))).collect::<Vec<_>>();
+    let transformed = binomial_transform(&fib);
+    for (n, t) in transformed.iter().enumerate() {
+        assert_eq!(t.value(), fibonacci(2 * n as u64));
+    }
+    assert_eq!(inverse_binomial_transform(&transformed), fib);
+
+    // Binomial transform of the all-ones sequence is 2^n
+    let ones = vec![Rational::integer(1); 10];
+    let powers = binomial_transform(&ones);
+    assert_eq!(powers[9], Rational::integer(512));
+    assert_eq!(inverse_binomial_transform(&powers), ones);
+}
+
+#[test]
+fn test_power_series_composition() {
+    // 1 / (1 - x) composed with x / (1 + x) is 1 + x
+    let geometric = PowerSeries::from_polynomial(&poly(&[1, -1]), 12).inverse();
+    let g = PowerSeries::from_polynomial(&poly(&[0, 1]), 12)
+        * PowerSeries::from_polynomial(&poly(&[1, 1]), 12).inverse();
+    assert_eq!(
+        geometric.compose(&g),
+        PowerSeries::from_polynomial(&poly(&[1, 1]), 12)
+    );
+
+    // Composition with a polynomial agrees with evaluation of polynomials
+    let f = poly(&[3, 1, 4, 1, 5, 9, 2, 6]);
+    let h = poly(&[0, 2, 7, 1, 8]);
+    let expected = f.coeffs().iter().rev().fold(Polynomial::zero(), |acc, c| {
+        &(&acc * &h) + &Polynomial::from_coeffs(vec![*c])
+    });
+    let composed =
+        PowerSeries::from_polynomial(&f, 20).compose(&PowerSeries::from_polynomial(&h, 20));
+    assert_eq!(composed, PowerSeries::from_polynomial(&expected, 20));
+
+    // Precision is the smaller one
+    assert_eq!(geometric.compose(&g.truncate(5)).precision(), 5);
+}
+
+#[test]
+fn test_power_series_reversion() {
+    // x - x^2 reverses to the Catalan series sum C_{k-1} x^k
+    let f = PowerSeries::from_polynomial(&poly(&[0, 1, -1]), 15);
+    let g = f.reverse();
+    let catalan = [
+        1u64, 1, 2, 5, 14, 42, 132, 429, 1430, 4862, 16796, 58786, 208012, 742900,
+    ];
+    assert_eq!(g.coeffs()[0], F::new(0));
+    assert_eq!(&g.coeffs()[1..], catalan.map(F::new).as_slice());
+
+    let x = PowerSeries::from_polynomial(&poly(&[0, 1]), 15);
+    assert_eq!(f.compose(&g), x);
+    assert_eq!(g.compose(&f), x);
+    assert_eq!(g.reverse(), f);
+
+    // x e^{-x} reverses to the tree function T(x) = sum k^{k-1} x^k / k!, counting rooted labelled trees
+    let n = 10;
+    let mut factorial = Rational::from(1);
+    let mut coeffs = vec![Rational::from(0)];
+    for k in 0..n - 1 {
+        let sign = if k % 2 == 0 { 1 } else { -1 };
+        coeffs.push(Rational::new(sign, 1) / factorial);
+        factorial *= Rational::from(k as i128 + 1);
+    }
+    let tree = PowerSeries::new(coeffs, n).reverse();
+    let mut factorial = 1i128;
+    for k in 1..n {
+        factorial *= k as i128;
+        let k = k as i128;
+        assert_eq!(
+            *tree.coeff(k as usize),
+            Rational::new(k.pow(k as u32 - 1), factorial)
+        );
+    }
+}
@@ -0,0 +1,134 @@
+use algebra::{
+    coding::goppa::{GoppaCode, GoppaError},
+    field::finite_field::binary::Gf2e,
+    polynomial::Polynomial,
+    ring::RingBase,
+};
+
+/// Deterministic xorshift generator
+struct XorShift(u64);
+
+impl XorShift {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn bits(&mut self, n: usize) -> Vec<bool> {
+        (0..n).map(|_| self.next() & 1 == 1).collect()
+    }
+
+    /// k distinct positions below n
+    fn positions(&mut self, n: usize, k: usize) -> Vec<usize> {
+        let mut positions = Vec::new();
+        while positions.len() < k {
+            let p = (self.next() % n as u64) as usize;
+            if !positions.contains(&p) {
+                positions.push(p);
+            }
+        }
+        positions
+    }
+}
+
+/// First irreducible g(x) = x^t + x^{t-1} + b x + c found, as a Goppa code with full support
+/// The x^{t-1} term avoids affine polynomials x^{2^k} + b x + c, which are almost never irreducible.
+fn code<const M: u32>(t: usize) -> GoppaCode<M> {
+    (1..1 << M)
+        .flat_map(|b| (1..1 << M).map(move |c| (b, c)))
+        .find_map(|(b, c)| {
+            let mut coeffs = vec![Gf2e::<M>::zero(); t + 1];
+            coeffs[t - 1] = Gf2e::one();
+            coeffs[0] = Gf2e::new(c);
+            coeffs[1] = Gf2e::new(b);
+            coeffs[t] = Gf2e::one();
+            GoppaCode::full_support(Polynomial::from_coeffs(coeffs)).ok()
+        })
+        .expect("Some irreducible polynomial of this shape exists")
+}
+
+fn check_code<const M: u32>(t: usize, seed: u64) {
+    let code = code::<M>(t);
+    let n = code.length();
+    let k = code.dimension();
+    assert_eq!(n, 1 << M);
+    assert_eq!(code.correctable_errors(), t);
+    assert!(k >= n - M as usize * t);
+
+    let mut rng = XorShift(seed);
+    for _ in 0..10 {
+        let message = rng.bits(k);
+        let codeword = code.encode(&message);
+        assert!(code
+            .parity_check_matrix()
+            .mul_vector(&pack(&codeword))
+            .iter()
+            .all(|&b| !b));
+        assert!(code.syndrome(&codeword).degree().is_none());
+
+        for errors in 0..=t {
+            let mut received = codeword.clone();
+            let mut positions = rng.positions(n, errors);
+            for &p in &positions {
+                received[p] ^= true;
+            }
+            positions.sort_unstable();
+            assert_eq!(code.error_positions(&received), Some(positions));
+            assert_eq!(code.decode(&received), Some(codeword.clone()));
+            assert_eq!(code.decode_message(&received), Some(message.clone()));
+        }
+    }
+}
+
+fn pack(bits: &[bool]) -> Vec<u64> {
+    bits.chunks(64)
+        .map(|chunk| chunk.iter().rev().fold(0, |acc, &b| acc << 1 | b as u64))
+        .collect()
+}
+
+#[test]
+fn test_small_goppa_code() {
+    // Classic [16, 8] code with g(x) = x^2 + x + a^3 over GF(16)
+    let a3 = Gf2e::<4>::x().pow(3);
+    let g = Polynomial::from_coeffs(vec![a3, Gf2e::one(), Gf2e::one()]);
+    let code = GoppaCode::full_support(g).unwrap();
+    assert_eq!((code.length(), code.dimension()), (16, 8));
+    check_code::<4>(2, 1);
+}
+
+#[test]
+fn test_goppa_decoding() {
+    check_code::<5>(3, 7);
+    check_code::<6>(5, 11);
+    check_code::<8>(8, 13);
+}
+
+#[test]
+fn test_invalid_parameters() {
+    let one = Gf2e::<4>::one();
+    // x^2 + 1 = (x + 1)^2
+    let reducible = Polynomial::from_coeffs(vec![one, Gf2e::zero(), one]);
+    assert_eq!(
+        GoppaCode::full_support(reducible).err(),
+        Some(GoppaError::ReduciblePolynomial)
+    );
+    let constant = Polynomial::from_coeffs(vec![one]);
+    assert_eq!(
+        GoppaCode::full_support(constant).err(),
+        Some(GoppaError::ReduciblePolynomial)
+    );
+    // Linear polynomials are irreducible but vanish on their root
+    let linear = Polynomial::from_coeffs(vec![one, one]);
+    assert_eq!(
+        GoppaCode::full_support(linear).err(),
+        Some(GoppaError::SupportRoot)
+    );
+    let a3 = Gf2e::<4>::x().pow(3);
+    let g = Polynomial::from_coeffs(vec![a3, one, one]);
+    assert_eq!(
+        GoppaCode::new(g, vec![one, one]).err(),
+        Some(GoppaError::RepeatedSupport)
+    );
+}
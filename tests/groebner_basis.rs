@@ -0,0 +1,133 @@
+use algebra::{
+    field::finite_field::Fp,
+    polynomial::multivariate::{
+        groebner::{divide, groebner_basis},
+        ideal::Ideal,
+        monomial::Monomial,
+        ordering::{GRevLex, Lex, MonomialOrder},
+        MultiPoly,
+    },
+};
+
+type F = Fp<32003>;
+
+fn poly<O: MonomialOrder>(nvars: usize, terms: &[(&[u32], i64)], order: O) -> MultiPoly<F, O> {
+    MultiPoly::from_terms(
+        nvars,
+        terms
+            .iter()
+            .map(|(exps, c)| {
+                (
+                    Monomial::new(exps.to_vec()),
+                    F::new(c.rem_euclid(32003) as u64),
+                )
+            })
+            .collect(),
+        order,
+    )
+}
+
+#[test]
+fn test_division_identity() {
+    // f = x^2 y + x y^2 + y^2 divided by (x y - 1, y^2 - 1)
+    let f = poly(2, &[(&[2, 1], 1), (&[1, 2], 1), (&[0, 2], 1)], Lex);
+    let divisors = [
+        poly(2, &[(&[1, 1], 1), (&[0, 0], -1)], Lex),
+        poly(2, &[(&[0, 2], 1), (&[0, 0], -1)], Lex),
+    ];
+    let (quotients, remainder) = divide(&f, &divisors);
+
+    // Cox, Little, O'Shea: r = x + y + 1
+    assert_eq!(
+        remainder,
+        poly(2, &[(&[1, 0], 1), (&[0, 1], 1), (&[0, 0], 1)], Lex)
+    );
+    let recombined = quotients
+        .iter()
+        .zip(&divisors)
+        .fold(remainder, |acc, (q, g)| &acc + &(q * g));
+    assert_eq!(recombined, f);
+}
+
+#[test]
+fn test_groebner_basis_lex_elimination() {
+    // I = <x^2 + y^2 + z^2 - 1, x^2 + z^2 - y, x - z>
+    let gens = vec![
+        poly(
+            3,
+            &[
+                (&[2, 0, 0], 1),
+                (&[0, 2, 0], 1),
+                (&[0, 0, 2], 1),
+                (&[0, 0, 0], -1),
+            ],
+            Lex,
+        ),
+        poly(
+            3,
+            &[(&[2, 0, 0], 1), (&[0, 0, 2], 1), (&[0, 1, 0], -1)],
+            Lex,
+        ),
+        poly(3, &[(&[1, 0, 0], 1), (&[0, 0, 1], -1)], Lex),
+    ];
+    let basis = groebner_basis(&gens);
+
+    // Reduced basis: x - z, y - 2 z^2, z^4 + z^2 / 2 - 1/4
+    let half = F::new(1) / F::new(2);
+    let quarter = half * half;
+    assert_eq!(basis.len(), 3);
+    assert_eq!(basis[0], poly(3, &[(&[1, 0, 0], 1), (&[0, 0, 1], -1)], Lex));
+    assert_eq!(basis[1], poly(3, &[(&[0, 1, 0], 1), (&[0, 0, 2], -2)], Lex));
+    assert_eq!(
+        basis[2],
+        MultiPoly::from_terms(
+            3,
+            vec![
+                (Monomial::new(vec![0, 0, 4]), F::new(1)),
+                (Monomial::new(vec![0, 0, 2]), half),
+                (Monomial::new(vec![0, 0, 0]), -quarter),
+            ],
+            Lex
+        )
+    );
+}
+
+#[test]
+fn test_ideal_membership() {
+    // I = <x^3 - 2 x y, x^2 y - 2 y^2 + x>
+    let f1 = poly(2, &[(&[3, 0], 1), (&[1, 1], -2)], GRevLex);
+    let f2 = poly(2, &[(&[2, 1], 1), (&[0, 2], -2), (&[1, 0], 1)], GRevLex);
+    let ideal = Ideal::new(vec![f1.clone(), f2.clone()]);
+
+    // Any combination of the generators is a member
+    let x = MultiPoly::var(2, 0, GRevLex);
+    let y = MultiPoly::var(2, 1, GRevLex);
+    let member = &(&x * &f1) + &(&(&y * &y) * &f2);
+    assert!(ideal.contains(&member));
+    assert!(ideal.contains(&f1));
+
+    // x^2 is in I since x^2 = x f2 - y f1, but x is not
+    assert!(ideal.contains(&(&x * &x)));
+    assert!(!ideal.contains(&x));
+    assert!(!ideal.is_unit());
+
+    // Normal forms agree on cosets
+    let g = &(&y * &y) + &x;
+    assert_eq!(ideal.normal_form(&(&g + &member)), ideal.normal_form(&g));
+
+    // A precomputed basis gives the same answers
+    let precomputed = Ideal::from_groebner_basis(ideal.groebner_basis().to_vec());
+    assert!(precomputed.contains(&member));
+    assert!(!precomputed.contains(&x));
+}
+
+#[test]
+fn test_unit_ideal() {
+    // x y - 1 and x have no common zero
+    let ideal = Ideal::new(vec![
+        poly(2, &[(&[1, 1], 1), (&[0, 0], -1)], GRevLex),
+        poly(2, &[(&[1, 0], 1)], GRevLex),
+    ]);
+    assert!(ideal.is_unit());
+    assert_eq!(ideal.groebner_basis().len(), 1);
+}
@@ -0,0 +1,134 @@
+use algebra::{
+    field::finite_field::Fp,
+    group::{discrete_log::baby_step_giant_step, multiplicative::FpUnit, AbelianGroup, Group},
+    magma::{Addition, Associative, Loop, Magma, Multiplication},
+};
+
+/// Product of a sequence in any abelian group, independent of the order of the factors
+fn product<Op, G>(items: &[G]) -> G
+where
+    Op: Associative,
+    G: AbelianGroup<Op>,
+{
+    items
+        .iter()
+        .cloned()
+        .fold(G::identity(), |acc, a| Magma::<Op>::op(acc, a))
+}
+
+#[test]
+fn test_additive_group_power_and_inverse() {
+    assert_eq!(Group::<Addition>::invert(5_i64), -5);
+    assert_eq!(Group::<Addition>::power(7_i64, 6), 42);
+    assert_eq!(Group::<Addition>::power(7_i64, -3), -21);
+    assert_eq!(Group::<Addition>::power(Fp::<13>::new(4), 5), Fp::new(7));
+    assert_eq!(Group::<Addition>::invert(Fp::<13>::new(4)), Fp::new(9));
+    assert_eq!(product::<Addition, _>(&[1_i64, 2, 3]), 6);
+}
+
+#[test]
+fn test_fp_unit_group_axioms() {
+    type U = FpUnit<13>;
+    assert!(U::try_from(Fp::new(0)).is_err());
+    for a in U::elements() {
+        assert_eq!(Group::<Multiplication>::invert(a) * a, U::new(1));
+        assert_eq!(Magma::<Multiplication>::op(a, Loop::identity()), a);
+        assert_eq!(Group::<Multiplication>::power(a, -1), a.inverse());
+        assert_eq!(Group::<Multiplication>::power(a, 12), U::new(1));
+    }
+    assert_eq!(
+        product::<Multiplication, _>(&U::elements().collect::<Vec<_>>()),
+        U::new(12)
+    );
+}
+
+#[test]
+fn test_fp_unit_order_and_generator() {
+    type U = FpUnit<41>;
+    for a in U::elements() {
+        let k = a.order();
+        assert!(U::ORDER.is_multiple_of(k));
+        assert_eq!(a.element().pow(k), Fp::new(1));
+        assert_eq!(a.subgroup().len() as u64, k);
+    }
+    let g = U::generator();
+    assert_eq!(g, U::new(6));
+    assert!(g.is_generator());
+    assert!(!U::new(2).is_generator());
+    assert_eq!(U::elements().filter(U::is_generator).count(), 16);
+}
+
+#[test]
+fn test_fp_unit_subgroups() {
+    type U = FpUnit<31>;
+    for d in [1, 2, 3, 5, 6, 10, 15, 30] {
+        let h = U::subgroup_generator(d).unwrap();
+        assert_eq!(h.order(), d);
+        let subgroup = h.subgroup();
+        assert_eq!(subgroup.len() as u64, d);
+        // The unique subgroup of order d is the set of solutions of x^d = 1
+        let solutions = U::elements()
+            .filter(|x| x.element().pow(d) == Fp::new(1))
+            .count();
+        assert_eq!(solutions as u64, d);
+        assert!(subgroup.iter().all(|x| x.element().pow(d) == Fp::new(1)));
+    }
+    assert_eq!(U::subgroup_generator(4), None);
+    assert_eq!(U::subgroup_generator(0), None);
+}
+
+#[test]
+fn test_discrete_log_small() {
+    type U = FpUnit<101>;
+    let g = U::generator();
+    for k in 0..U::ORDER {
+        let h = Group::<Multiplication>::power(g, k as i64);
+        assert_eq!(h.discrete_log(&g), Some(k));
+    }
+
+    // 3 is not a power of an element of order 4
+    let i = U::subgroup_generator(4).unwrap();
+    assert_eq!(U::new(3).discrete_log(&i), None);
+    assert_eq!(U::new(100).discrete_log(&i), Some(2));
+}
+
+#[test]
+fn test_discrete_log_large_prime() {
+    type U = FpUnit<1_000_000_007>;
+    let g = U::generator();
+    assert_eq!(g, U::new(5));
+    for k in [0, 1, 31_622, 123_456_789, 1_000_000_005] {
+        let h = U::new(g.element().pow(k).value());
+        assert_eq!(h.discrete_log(&g), Some(k));
+    }
+}
+
+#[test]
+fn test_baby_step_giant_step_additive() {
+    // In (Z/97, +) the discrete logarithm is division
+    let g = Fp::<97>::new(5);
+    let h = Fp::<97>::new(42);
+    let k = baby_step_giant_step::<Addition, _>(&g, &h, 97).unwrap();
+    assert_eq!(g * Fp::new(k), h);
+    assert_eq!(baby_step_giant_step::<Addition, _>(&g, &h, 0), None);
+}
+
+#[test]
+fn test_diffie_hellman() {
+    type U = FpUnit<2_147_483_647>;
+    let g = U::generator();
+    let (a, b) = (1_234_567_i64, 7_654_321_i64);
+
+    // Public keys g^a and g^b give the same shared secret g^{ab} to both parties
+    let public_a = Group::<Multiplication>::power(g, a);
+    let public_b = Group::<Multiplication>::power(g, b);
+    let shared = Group::<Multiplication>::power(public_b, a);
+    assert_eq!(shared, Group::<Multiplication>::power(public_a, b));
+
+    // A small prime modulus does not hide the private keys
+    let recovered = public_a.discrete_log(&g).unwrap();
+    assert_eq!(
+        Group::<Multiplication>::power(public_b, recovered as i64),
+        shared
+    );
+}
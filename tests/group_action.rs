@@ -0,0 +1,135 @@
+use algebra::{
+    field::finite_field::{
+        binary::Gf2e,
+        extension::{Aes, FpExt},
+        Fp,
+    },
+    group::{
+        action::{
+            orbit, stabilizer, Action, Evaluate, Frobenius, Galois, LeftMultiplication, Permute,
+        },
+        permutation::Permutation,
+    },
+};
+
+/// Every element of S_3
+fn s3() -> Vec<Permutation> {
+    [
+        [0, 1, 2],
+        [0, 2, 1],
+        [1, 0, 2],
+        [1, 2, 0],
+        [2, 0, 1],
+        [2, 1, 0],
+    ]
+    .into_iter()
+    .map(|images| Permutation::new(images.to_vec()))
+    .collect()
+}
+
+#[test]
+fn test_permutation_action_laws() {
+    let x = vec!['a', 'b', 'c'];
+    let e = Permutation::identity(3);
+    assert_eq!(Permute::act(&e, &x), x);
+
+    for g in s3() {
+        for h in s3() {
+            let gh = g.compose(&h);
+            assert_eq!(
+                Permute::act(&gh, &x),
+                Permute::act(&g, &Permute::act(&h, &x))
+            );
+            assert_eq!(
+                Evaluate::act(&gh, &1),
+                Evaluate::act(&g, &Evaluate::act(&h, &1))
+            );
+        }
+        assert_eq!(g.compose(&g.inverse()), e);
+    }
+}
+
+#[test]
+fn test_orbit_stabilizer() {
+    // Orbit-stabilizer: |G| = |orbit| |stabilizer|
+    let x = vec![0, 0, 1];
+    let generators = [
+        Permutation::from_cycles(3, &[&[0, 1]]),
+        Permutation::from_cycles(3, &[&[0, 1, 2]]),
+    ];
+    let orbit = orbit::<Permute, _, _>(&generators, &x);
+    let stabilizer = stabilizer::<Permute, _, _>(&s3(), &x);
+    assert_eq!(orbit.len(), 3);
+    assert_eq!(stabilizer.len(), 2);
+    assert!(stabilizer.contains(&Permutation::from_cycles(3, &[&[0, 1]])));
+}
+
+#[test]
+fn test_multiplicative_orbit() {
+    // The orbit of 1 under multiplication by 2 in F_7 is the subgroup {1, 2, 4}
+    type F7 = Fp<7>;
+    let orbit = orbit::<LeftMultiplication, _, _>(&[F7::new(2)], &F7::new(1));
+    assert_eq!(orbit, vec![F7::new(1), F7::new(2), F7::new(4)]);
+}
+
+#[test]
+fn test_galois_action_on_extension_field() {
+    type GF256 = FpExt<2, 8, Aes>;
+    let elements = (0..=255u64)
+        .map(|b| GF256::new(std::array::from_fn(|k| Fp::new((b >> k) & 1))))
+        .collect::<Vec<_>>();
+    let (g, h) = (Frobenius::new(3, 8), Frobenius::new(6, 8));
+
+    for (a, b) in elements.iter().zip(elements.iter().rev()) {
+        // Action laws, with σ^8 = e
+        assert_eq!(Galois::act(&Frobenius::new(0, 8), a), *a);
+        assert_eq!(Galois::act(&Frobenius::new(8, 8), a), *a);
+        assert_eq!(
+            Galois::act(&(g * h), a),
+            Galois::act(&g, &Galois::act(&h, a))
+        );
+
+        // Each σ^k is a field automorphism
+        assert_eq!(
+            Galois::act(&g, &(*a * *b)),
+            Galois::act(&g, a) * Galois::act(&g, b)
+        );
+        assert_eq!(
+            Galois::act(&g, &(*a + *b)),
+            Galois::act(&g, a) + Galois::act(&g, b)
+        );
+    }
+
+    // Orbits are the sets of conjugates, of size dividing 8, and only F2 is fixed
+    let x = GF256::x();
+    assert_eq!(orbit::<Galois, _, _>(&[Frobenius::new(1, 8)], &x).len(), 8);
+    let fixed = elements
+        .iter()
+        .filter(|a| orbit::<Galois, _, _>(&[Frobenius::new(1, 8)], *a).len() == 1)
+        .count();
+    assert_eq!(fixed, 2);
+}
+
+#[test]
+fn test_galois_action_on_binary_field() {
+    // The elements of GF(16) fixed by σ^2 form the subfield GF(4)
+    let group = Frobenius::elements(4).collect::<Vec<_>>();
+    let subfield = Gf2e::<4>::elements()
+        .filter(|a| Galois::act(&Frobenius::new(2, 4), a) == *a)
+        .collect::<Vec<_>>();
+    assert_eq!(subfield.len(), 4);
+    for a in &subfield {
+        assert!(stabilizer::<Galois, _, _>(&group, a).contains(&Frobenius::new(2, 4)));
+    }
+    for a in Gf2e::<4>::elements() {
+        assert_eq!(Galois::act(&Frobenius::new(1, 4), &a), a.frobenius());
+        assert_eq!(Galois::act(&Frobenius::new(5, 4), &a), a.frobenius());
+    }
+
+    // Powers stay reduced modulo the degree, so products never overflow
+    let top = Frobenius::new(u64::MAX, 4);
+    assert_eq!(top, Frobenius::new(3, 4));
+    assert_eq!(top * top, Frobenius::new(2, 4));
+    let big = Frobenius::new(u32::MAX as u64 - 1, u32::MAX);
+    assert_eq!((big * big).power(), u32::MAX - 2);
+}
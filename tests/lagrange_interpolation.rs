@@ -1,4 +1,7 @@
-use algebra::polynomial::lagrange::lagrange_interpolation;
+use algebra::{
+    field::finite_field::{montgomery::FpMont, Fp},
+    polynomial::lagrange::{lagrange_interpolation, lagrange_interpolation_fast},
+};
 use common::MyF64;
 
 mod common;
@@ -19,3 +22,35 @@ fn test_lagrange_f64() {
     let val = poly.evaluate(&3.0);
     assert!((val - 10.0_f64).abs() < 1e-6);
 }
+
+#[test]
+fn test_lagrange_fast_matches() {
+    let points = (1..=30_u64)
+        .map(|x| {
+            (
+                Fp::<1_000_000_007>::new(x * x + 3),
+                Fp::new(x.pow(5) + 7 * x),
+            )
+        })
+        .collect::<Vec<_>>();
+    let poly = lagrange_interpolation_fast(&points);
+    assert_eq!(poly, lagrange_interpolation(&points));
+    assert!(points.iter().all(|(x, y)| poly.evaluate(x) == *y));
+
+    let points = points
+        .iter()
+        .map(|(x, y)| {
+            (
+                FpMont::<1_000_000_007>::new(x.value()),
+                FpMont::new(y.value()),
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        lagrange_interpolation_fast(&points),
+        lagrange_interpolation(&points)
+    );
+    assert!(lagrange_interpolation_fast::<Fp<7>>(&[])
+        .coeffs()
+        .is_empty());
+}
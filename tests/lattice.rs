@@ -0,0 +1,50 @@
+use std::collections::BTreeSet;
+
+use algebra::lattice::{join_all, lcm, meet_all, BoundedAbove, BoundedBelow, Lattice};
+
+/// Check the lattice axioms on every triple of the given elements
+fn check_lattice_laws<L: Lattice + Clone + std::fmt::Debug>(elements: &[L]) {
+    for a in elements {
+        assert_eq!(a.meet(a), *a);
+        assert_eq!(a.join(a), *a);
+        for b in elements {
+            assert_eq!(a.meet(b), b.meet(a));
+            assert_eq!(a.join(b), b.join(a));
+            // Absorption
+            assert_eq!(a.meet(&a.join(b)), *a);
+            assert_eq!(a.join(&a.meet(b)), *a);
+            for c in elements {
+                assert_eq!(a.meet(&b.meet(c)), a.meet(b).meet(c));
+                assert_eq!(a.join(&b.join(c)), a.join(b).join(c));
+            }
+        }
+    }
+}
+
+#[test]
+fn test_divisibility_lattice() {
+    check_lattice_laws(&(0..=30).collect::<Vec<u64>>());
+    assert_eq!(12_u64.meet(&18), 6);
+    assert_eq!(12_u64.join(&18), 36);
+    assert!(4_u64.leq(&12));
+    assert!(!4_u64.leq(&6));
+    assert!(u64::bottom().leq(&7) && 7_u64.leq(&u64::top()));
+
+    assert_eq!(join_all([4_u64, 6, 10]), 60);
+    assert_eq!(meet_all([12_u64, 18, 30]), 6);
+    assert_eq!(lcm(0, 5), 0);
+}
+
+#[test]
+fn test_boolean_and_set_lattices() {
+    check_lattice_laws(&[false, true]);
+    assert!(meet_all(Vec::<bool>::new()));
+
+    let sets = [vec![], vec![1], vec![2], vec![1, 2], vec![2, 3]]
+        .into_iter()
+        .map(BTreeSet::from_iter)
+        .collect::<Vec<BTreeSet<i32>>>();
+    check_lattice_laws(&sets);
+    assert_eq!(join_all(sets.clone()), BTreeSet::from([1, 2, 3]));
+    assert!(sets[1].leq(&sets[3]));
+}
@@ -0,0 +1,91 @@
+use algebra::number::{is_prime, is_strong_lucas_prp, jacobi, lucas_sequence, pow_mod};
+
+/// Lucas sequences by the defining recurrence
+fn recurrence(p: i64, q: i64, n: u64, m: u64) -> (u64, u64) {
+    let m = m as i128;
+    let (p, q) = (p as i128, q as i128);
+    let (mut u, mut u1, mut v, mut v1) = (0, 1, 2, p);
+    for _ in 0..n {
+        (u, u1) = (u1, (p * u1 - q * u).rem_euclid(m));
+        (v, v1) = (v1, (p * v1 - q * v).rem_euclid(m));
+    }
+    (u.rem_euclid(m) as u64, v.rem_euclid(m) as u64)
+}
+
+#[test]
+fn test_lucas_sequence_matches_recurrence() {
+    for (p, q) in [(1, -1), (3, 2), (-2, 5), (4, 4), (0, -3)] {
+        for m in [1, 2, 10, 97, 1_000_000_007] {
+            for n in 0..60 {
+                assert_eq!(
+                    lucas_sequence(p, q, n, m),
+                    recurrence(p, q, n, m),
+                    "P = {p}, Q = {q}, n = {n}, m = {m}"
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_lucas_sequence_identities() {
+    let m = 1_000_000_007;
+    // Fibonacci and Lucas numbers
+    assert_eq!(
+        lucas_sequence(1, -1, 90, u64::MAX),
+        (2_880_067_194_370_816_120, 6_440_026_026_380_244_498)
+    );
+    // U_n(3, 2) = 2^n - 1 and V_n(3, 2) = 2^n + 1
+    let n = 1_000_000_000_000;
+    let two_n = pow_mod(2, n, m);
+    assert_eq!(
+        lucas_sequence(3, 2, n, m),
+        ((two_n + m - 1) % m, (two_n + 1) % m)
+    );
+    // V_n^2 - D U_n^2 = 4 Q^n with D = P^2 - 4Q
+    let (p, q) = (7_i64, 3_i64);
+    let d = (p * p - 4 * q) as u64;
+    let (u, v) = lucas_sequence(p, q, n, m);
+    let lhs = ((v as u128 * v as u128) % m as u128 + m as u128 * m as u128
+        - (d as u128 * u as u128 % m as u128 * u as u128) % m as u128)
+        % m as u128;
+    assert_eq!(lhs as u64, 4 * pow_mod(3, n, m) % m);
+}
+
+#[test]
+fn test_jacobi_symbol() {
+    // Euler's criterion for primes
+    for p in [3_u64, 5, 7, 11, 101, 997] {
+        for a in -20_i64..50 {
+            let euler = pow_mod(a.rem_euclid(p as i64) as u64, (p - 1) / 2, p);
+            let expected = match euler {
+                0 => 0,
+                1 => 1,
+                _ => -1,
+            };
+            assert_eq!(jacobi(a, p), expected, "a = {a}, p = {p}");
+        }
+    }
+    // Multiplicative in the modulus
+    for a in -30_i64..30 {
+        assert_eq!(jacobi(a, 15), jacobi(a, 3) * jacobi(a, 5));
+        assert_eq!(jacobi(a, 1), 1);
+    }
+}
+
+#[test]
+fn test_strong_lucas_probable_primes() {
+    // Strong Lucas pseudoprimes below 60000 (OEIS A217255)
+    let pseudoprimes = [
+        5459, 5777, 10877, 16109, 18971, 22499, 24569, 25199, 40309, 58519,
+    ];
+    for n in 0..60_000 {
+        assert_eq!(
+            is_strong_lucas_prp(n),
+            is_prime(n) || pseudoprimes.contains(&n),
+            "n = {n}"
+        );
+    }
+    assert!(is_strong_lucas_prp((1 << 61) - 1));
+    assert!(!is_strong_lucas_prp(((1 << 31) - 1) * ((1 << 31) - 1)));
+}
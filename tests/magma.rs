@@ -0,0 +1,61 @@
+use algebra::{
+    field::finite_field::Fp,
+    magma::{
+        Addition, Associative, Commutative, Idempotent, Loop, Magma, Max, Min, Quasigroup,
+        Semilattice, Subtraction,
+    },
+};
+
+/// Fold a non-empty sequence with a semilattice operation
+fn fold_semilattice<Op, T>(items: Vec<T>) -> T
+where
+    Op: Associative + Commutative + Idempotent,
+    T: Semilattice<Op>,
+{
+    items
+        .into_iter()
+        .reduce(|a, b| Magma::<Op>::op(a, b))
+        .unwrap()
+}
+
+#[test]
+fn test_min_max_semilattices() {
+    let items = vec![3, -1, 7, 7, 2];
+    assert_eq!(fold_semilattice::<Min, _>(items.clone()), -1);
+    assert_eq!(fold_semilattice::<Max, _>(items.clone()), 7);
+
+    // Idempotence and commutativity
+    for a in -3..3 {
+        assert_eq!(Magma::<Min>::op(a, a), a);
+        for b in -3..3 {
+            assert_eq!(Magma::<Max>::op(a, b), Magma::<Max>::op(b, a));
+        }
+    }
+}
+
+#[test]
+fn test_quasigroup_division() {
+    type F7 = Fp<7>;
+    for a in 0..7 {
+        for b in 0..7 {
+            let (a, b) = (F7::new(a), F7::new(b));
+
+            let x = Quasigroup::<Addition>::left_div(a, b);
+            let y = Quasigroup::<Addition>::right_div(a, b);
+            assert_eq!(Magma::<Addition>::op(a, x), b);
+            assert_eq!(Magma::<Addition>::op(y, a), b);
+
+            let x = Quasigroup::<Subtraction>::left_div(a, b);
+            let y = Quasigroup::<Subtraction>::right_div(a, b);
+            assert_eq!(Magma::<Subtraction>::op(a, x), b);
+            assert_eq!(Magma::<Subtraction>::op(y, a), b);
+        }
+    }
+}
+
+#[test]
+fn test_loop_identity() {
+    let e = <i64 as Loop<Addition>>::identity();
+    assert_eq!(Magma::<Addition>::op(e, 5), 5);
+    assert_eq!(Magma::<Addition>::op(5, e), 5);
+}
@@ -0,0 +1,59 @@
+use algebra::matrix::Matrix;
+
+fn m(rows: &[&[i64]]) -> Matrix<i64> {
+    Matrix::from_rows(rows.iter().map(|row| row.to_vec()).collect())
+}
+
+#[test]
+fn test_from_blocks_and_views() {
+    let a = m(&[&[1, 2], &[3, 4]]);
+    let b = m(&[&[5], &[6]]);
+    let c = m(&[&[7, 8]]);
+    let d = m(&[&[9]]);
+
+    let full = Matrix::from_blocks(&[&[&a, &b], &[&c, &d]]);
+    assert_eq!(full, m(&[&[1, 2, 5], &[3, 4, 6], &[7, 8, 9]]));
+
+    // Views recover the blocks
+    assert_eq!(full.block(0..2, 0..2).to_matrix(), a);
+    assert_eq!(full.block(0..2, 2..3).to_matrix(), b);
+    assert_eq!(full.block(2..3, 0..2).to_matrix(), c);
+    let view = full.block(1..3, 1..3);
+    assert_eq!((view.rows(), view.cols()), (2, 2));
+    assert_eq!(view[(1, 0)], 8);
+    assert_eq!(view.row(0), &[4, 6]);
+}
+
+#[test]
+fn test_concatenation() {
+    let a = m(&[&[1, 2], &[3, 4]]);
+    let i = Matrix::identity(2);
+    assert_eq!(a.concat_horizontal(&i), m(&[&[1, 2, 1, 0], &[3, 4, 0, 1]]));
+    assert_eq!(
+        a.concat_vertical(&i),
+        m(&[&[1, 2], &[3, 4], &[1, 0], &[0, 1]])
+    );
+
+    let mut z = Matrix::zeros(3, 3);
+    z.set_block(1, 1, &a);
+    assert_eq!(z, m(&[&[0, 0, 0], &[0, 1, 2], &[0, 3, 4]]));
+}
+
+#[test]
+fn test_block_triangular_determinant() {
+    // det [[A, B], [0, D]] = det A det D
+    let a = m(&[&[2, 1], &[1, 3]]);
+    let b = m(&[&[4, -1, 0], &[2, 2, 2]]);
+    let d = m(&[&[1, 2, 0], &[0, 1, 5], &[3, 0, 1]]);
+    let zero = Matrix::zeros(3, 2);
+    let full = Matrix::from_blocks(&[&[&a, &b], &[&zero, &d]]);
+    assert_eq!(full.determinant(), a.determinant() * d.determinant());
+}
+
+#[test]
+#[should_panic]
+fn test_mismatched_blocks() {
+    let a = m(&[&[1, 2]]);
+    let b = m(&[&[1], &[2]]);
+    let _ = Matrix::from_blocks(&[&[&a, &b]]);
+}
@@ -0,0 +1,152 @@
+use algebra::{matrix::Matrix, number::Complex};
+
+const TOL: f64 = 1e-9;
+
+fn sorted(mut values: Vec<Complex>) -> Vec<Complex> {
+    values.sort_by(|a, b| a.re.total_cmp(&b.re).then(a.im.total_cmp(&b.im)));
+    values
+}
+
+fn assert_close(values: Option<Vec<Complex>>, expected: &[Complex]) {
+    let values = sorted(values.expect("QR iterations converge"));
+    let expected = sorted(expected.to_vec());
+    assert_eq!(values.len(), expected.len());
+    for (v, e) in values.iter().zip(&expected) {
+        assert!((*v - *e).abs() < TOL, "{v} != {e}");
+    }
+}
+
+fn real(values: &[f64]) -> Vec<Complex> {
+    values.iter().map(|&x| Complex::from(x)).collect()
+}
+
+#[test]
+fn test_hessenberg_form() {
+    let a = Matrix::from_fn(5, 5, |i, j| ((i * 7 + j * 3) % 11) as f64 - 5.0);
+    let h = a.hessenberg();
+    for i in 0usize..5 {
+        for j in 0..i.saturating_sub(1) {
+            assert_eq!(h[(i, j)], 0.0);
+        }
+    }
+    // Similarity preserves the trace
+    let trace = |m: &Matrix<f64>| (0..5).map(|i| m[(i, i)]).sum::<f64>();
+    assert!((trace(&a) - trace(&h)).abs() < TOL);
+}
+
+#[test]
+fn test_eigenvalues_triangular_and_symmetric() {
+    let a = Matrix::from_rows(vec![
+        vec![2.0, 1.0, 5.0],
+        vec![0.0, -3.0, 4.0],
+        vec![0.0, 0.0, 7.0],
+    ]);
+    assert_close(a.eigenvalues(), &real(&[-3.0, 2.0, 7.0]));
+
+    let s = Matrix::from_rows(vec![
+        vec![2.0, -1.0, 0.0],
+        vec![-1.0, 2.0, -1.0],
+        vec![0.0, -1.0, 2.0],
+    ]);
+    let r = 2f64.sqrt();
+    assert_close(s.eigenvalues(), &real(&[2.0 - r, 2.0, 2.0 + r]));
+}
+
+#[test]
+fn test_eigenvalues_complex_pairs() {
+    // Rotation by 90 degrees
+    let rot = Matrix::from_rows(vec![vec![0.0, -1.0], vec![1.0, 0.0]]);
+    assert_close(
+        rot.eigenvalues(),
+        &[Complex::new(0.0, 1.0), Complex::new(0.0, -1.0)],
+    );
+
+    // Cyclic permutation: cube roots of unity
+    let p = Matrix::from_rows(vec![
+        vec![0.0, 0.0, 1.0],
+        vec![1.0, 0.0, 0.0],
+        vec![0.0, 1.0, 0.0],
+    ]);
+    let h = 3f64.sqrt() / 2.0;
+    assert_close(
+        p.eigenvalues(),
+        &[
+            Complex::from(1.0),
+            Complex::new(-0.5, h),
+            Complex::new(-0.5, -h),
+        ],
+    );
+}
+
+#[test]
+fn test_eigenvalues_companion_matrix() {
+    // Companion matrix of (x - 1)(x - 2)(x - 3)(x^2 + 1) = x^5 - 6x^4 + 12x^3 - 12x^2 + 11x - 6
+    let coeffs = [-6.0, 11.0, -12.0, 12.0, -6.0];
+    let c = Matrix::from_fn(5, 5, |i, j| {
+        if j == 4 {
+            -coeffs[i]
+        } else if i == j + 1 {
+            1.0
+        } else {
+            0.0
+        }
+    });
+    let mut expected = real(&[1.0, 2.0, 3.0]);
+    expected.extend([Complex::new(0.0, 1.0), Complex::new(0.0, -1.0)]);
+    assert_close(c.eigenvalues(), &expected);
+}
+
+#[test]
+fn test_eigenvalue_sums() {
+    // Sum of eigenvalues is the trace, for a larger generic matrix
+    let n = 12;
+    let a = Matrix::from_fn(n, n, |i, j| {
+        (((i * 31 + j * 17 + i * j) % 23) as f64 - 11.0) / 7.0
+    });
+    let values = a.eigenvalues().unwrap();
+    assert_eq!(values.len(), n);
+    let sum = values.iter().fold(Complex::from(0.0), |acc, &v| acc + v);
+    let trace = (0..n).map(|i| a[(i, i)]).sum::<f64>();
+    assert!((sum.re - trace).abs() < 1e-8);
+    assert!(sum.im.abs() < 1e-8);
+}
+
+#[test]
+fn test_eigenvalues_zero_diagonal() {
+    // Nilpotent shift: the diagonal is zero, so only an absolute floor can deflate it
+    let n = 4;
+    let shift = Matrix::from_fn(n, n, |i, j| if i == j + 1 { 1.0 } else { 0.0 });
+    let values = shift.eigenvalues().unwrap();
+    assert_eq!(values.len(), n);
+    assert!(values.iter().all(|v| v.abs() < 1e-3));
+
+    // Zero diagonal and tiny subdiagonal: a relative deflation test never fires
+    let a = Matrix::from_fn(n, n, |i, j| {
+        if i == j + 1 {
+            1e-200
+        } else if j > i {
+            1.0
+        } else {
+            0.0
+        }
+    });
+    assert_close(a.eigenvalues(), &real(&[0.0; 4]));
+
+    // Tiny entries near the underflow threshold
+    let tiny = Matrix::from_rows(vec![vec![0.0, 1e-300], vec![1e-300, 0.0]]);
+    assert_close(tiny.eigenvalues(), &real(&[-1e-300, 1e-300]));
+    assert_eq!(
+        Matrix::<f64>::zeros(3, 3).eigenvalues(),
+        Some(real(&[0.0; 3]))
+    );
+}
+
+#[test]
+fn test_complex_arithmetic() {
+    let z = Complex::new(3.0, 4.0);
+    assert_eq!(z.abs(), 5.0);
+    assert_eq!(z * z.conj(), Complex::from(25.0));
+    assert_eq!(z / z, Complex::from(1.0));
+    assert_eq!(z.to_string(), "3 + 4 i");
+    assert_eq!(z.conj().to_string(), "3 - 4 i");
+}
@@ -0,0 +1,101 @@
+use algebra::{
+    field::finite_field::{reduce::ReduceMod, Fp},
+    group::action::{Action, Linear},
+    matrix::Matrix,
+    polynomial::Polynomial,
+};
+
+type F7 = Fp<7>;
+
+fn poly(coeffs: &[u64]) -> Polynomial<F7> {
+    Polynomial::from_coeffs(coeffs.iter().map(|c| F7::new(*c)).collect())
+}
+
+#[test]
+fn test_integer_determinant_and_rank() {
+    let a = Matrix::from_rows(vec![vec![2_i64, -1, 0], vec![-1, 2, -1], vec![0, -1, 2]]);
+    assert_eq!(a.determinant(), 4);
+    assert_eq!(a.rank(), 3);
+
+    // A zero leading entry forces a row swap
+    let b = Matrix::from_rows(vec![vec![0_i64, 1, 2], vec![3, 4, 5], vec![6, 7, 9]]);
+    assert_eq!(b.determinant(), -3);
+
+    let singular = Matrix::from_rows(vec![vec![1_i64, 2, 3], vec![2, 4, 6], vec![1, 0, 1]]);
+    assert_eq!(singular.determinant(), 0);
+    assert_eq!(singular.rank(), 2);
+}
+
+#[test]
+fn test_integer_smith_normal_form() {
+    let a = Matrix::from_rows(vec![vec![2_i64, 4, 4], vec![-6, 6, 12], vec![10, -4, -16]]);
+    assert_eq!(a.invariant_factors(), vec![2, 6, 12]);
+
+    let b = Matrix::from_rows(vec![vec![6_i64, 4], vec![4, 6], vec![2, 2]]);
+    assert_eq!(
+        b.smith_normal_form(),
+        Matrix::from_rows(vec![vec![2, 0], vec![0, 2], vec![0, 0]])
+    );
+}
+
+#[test]
+fn test_polynomial_matrix_determinant() {
+    // det [[x, 1], [1, x]] = x^2 - 1
+    let m = Matrix::from_rows(vec![
+        vec![poly(&[0, 1]), poly(&[1])],
+        vec![poly(&[1]), poly(&[0, 1])],
+    ]);
+    assert_eq!(m.determinant(), poly(&[6, 0, 1]));
+    assert_eq!(m.rank(), 2);
+
+    // Rows proportional over F_7(x)
+    let m = Matrix::from_rows(vec![
+        vec![poly(&[1, 1]), poly(&[0, 1])],
+        vec![poly(&[1, 2, 1]), poly(&[0, 1, 1])],
+    ]);
+    assert_eq!(m.determinant(), Polynomial::zero());
+    assert_eq!(m.rank(), 1);
+}
+
+#[test]
+fn test_similarity_invariants() {
+    // diag(2, 2, 3): invariants (x - 2) | (x - 2)(x - 3)
+    let a = Matrix::from_rows(vec![
+        vec![F7::new(2), F7::new(0), F7::new(0)],
+        vec![F7::new(0), F7::new(2), F7::new(0)],
+        vec![F7::new(0), F7::new(0), F7::new(3)],
+    ]);
+    let invariants = a.similarity_invariants();
+    assert_eq!(
+        invariants,
+        vec![poly(&[5, 1]), &poly(&[5, 1]) * &poly(&[4, 1])]
+    );
+    assert_eq!(
+        a.characteristic_polynomial(),
+        &invariants[0] * &invariants[1]
+    );
+
+    // Companion matrix of x^3 + 2x + 5 is cyclic: a single invariant factor
+    let c = Matrix::from_rows(vec![
+        vec![F7::new(0), F7::new(0), -F7::new(5)],
+        vec![F7::new(1), F7::new(0), -F7::new(2)],
+        vec![F7::new(0), F7::new(1), F7::new(0)],
+    ]);
+    assert_eq!(c.similarity_invariants(), vec![poly(&[5, 2, 0, 1])]);
+}
+
+#[test]
+fn test_matrix_reduction_and_action() {
+    let a = Matrix::from_rows(vec![vec![8_i64, -1], vec![3, 14]]);
+    let reduced = a.reduce_mod::<7>().unwrap();
+    assert_eq!(
+        reduced,
+        Matrix::from_rows(vec![
+            vec![F7::new(1), F7::new(6)],
+            vec![F7::new(3), F7::new(0)]
+        ])
+    );
+
+    let v = vec![F7::new(1), F7::new(2)];
+    assert_eq!(Linear::act(&reduced, &v), vec![F7::new(6), F7::new(3)]);
+}
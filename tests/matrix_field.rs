@@ -0,0 +1,101 @@
+use algebra::{
+    field::finite_field::{binary::Gf2e, Fp},
+    matrix::Matrix,
+    number::Rational,
+    ring::RingBase,
+};
+
+type F7 = Fp<7>;
+
+fn matrix(rows: &[&[u64]]) -> Matrix<F7> {
+    Matrix::from_rows(
+        rows.iter()
+            .map(|r| r.iter().map(|c| F7::new(*c)).collect())
+            .collect(),
+    )
+}
+
+fn vector(v: &[u64]) -> Vec<F7> {
+    v.iter().map(|c| F7::new(*c)).collect()
+}
+
+#[test]
+fn test_determinant_and_rank() {
+    let a = matrix(&[&[2, 6, 0], &[6, 2, 6], &[0, 6, 2]]);
+    // det = 2 (4 - 36) - 6 (12 - 0) = -136 = 4 (mod 7)
+    assert_eq!(a.determinant(), F7::new(4));
+    assert_eq!(a.rank(), 3);
+
+    // Third row is the sum of the first two
+    let singular = matrix(&[&[1, 2, 3], &[4, 5, 6], &[5, 0, 2]]);
+    assert_eq!(singular.determinant(), F7::zero());
+    assert_eq!(singular.rank(), 2);
+
+    // Singular over F7 but not over the integers: det = 7
+    let b = matrix(&[&[3, 1], &[1, 5]]);
+    assert_eq!(b.rank(), 1);
+}
+
+#[test]
+fn test_determinant_and_rank_rational() {
+    let q = |n: i128, d: i128| Rational::new(n, d);
+    let a = Matrix::from_rows(vec![
+        vec![q(1, 2), q(1, 3), q(0, 1)],
+        vec![q(2, 1), q(-1, 4), q(1, 1)],
+        vec![q(0, 1), q(3, 5), q(2, 3)],
+    ]);
+    // det = 1/2 (-1/6 - 3/5) - 1/3 (4/3 - 0) = -23/60 - 4/9 = -149/180
+    assert_eq!(a.determinant(), q(-149, 180));
+    assert_eq!(a.rank(), 3);
+
+    // Second row is 3/2 times the first
+    let singular = Matrix::from_rows(vec![
+        vec![q(2, 3), q(-1, 2), q(1, 1)],
+        vec![q(1, 1), q(-3, 4), q(3, 2)],
+        vec![q(1, 7), q(0, 1), q(5, 1)],
+    ]);
+    assert_eq!(singular.determinant(), Rational::zero());
+    assert_eq!(singular.rank(), 2);
+    assert_eq!(Matrix::<Rational>::zeros(2, 3).rank(), 0);
+}
+
+#[test]
+fn test_inverse() {
+    let a = matrix(&[&[0, 1, 2], &[3, 4, 5], &[6, 1, 1]]);
+    let inv = a.inverse().expect("Matrix is invertible");
+    assert_eq!(&a * &inv, Matrix::identity(3));
+    assert_eq!(&inv * &a, Matrix::identity(3));
+    assert_eq!(inv.inverse(), Some(a));
+
+    assert_eq!(matrix(&[&[1, 2], &[2, 4]]).inverse(), None);
+    assert_eq!(
+        Matrix::<F7>::identity(0).inverse(),
+        Some(Matrix::identity(0))
+    );
+
+    // Vandermonde matrix of distinct points of GF(2^8)
+    let x = Matrix::from_fn(4, 4, |i, j| Gf2e::<8>::new(i as u32 + 1).pow(j as u64));
+    assert_ne!(x.determinant(), Gf2e::zero());
+    assert_eq!(&x * &x.inverse().unwrap(), Matrix::identity(4));
+}
+
+#[test]
+fn test_solve() {
+    let a = matrix(&[&[1, 2, 3], &[4, 5, 6], &[5, 0, 2]]);
+
+    // Consistent: b is A (1, 1, 1), the solution is unique up to the null space
+    let b = vector(&[6, 15, 7]);
+    let x = a.solve(&b).expect("System is consistent");
+    assert_eq!(a.apply(&x), b);
+    let kernel = a.null_space();
+    assert_eq!(kernel.rows(), 1);
+    assert_eq!(a.apply(kernel.row(0)), vector(&[0, 0, 0]));
+
+    // Inconsistent: the third equation is not the sum of the first two
+    assert_eq!(a.solve(&vector(&[1, 1, 1])), None);
+
+    // Square invertible systems agree with the inverse
+    let c = matrix(&[&[0, 1, 2], &[3, 4, 5], &[6, 1, 1]]);
+    let b = vector(&[1, 2, 3]);
+    assert_eq!(c.solve(&b), Some(c.inverse().unwrap().apply(&b)));
+}
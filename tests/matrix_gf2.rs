@@ -0,0 +1,69 @@
+use algebra::{
+    field::finite_field::Fp,
+    matrix::{gf2::BitMatrix, Matrix},
+};
+
+/// Deterministic pseudo-random bits
+fn bits(rows: usize, cols: usize, seed: u64) -> BitMatrix {
+    BitMatrix::from_fn(rows, cols, |i, j| {
+        let mut x = seed ^ ((i as u64) << 32) ^ j as u64;
+        x = x.wrapping_mul(0x9E37_79B9_7F4A_7C15);
+        x ^= x >> 29;
+        x.wrapping_mul(0xBF58_476D_1CE4_E5B9) >> 63 == 1
+    })
+}
+
+#[test]
+fn test_entries_and_transpose() {
+    let mut a = BitMatrix::zeros(3, 70);
+    a.set(1, 65, true);
+    a.flip(2, 3);
+    a.flip(2, 3);
+    a.flip(0, 0);
+    assert!(a.get(1, 65) && a.get(0, 0) && !a.get(2, 3));
+    assert_eq!(a.row_ones(1).collect::<Vec<_>>(), vec![65]);
+    let t = a.transpose();
+    assert_eq!((t.rows(), t.cols()), (70, 3));
+    assert!(t.get(65, 1));
+    assert_eq!(t.transpose(), a);
+    assert_eq!(BitMatrix::identity(2).to_string(), "10\n01\n");
+}
+
+#[test]
+fn test_null_space() {
+    for (rows, cols, seed) in [
+        (10, 20, 1),
+        (50, 64, 2),
+        (100, 130, 3),
+        (130, 100, 4),
+        (65, 65, 5),
+    ] {
+        let a = bits(rows, cols, seed);
+        let kernel = a.null_space();
+        // Rank-nullity
+        assert_eq!(a.rank() + kernel.rows(), cols);
+        assert_eq!(kernel.rank(), kernel.rows());
+        for k in 0..kernel.rows() {
+            assert!(a.mul_vector(kernel.row_words(k)).iter().all(|&b| !b));
+        }
+    }
+    assert_eq!(BitMatrix::identity(5).null_space().rows(), 0);
+}
+
+#[test]
+fn test_rank_and_dense_conversion() {
+    // Rows r0, r1, r0 + r1 over GF(2)
+    let a = BitMatrix::from_fn(3, 4, |i, j| match i {
+        0 => j % 2 == 0,
+        1 => j < 2,
+        _ => (j % 2 == 0) != (j < 2),
+    });
+    assert_eq!(a.rank(), 2);
+    assert_eq!(a.null_space().rows(), 2);
+
+    for seed in 0..5 {
+        let a = bits(12, 9, seed);
+        let dense = Matrix::<Fp<2>>::from(&a);
+        assert_eq!(BitMatrix::from(&dense), a);
+    }
+}
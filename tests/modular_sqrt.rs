@@ -0,0 +1,90 @@
+use algebra::{
+    field::finite_field::Fp,
+    number::{crt, factorize, sqrt_mod, sqrt_mod_prime_power},
+};
+
+/// All roots of x^2 = a (mod n) by exhaustive search
+fn brute_force_sqrt(a: u64, n: u64) -> Vec<u64> {
+    (0..n).filter(|x| (x * x) % n == a % n).collect()
+}
+
+#[test]
+fn test_sqrt_mod_matches_brute_force() {
+    for n in 1..=200 {
+        for a in 0..n {
+            assert_eq!(sqrt_mod(a, n), brute_force_sqrt(a, n), "a = {a}, n = {n}");
+        }
+    }
+}
+
+#[test]
+fn test_sqrt_mod_prime_powers() {
+    // Powers of two have up to four unit roots
+    assert_eq!(sqrt_mod_prime_power(17, 2, 5), brute_force_sqrt(17, 32));
+    assert_eq!(sqrt_mod_prime_power(0, 3, 5), brute_force_sqrt(0, 243));
+    assert_eq!(sqrt_mod_prime_power(18, 3, 4), brute_force_sqrt(18, 81));
+    assert_eq!(sqrt_mod_prime_power(9 * 7, 3, 6), brute_force_sqrt(63, 729));
+}
+
+#[test]
+fn test_sqrt_mod_prime_power_near_u64_max() {
+    // p^2 > 2^63, where the Hensel step must not overflow
+    let p = 4_294_967_291;
+    let n = p * p;
+    assert_eq!(sqrt_mod(4, n), vec![2, n - 2]);
+    assert_eq!(sqrt_mod_prime_power(4, p, 2), vec![2, n - 2]);
+
+    let x = 12_345_678_901_234_567_u64;
+    let a = ((x as u128 * x as u128) % n as u128) as u64;
+    let roots = sqrt_mod(a, n);
+    assert_eq!(roots, vec![x.min(n - x), x.max(n - x)]);
+}
+
+#[test]
+fn test_sqrt_mod_zero_modulus() {
+    assert_eq!(sqrt_mod(4, 0), Vec::<u64>::new());
+}
+
+#[test]
+fn test_sqrt_mod_large_composite() {
+    // n = 1000003 * 998244353
+    let n = 1_000_003 * 998_244_353;
+    let x = 123_456_789_012;
+    let a = ((x as u128 * x as u128) % n as u128) as u64;
+    let roots = sqrt_mod(a, n);
+    assert_eq!(roots.len(), 4);
+    assert!(roots.contains(&x));
+    for r in roots {
+        assert_eq!((r as u128 * r as u128) % n as u128, a as u128);
+    }
+}
+
+#[test]
+fn test_factorize_and_crt() {
+    assert_eq!(factorize(0), vec![]);
+    assert_eq!(factorize(1), vec![]);
+    assert_eq!(factorize(360), vec![(2, 3), (3, 2), (5, 1)]);
+    assert_eq!(
+        factorize(1_000_003 * 998_244_353),
+        vec![(1_000_003, 1), (998_244_353, 1)]
+    );
+    // Semiprime close to 2^64, where x^2 + c must not overflow inside Pollard's rho
+    assert_eq!(
+        factorize(4_294_967_279 * 4_294_967_291),
+        vec![(4_294_967_279, 1), (4_294_967_291, 1)]
+    );
+    assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+    assert_eq!(crt(&[(1, 4), (3, 6)]), None);
+}
+
+#[test]
+fn test_fp_sqrt() {
+    type F = Fp<1_000_000_007>;
+    for n in [0, 1, 2, 4, 12345, 999_999_999] {
+        let x = F::new(n);
+        let root = (x * x).sqrt().unwrap();
+        assert!(root == x || root == -x);
+    }
+    // 5 is a non-residue modulo 1e9 + 7
+    assert_eq!(F::new(5).sqrt(), None);
+}
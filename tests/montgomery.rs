@@ -0,0 +1,114 @@
+use algebra::{
+    field::{
+        batch_inverse, checked_batch_inverse,
+        finite_field::{montgomery::FpMont, Fp},
+        FieldBase,
+    },
+    polynomial::lagrange::lagrange_interpolation,
+    ring::RingBase,
+    verify::verify_finite_field,
+};
+
+/// Deterministic xorshift generator
+struct XorShift(u64);
+
+impl XorShift {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+}
+
+fn check_against_fp<const P: u64>(rng: &mut XorShift) {
+    for _ in 0..1000 {
+        let (a, b) = (rng.next(), rng.next());
+        let (x, y) = (FpMont::<P>::new(a), FpMont::<P>::new(b));
+        let (u, v) = (Fp::<P>::new(a), Fp::<P>::new(b));
+        assert_eq!(x.value(), u.value());
+        assert_eq!((x + y).value(), (u + v).value());
+        assert_eq!((x - y).value(), (u - v).value());
+        assert_eq!((x * y).value(), (u * v).value());
+        assert_eq!((-x).value(), (-u).value());
+        assert_eq!(x.pow(b).value(), u.pow(b).value());
+        if v != Fp::zero() {
+            assert_eq!((x / y).value(), (u / v).value());
+        }
+        assert_eq!(Fp::from(x), u);
+        assert_eq!(FpMont::from(u), x);
+    }
+}
+
+#[test]
+fn test_matches_fp() {
+    let mut rng = XorShift(0x2545f4914f6cdd1d);
+    check_against_fp::<3>(&mut rng);
+    check_against_fp::<998244353>(&mut rng);
+    check_against_fp::<1_000_000_007>(&mut rng);
+    // Largest prime below 2^63
+    check_against_fp::<9223372036854775783>(&mut rng);
+
+    assert_eq!(FpMont::<7>::new(10).to_string(), "F7(3)");
+    assert_eq!(format!("{:?}", FpMont::<7>::one()), "FpMont(1)");
+}
+
+#[test]
+fn test_field_axioms() {
+    let elements = (0..31).map(FpMont::<31>::new).collect::<Vec<_>>();
+    assert_eq!(verify_finite_field(&elements, FpMont::pow), Ok(()));
+}
+
+#[test]
+fn test_batch_inverse() {
+    type F = Fp<1_000_000_007>;
+    let mut rng = XorShift(7);
+    let elements = (0..100)
+        .map(|_| F::new(rng.next() % 1_000_000_006 + 1))
+        .collect::<Vec<_>>();
+    let inverses = batch_inverse(&elements);
+    assert_eq!(
+        inverses,
+        elements.iter().map(|a| a.inverse()).collect::<Vec<_>>()
+    );
+    assert!(batch_inverse::<F>(&[]).is_empty());
+
+    let mont = elements
+        .iter()
+        .map(|a| FpMont::from(*a))
+        .collect::<Vec<_>>();
+    let mont_inverses = batch_inverse(&mont);
+    assert!(mont_inverses
+        .iter()
+        .zip(&inverses)
+        .all(|(a, b)| Fp::from(*a) == *b));
+
+    let with_zero = [F::new(2), F::zero(), F::new(3)];
+    assert_eq!(checked_batch_inverse(&with_zero), None);
+}
+
+#[test]
+fn test_lagrange_interpolation() {
+    const P: u64 = 1_000_000_007;
+    let points = (1..20)
+        .map(|x| (x, (x * x * x + 5) % P))
+        .collect::<Vec<_>>();
+    let fp = lagrange_interpolation(
+        &points
+            .iter()
+            .map(|(x, y)| (Fp::<P>::new(*x), Fp::new(*y)))
+            .collect::<Vec<_>>(),
+    );
+    let mont = lagrange_interpolation(
+        &points
+            .iter()
+            .map(|(x, y)| (FpMont::<P>::new(*x), FpMont::new(*y)))
+            .collect::<Vec<_>>(),
+    );
+    let coeffs = |c: &[FpMont<P>]| c.iter().map(|a| a.value()).collect::<Vec<_>>();
+    assert_eq!(coeffs(mont.coeffs()), [5, 0, 0, 1]);
+    assert_eq!(
+        fp.coeffs().iter().map(|a| a.value()).collect::<Vec<_>>(),
+        [5, 0, 0, 1]
+    );
+}
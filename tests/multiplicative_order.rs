@@ -0,0 +1,59 @@
+use algebra::number::{carmichael_lambda, euler_phi, gcd, mult_order, pow_mod};
+
+/// Multiplicative order by exhaustive search
+fn brute_force_order(a: u64, n: u64) -> Option<u64> {
+    if gcd(a % n, n) != 1 {
+        return None;
+    }
+    (1..=n).find(|&k| pow_mod(a, k, n) == 1 % n)
+}
+
+#[test]
+fn test_mult_order_matches_brute_force() {
+    for n in 1..=300 {
+        for a in 0..n + 3 {
+            assert_eq!(
+                mult_order(a, n),
+                brute_force_order(a, n),
+                "a = {a}, n = {n}"
+            );
+        }
+    }
+    assert_eq!(mult_order(3, 0), None);
+}
+
+#[test]
+fn test_totient_and_carmichael() {
+    for n in 1..=300 {
+        let units = (1..=n).filter(|&a| gcd(a % n, n) == 1).collect::<Vec<_>>();
+        assert_eq!(euler_phi(n), units.len() as u64, "n = {n}");
+        let exponent = units
+            .iter()
+            .map(|&a| mult_order(a, n).unwrap())
+            .max()
+            .unwrap();
+        assert_eq!(carmichael_lambda(n), exponent, "n = {n}");
+    }
+    assert_eq!(euler_phi(0), 0);
+    assert_eq!(carmichael_lambda(0), 0);
+}
+
+#[test]
+fn test_decimal_period() {
+    // Period of the decimal expansion of 1/n is the order of 10 modulo n
+    assert_eq!(mult_order(10, 7), Some(6));
+    assert_eq!(mult_order(10, 13), Some(6));
+    assert_eq!(mult_order(10, 17), Some(16));
+    assert_eq!(mult_order(10, 81), Some(9));
+    assert_eq!(mult_order(10, 12), None);
+}
+
+#[test]
+fn test_mult_order_large_moduli() {
+    // 3 generates the units modulo the prime 998244353 = 119 * 2^23 + 1
+    let p = 998_244_353;
+    assert_eq!(mult_order(3, p), Some(p - 1));
+    assert_eq!(mult_order(pow_mod(3, 119, p), p), Some(1 << 23));
+    // Mersenne prime 2^61 - 1: the order of 2 is 61
+    assert_eq!(mult_order(2, (1 << 61) - 1), Some(61));
+}
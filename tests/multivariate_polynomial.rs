@@ -0,0 +1,151 @@
+use std::cmp::Ordering;
+
+use algebra::{
+    field::finite_field::Fp,
+    polynomial::multivariate::{
+        monomial::Monomial,
+        ordering::{GRevLex, GrLex, Lex, MonomialOrder, Weighted},
+        MultiPoly,
+    },
+};
+
+fn m(exps: &[u32]) -> Monomial {
+    Monomial::new(exps.to_vec())
+}
+
+#[test]
+fn test_monomial_orders() {
+    // Examples from Cox, Little, O'Shea - Ideals, Varieties, and Algorithms
+    assert_eq!(Lex.cmp(&m(&[1, 2, 0]), &m(&[0, 3, 4])), Ordering::Greater);
+    assert_eq!(Lex.cmp(&m(&[3, 2, 4]), &m(&[3, 2, 1])), Ordering::Greater);
+    assert_eq!(GrLex.cmp(&m(&[1, 2, 3]), &m(&[3, 2, 0])), Ordering::Greater);
+    assert_eq!(GrLex.cmp(&m(&[1, 2, 4]), &m(&[1, 1, 5])), Ordering::Greater);
+    assert_eq!(
+        GRevLex.cmp(&m(&[4, 7, 1]), &m(&[4, 2, 3])),
+        Ordering::Greater
+    );
+    assert_eq!(
+        GRevLex.cmp(&m(&[1, 5, 2]), &m(&[4, 1, 3])),
+        Ordering::Greater
+    );
+
+    let weighted = Weighted::new(vec![1, 3], Lex);
+    assert_eq!(weighted.cmp(&m(&[2, 0]), &m(&[0, 1])), Ordering::Less);
+    assert_eq!(weighted.cmp(&m(&[3, 0]), &m(&[0, 1])), Ordering::Greater);
+}
+
+#[test]
+#[should_panic(expected = "Weights must be positive")]
+fn test_weighted_order_rejects_zero_weight() {
+    let _ = Weighted::new(vec![1, 0], Lex);
+}
+
+#[test]
+#[should_panic(expected = "one weight per variable")]
+fn test_weighted_order_rejects_wrong_length() {
+    let weighted = Weighted::new(vec![1, 3], Lex);
+    let _ = weighted.cmp(&m(&[1, 0, 0]), &m(&[0, 1, 0]));
+}
+
+#[test]
+#[should_panic(expected = "same number of variables")]
+fn test_monomial_nvars_mismatch() {
+    let _ = m(&[1, 2]).mul(&m(&[1, 2, 3]));
+}
+
+#[test]
+#[should_panic(expected = "same number of variables")]
+fn test_graded_order_nvars_mismatch() {
+    // The total degrees differ, which must not hide the mismatch
+    let _ = GrLex.cmp(&m(&[2, 1]), &m(&[1, 0, 0]));
+}
+
+#[test]
+#[should_panic(expected = "out of range")]
+fn test_degree_in_out_of_range() {
+    let f = MultiPoly::var(2, 0, Lex) * MultiPoly::<Fp<7>, _>::var(2, 1, Lex);
+    let _ = f.degree_in(2);
+}
+
+#[test]
+fn test_leading_term_depends_on_order() {
+    type F = Fp<101>;
+    // f = x y^2 + x^2 + y^3
+    let terms = vec![
+        (m(&[1, 2]), F::new(1)),
+        (m(&[2, 0]), F::new(1)),
+        (m(&[0, 3]), F::new(1)),
+    ];
+    let f = MultiPoly::from_terms(2, terms, Lex);
+    assert_eq!(f.leading_monomial(), Some(&m(&[2, 0])));
+
+    let f = f.with_order(GrLex);
+    assert_eq!(f.leading_monomial(), Some(&m(&[1, 2])));
+
+    let f = f.with_order(GRevLex);
+    assert_eq!(f.leading_monomial(), Some(&m(&[1, 2])));
+}
+
+#[test]
+fn test_multivariate_arithmetic() {
+    type F = Fp<101>;
+    let x = MultiPoly::<F, GRevLex>::var(2, 0, GRevLex);
+    let y = MultiPoly::<F, GRevLex>::var(2, 1, GRevLex);
+    let one = MultiPoly::constant(2, F::new(1), GRevLex);
+
+    // (x + y)(x - y) = x^2 - y^2
+    let lhs = &(&x + &y) * &(&x - &y);
+    let rhs = &(&x * &x) - &(&y * &y);
+    assert_eq!(lhs, rhs);
+
+    // (x + 1)^2 evaluated at (3, 7)
+    let p = &(&x + &one) * &(&x + &one);
+    assert_eq!(p.evaluate(&[F::new(3), F::new(7)]), F::new(16));
+    assert_eq!(p.terms().len(), 3);
+
+    // x - x = 0
+    assert!((&x - &x).is_zero());
+}
+
+#[test]
+fn test_homogenization() {
+    type F = Fp<101>;
+    // f = x^3 + x y + 5 in the variables (x, y), extended with z
+    let f = MultiPoly::from_terms(
+        2,
+        vec![
+            (m(&[3, 0]), F::new(1)),
+            (m(&[1, 1]), F::new(1)),
+            (m(&[0, 0]), F::new(5)),
+        ],
+        GRevLex,
+    );
+    assert_eq!(f.total_degree(), Some(3));
+    assert_eq!(f.degree_in(1), Some(1));
+    assert!(!f.is_homogeneous());
+
+    // f^h = x^3 + x y z + 5 z^3
+    let fh = f.add_var().homogenize(2);
+    let expected = MultiPoly::from_terms(
+        3,
+        vec![
+            (m(&[3, 0, 0]), F::new(1)),
+            (m(&[1, 1, 1]), F::new(1)),
+            (m(&[0, 0, 3]), F::new(5)),
+        ],
+        GRevLex,
+    );
+    assert_eq!(fh, expected);
+    assert!(fh.is_homogeneous());
+    assert_eq!(fh.dehomogenize(2), f.add_var());
+
+    // Homogenizing with respect to an existing variable: x^2 + y -> x^2 + x y
+    let g = MultiPoly::from_terms(
+        2,
+        vec![(m(&[2, 0]), F::new(1)), (m(&[0, 1]), F::new(1))],
+        Lex,
+    );
+    let gh = g.homogenize(0);
+    assert_eq!(gh.terms()[1].0, m(&[1, 1]));
+    assert!(MultiPoly::<F, Lex>::zero(2, Lex).homogenize(0).is_zero());
+}
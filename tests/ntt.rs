@@ -0,0 +1,201 @@
+use algebra::{
+    field::finite_field::Fp,
+    polynomial::{
+        genfunc::convolution,
+        ntt::{
+            convolution_mod, convolution_ntt, convolution_u64, inverse_ntt, ntt, root_of_unity,
+            transform_length, CRT_PRIMES,
+        },
+        Polynomial,
+    },
+};
+
+/// Deterministic xorshift generator
+struct XorShift(u64);
+
+impl XorShift {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn vec<const P: u64>(&mut self, n: usize) -> Vec<Fp<P>> {
+        (0..n).map(|_| Fp::new(self.next())).collect()
+    }
+}
+
+/// Schoolbook product of u64 sequences modulo 2^128
+fn checked_convolution(a: &[u64], b: &[u64]) -> Option<Vec<u128>> {
+    let mut c = vec![0u128; a.len() + b.len() - 1];
+    for (i, x) in a.iter().enumerate() {
+        for (j, y) in b.iter().enumerate() {
+            c[i + j] = c[i + j].checked_add(*x as u128 * *y as u128)?;
+        }
+    }
+    Some(c)
+}
+
+/// Direct O(n^2) transform by Horner's scheme at each w^k
+fn naive_dft<const P: u64>(a: &[Fp<P>]) -> Vec<Fp<P>> {
+    let w = root_of_unity::<P>(a.len()).unwrap();
+    (0..a.len() as u64)
+        .map(|k| {
+            let wk = w.pow(k);
+            a.iter().rev().fold(Fp::new(0), |acc, x| acc * wk + *x)
+        })
+        .collect()
+}
+
+fn check_lengths<const P: u64>(rng: &mut XorShift, lengths: &[usize]) {
+    for &n in lengths {
+        let a = rng.vec::<P>(n);
+        let transform = ntt(&a);
+        assert_eq!(transform, naive_dft(&a), "length {n}");
+        assert_eq!(inverse_ntt(&transform), a, "length {n}");
+    }
+}
+
+#[test]
+fn test_roots_of_unity() {
+    const P: u64 = 998244353;
+    let w = root_of_unity::<P>(1 << 23).unwrap();
+    assert_eq!(w.pow(1 << 23), Fp::new(1));
+    assert_ne!(w.pow(1 << 22), Fp::new(1));
+    assert_eq!(root_of_unity::<P>(3), None);
+    assert_eq!(root_of_unity::<P>(0), None);
+
+    assert_eq!(transform_length::<P>(1000), Some(1024));
+    assert_eq!(transform_length::<P>(100), Some(112));
+    assert_eq!(transform_length::<1_000_000_007>(3), Some(500000003));
+}
+
+#[test]
+fn test_mixed_radix() {
+    let mut rng = XorShift(0x2545f4914f6cdd1d);
+    // 998244353 - 1 = 2^23 7 17, 17 goes through Bluestein
+    check_lengths::<998244353>(&mut rng, &[1, 2, 7, 8, 14, 17, 34, 56, 112, 119, 256, 952]);
+    // 754974721 - 1 = 2^24 3^2 5
+    check_lengths::<754974721>(&mut rng, &[3, 9, 45, 90, 360, 720]);
+}
+
+#[test]
+fn test_bluestein() {
+    let mut rng = XorShift(0x9e3779b97f4a7c15);
+    // 1000000009 - 1 = 2^3 3^2 7 109^2 167
+    check_lengths::<1_000_000_009>(&mut rng, &[109, 167, 218, 334, 1308]);
+    // 10007 - 1 = 2 5003, no smoother convolution length exists for 5003
+    check_lengths::<10007>(&mut rng, &[5003]);
+}
+
+#[test]
+fn test_convolution() {
+    let mut rng = XorShift(0x1234_5678_9abc_def1);
+    for (n, m) in [(40, 50), (100, 13), (300, 250), (1000, 1000)] {
+        let (a, b) = (rng.vec::<998244353>(n), rng.vec::<998244353>(m));
+        assert_eq!(convolution_ntt(&a, &b), convolution(&a, &b));
+        let (a, b) = (rng.vec::<1_000_000_009>(n), rng.vec::<1_000_000_009>(m));
+        assert_eq!(convolution_ntt(&a, &b), convolution(&a, &b));
+        // Falls back to schoolbook when P - 1 has no suitable divisor
+        let (a, b) = (rng.vec::<1_000_000_007>(n), rng.vec::<1_000_000_007>(m));
+        assert_eq!(convolution_ntt(&a, &b), convolution(&a, &b));
+    }
+    assert!(convolution_ntt::<998244353>(&[], &[Fp::new(1)]).is_empty());
+}
+
+#[test]
+fn test_crt_convolution() {
+    for p in CRT_PRIMES {
+        assert_eq!(p >> 61, 1);
+        assert!(algebra::number::is_prime(p));
+    }
+
+    let mut rng = XorShift(0x0ddc0ffeebadf00d);
+    for (n, m) in [(1, 1), (5, 70), (100, 100), (513, 1200)] {
+        // Full 64 bit inputs overflow u128 unless a single product, 32 bit inputs never do
+        let a = (0..n).map(|_| rng.next()).collect::<Vec<_>>();
+        let b = (0..m).map(|_| rng.next()).collect::<Vec<_>>();
+        assert_eq!(convolution_u64(&a, &b), checked_convolution(&a, &b));
+        assert_eq!(convolution_u64(&a, &b).is_some(), n.min(m) == 1);
+        let (a32, b32) = (
+            a.iter().map(|x| x >> 32).collect::<Vec<_>>(),
+            b.iter().map(|x| x >> 32).collect::<Vec<_>>(),
+        );
+        assert_eq!(convolution_u64(&a32, &b32), checked_convolution(&a32, &b32));
+        assert!(convolution_u64(&a32, &b32).is_some());
+
+        // Moduli whose multiplicative groups have no large power of two
+        for modulus in [1_000_000_007, 3, u64::MAX, 1 << 63] {
+            let expected = a
+                .iter()
+                .map(|x| x % modulus)
+                .collect::<Vec<_>>()
+                .iter()
+                .enumerate()
+                .fold(vec![0u64; n + m - 1], |mut c, (i, x)| {
+                    for (j, y) in b.iter().enumerate() {
+                        let product = (*x as u128 * (*y % modulus) as u128) % modulus as u128;
+                        c[i + j] = ((c[i + j] as u128 + product) % modulus as u128) as u64;
+                    }
+                    c
+                });
+            assert_eq!(
+                convolution_mod(&a, &b, modulus),
+                expected,
+                "modulus {modulus}"
+            );
+        }
+    }
+    assert_eq!(convolution_u64(&[], &[1]), Some(vec![]));
+    // Largest coefficients that fit, and the first that do not
+    let square = (u64::MAX as u128) * (u64::MAX as u128);
+    assert_eq!(
+        convolution_u64(&[u64::MAX, 2], &[u64::MAX, u64::MAX]),
+        Some(vec![square, u128::MAX, 2 * u64::MAX as u128])
+    );
+    assert_eq!(convolution_u64(&[u64::MAX, 3], &[u64::MAX, u64::MAX]), None);
+}
+
+#[test]
+fn test_mul_crt() {
+    const P: u64 = 1_000_000_007;
+    let mut rng = XorShift(0xfeed_beef_cafe_f00d);
+    let f = Polynomial::from_coeffs(rng.vec::<P>(300));
+    let g = Polynomial::from_coeffs(rng.vec::<P>(200));
+    assert_eq!(f.mul_crt(&g), &f * &g);
+
+    let f = Polynomial::from_coeffs(vec![u64::MAX, 1]);
+    let g = Polynomial::from_coeffs(vec![u64::MAX, 0, 2]);
+    let square = (u64::MAX as u128) * (u64::MAX as u128);
+    assert_eq!(
+        f.mul_crt(&g),
+        Some(Polynomial::from_coeffs(vec![
+            square,
+            u64::MAX as u128,
+            2 * u64::MAX as u128,
+            2
+        ]))
+    );
+    let h = Polynomial::from_coeffs(vec![u64::MAX, u64::MAX]);
+    assert_eq!(h.mul_crt(&h), None);
+}
+
+#[test]
+fn test_mul_fast() {
+    fn check<const P: u64>(rng: &mut XorShift, n: usize, m: usize) {
+        let f = Polynomial::from_coeffs(rng.vec::<P>(n));
+        let g = Polynomial::from_coeffs(rng.vec::<P>(m));
+        assert_eq!(f.mul_fast(&g), &f * &g, "P = {P}, lengths {n} {m}");
+    }
+    let mut rng = XorShift(0x5851f42d4c957f2d);
+    for (n, m) in [(0, 10), (10, 300), (129, 129), (700, 1500)] {
+        // Power of two transform
+        check::<998244353>(&mut rng, n, m);
+        // Mixed radix, 1000000009 - 1 = 2^3 3^2 7 109^2 167
+        check::<1_000_000_009>(&mut rng, n, m);
+        // Three-prime CRT, 1000000007 - 1 = 2 500000003
+        check::<1_000_000_007>(&mut rng, n, m);
+        check::<{ (1 << 61) - 1 }>(&mut rng, n, m);
+    }
+}
@@ -0,0 +1,94 @@
+use algebra::number::{
+    bernoulli, bernoulli_numbers, checked_bernoulli, checked_bernoulli_numbers, checked_harmonic,
+    checked_harmonic_numbers, harmonic, harmonic_numbers, Rational,
+};
+
+#[test]
+fn test_bernoulli_numbers() {
+    let expected = [
+        Rational::integer(1),
+        Rational::new(-1, 2),
+        Rational::new(1, 6),
+        Rational::integer(0),
+        Rational::new(-1, 30),
+        Rational::integer(0),
+        Rational::new(1, 42),
+        Rational::integer(0),
+        Rational::new(-1, 30),
+        Rational::integer(0),
+        Rational::new(5, 66),
+    ];
+    assert_eq!(bernoulli_numbers(10), expected);
+    assert_eq!(bernoulli(12), Rational::new(-691, 2730));
+    assert_eq!(bernoulli(20), Rational::new(-174611, 330));
+    assert_eq!(bernoulli(13), Rational::integer(0));
+}
+
+#[test]
+fn test_harmonic_numbers() {
+    assert_eq!(harmonic(0), Rational::integer(0));
+    assert_eq!(harmonic(4), Rational::new(25, 12));
+    assert_eq!(harmonic(10), Rational::new(7381, 2520));
+
+    let numbers = harmonic_numbers(30);
+    assert_eq!(numbers.len(), 31);
+    for (n, h) in numbers.into_iter().enumerate() {
+        assert_eq!(h, harmonic(n));
+    }
+}
+
+#[test]
+fn test_sequences_exact_bounds() {
+    assert_eq!(
+        bernoulli(56),
+        Rational::new(-2479392929313226753685415739663229, 870)
+    );
+    assert_eq!(bernoulli_numbers(57).len(), 58);
+    assert_eq!(
+        harmonic(88),
+        Rational::new(
+            40866521918642154860585199122889549709,
+            8076030954443701744994070304101969600
+        )
+    );
+    assert_eq!(harmonic_numbers(88)[88], harmonic(88));
+
+    // The checked variants report the limits instead of panicking
+    assert_eq!(checked_bernoulli(56), Some(bernoulli(56)));
+    assert_eq!(checked_bernoulli(58), None);
+    assert_eq!(checked_bernoulli(59), Some(Rational::integer(0)));
+    assert_eq!(checked_bernoulli_numbers(57), Some(bernoulli_numbers(57)));
+    assert_eq!(checked_bernoulli_numbers(58), None);
+    assert_eq!(checked_harmonic(88), Some(harmonic(88)));
+    assert_eq!(checked_harmonic(89), None);
+    assert_eq!(checked_harmonic_numbers(88), Some(harmonic_numbers(88)));
+    assert_eq!(checked_harmonic_numbers(89), None);
+}
+
+#[test]
+#[should_panic(expected = "overflow")]
+fn test_bernoulli_overflow() {
+    let _ = bernoulli(58);
+}
+
+#[test]
+#[should_panic(expected = "overflow")]
+fn test_harmonic_overflow() {
+    let _ = harmonic(89);
+}
+
+#[test]
+fn test_rational_arithmetic() {
+    let a = Rational::new(6, -4);
+    assert_eq!((a.num(), a.den()), (-3, 2));
+    assert_eq!(a + Rational::new(1, 2), Rational::integer(-1));
+    assert_eq!(a * Rational::new(2, 3), Rational::integer(-1));
+    assert_eq!(a / Rational::new(-3, 4), Rational::integer(2));
+    assert_eq!(a - a, Rational::integer(0));
+}
+
+#[test]
+#[should_panic]
+fn test_rational_zero_denominator() {
+    let _ = Rational::new(1, 0);
+}
@@ -0,0 +1,106 @@
+use algebra::{
+    field::finite_field::Fp,
+    number::{
+        checked_partition_numbers, checked_partitions, compositions_into_parts, partition_numbers,
+        partition_numbers_mod, partitions, partitions_into_at_most, partitions_into_distinct_parts,
+        partitions_into_parts,
+    },
+};
+
+const P: u64 = 1_000_000_007;
+
+/// Partitions of n with every part at most k, by the recurrence p(n, k) = p(n, k - 1) + p(n - k, k)
+fn brute_force(n: usize, k: usize) -> u128 {
+    match (n, k) {
+        (0, _) => 1,
+        (_, 0) => 0,
+        (n, k) if k > n => brute_force(n, n),
+        (n, k) => brute_force(n, k - 1) + brute_force(n - k, k),
+    }
+}
+
+#[test]
+fn test_partition_numbers() {
+    let p = partition_numbers(60);
+    for (n, &p_n) in p.iter().enumerate() {
+        assert_eq!(p_n, brute_force(n, n), "n = {n}");
+    }
+    assert_eq!(partitions(100), 190_569_292);
+    assert_eq!(partitions(1000), 24_061_467_864_032_622_473_692_149_727_991);
+    // Largest exact value
+    partitions(1458);
+}
+
+#[test]
+#[should_panic(expected = "Partition number overflow")]
+fn test_partition_numbers_overflow() {
+    partitions(1459);
+}
+
+#[test]
+fn test_checked_partition_numbers() {
+    assert_eq!(checked_partitions(1458), Some(partitions(1458)));
+    assert_eq!(checked_partitions(1459), None);
+    assert_eq!(
+        checked_partition_numbers(1458),
+        Some(partition_numbers(1458))
+    );
+    assert_eq!(checked_partition_numbers(2000), None);
+}
+
+#[test]
+fn test_partition_numbers_mod() {
+    let exact = partition_numbers(1000);
+    let modular = partition_numbers_mod::<P>(1000);
+    for (e, m) in exact.iter().zip(&modular) {
+        assert_eq!((e % P as u128) as u64, m.value());
+    }
+    // Ramanujan's congruence p(5k + 4) = 0 (mod 5)
+    let modular = partition_numbers_mod::<5>(5000);
+    for k in 0..1000 {
+        assert_eq!(modular[5 * k + 4], Fp::new(0));
+    }
+}
+
+#[test]
+fn test_restricted_partitions() {
+    // Ways to make change for a dollar
+    let coins = partitions_into_parts::<u128>(&[1, 5, 10, 25, 50, 100], 100);
+    assert_eq!(coins[100], 293);
+    // Largest part at most k
+    for k in 0..8 {
+        let counts = partitions_into_at_most::<u128>(k, 30);
+        for (n, &c) in counts.iter().enumerate() {
+            assert_eq!(c, brute_force(n, k), "n = {n}, k = {k}");
+        }
+    }
+    // Euler: partitions into distinct parts equal partitions into odd parts
+    let all = (1..=200).collect::<Vec<_>>();
+    let odd = (1..=200).step_by(2).collect::<Vec<_>>();
+    assert_eq!(
+        partitions_into_distinct_parts::<Fp<P>>(&all, 200),
+        partitions_into_parts::<Fp<P>>(&odd, 200)
+    );
+    // Repeated and zero parts are ignored
+    assert_eq!(
+        partitions_into_parts::<u64>(&[0, 2, 3, 2], 12),
+        partitions_into_parts::<u64>(&[3, 2], 12)
+    );
+}
+
+#[test]
+fn test_compositions() {
+    // Compositions into ones and twos are Fibonacci numbers
+    let fib = compositions_into_parts::<u64>(&[1, 2], 30);
+    for n in 2..=30 {
+        assert_eq!(fib[n], fib[n - 1] + fib[n - 2]);
+    }
+    assert_eq!(fib[30], 1_346_269);
+    // Unrestricted compositions of n >= 1 number 2^(n - 1)
+    let all = (1..=40).collect::<Vec<_>>();
+    let counts = compositions_into_parts::<u64>(&all, 40);
+    assert_eq!(counts[0], 1);
+    for (n, &c) in counts.iter().enumerate().skip(1) {
+        assert_eq!(c, 1 << (n - 1));
+    }
+}
@@ -43,7 +43,7 @@ fn test_polynomial_subtraction_larger_minus_smaller() {
 
 #[test]
 fn test_mixed_ops_custom_type() {
-    let p1 = lagrange_interpolation(&vec![(MyF64(0.0), MyF64(1.0)), (MyF64(1.0), MyF64(2.0))]);
+    let p1 = lagrange_interpolation(&[(MyF64(0.0), MyF64(1.0)), (MyF64(1.0), MyF64(2.0))]);
     let p2 = p1.clone();
 
     // Owned + Owned
@@ -66,3 +66,111 @@ fn test_mixed_ops_custom_type() {
     // Div scalar
     let _ = p1.clone() / s;
 }
+
+#[test]
+fn test_coefficient_map() {
+    type F5 = Fp<5>;
+
+    // Reduction mod 5 drops the leading term of 3 + 7x + 10x^2
+    let p = Polynomial::from_coeffs(vec![3_i64, 7, 10]);
+    let reduced = p.map(|c| F5::new(c.rem_euclid(5) as u64));
+    assert_eq!(
+        reduced,
+        Polynomial::from_coeffs(vec![F5::new(3), F5::new(2)])
+    );
+    assert_eq!(reduced.degree(), Some(1));
+
+    let halves = p.map(|c| *c as f64 / 2.0);
+    assert_eq!(halves.coeffs(), &[1.5, 3.5, 5.0]);
+
+    // Fallible maps stop at the first error
+    let narrowed = p.try_map(|c| u8::try_from(*c));
+    assert_eq!(narrowed, Ok(Polynomial::from_coeffs(vec![3_u8, 7, 10])));
+    let negative = Polynomial::from_coeffs(vec![1_i64, -1]);
+    assert!(negative.try_map(|c| u8::try_from(*c)).is_err());
+}
+
+#[test]
+fn test_evaluate_progression() {
+    type F101 = Fp<101>;
+
+    // p(x) = 3 - 2x + 5x^3 + x^4
+    let p = Polynomial::from_coeffs(vec![3i64, -2, 0, 5, 1]);
+    let values = p.evaluate_progression(-7, 3, 20);
+    let expected: Vec<i64> = (0..20).map(|k| p.evaluate(&(-7 + 3 * k))).collect();
+    assert_eq!(values, expected);
+    assert_eq!(
+        p.evaluate_progression(2, 1, 3),
+        vec![p.evaluate(&2), p.evaluate(&3), p.evaluate(&4)]
+    );
+    assert!(p.evaluate_progression(0, 1, 0).is_empty());
+
+    // Constant and zero polynomials
+    assert_eq!(
+        Polynomial::from_coeffs(vec![4i64]).evaluate_progression(1, 2, 3),
+        vec![4, 4, 4]
+    );
+    assert_eq!(
+        Polynomial::<i64>::zero().evaluate_progression(1, 2, 2),
+        vec![0, 0]
+    );
+
+    // Wrapping around the field, as for Shamir shares at 1, ..., n
+    let q = Polynomial::from_coeffs((1..8).map(F101::new).collect());
+    let values = q.evaluate_progression(F101::new(1), F101::new(1), 150);
+    for (k, value) in values.iter().enumerate() {
+        assert_eq!(*value, q.evaluate(&F101::new(k as u64 + 1)));
+    }
+}
+
+#[test]
+fn test_division_operators() {
+    type F17 = Fp<17>;
+    let p = |c: &[u64]| Polynomial::from_coeffs(c.iter().map(|x| F17::new(*x)).collect());
+
+    // (3x^3 + 2x + 5) = (x^2 + 1)(3x) + (16x + 5)
+    let a = p(&[5, 2, 0, 3]);
+    let b = p(&[1, 0, 1]);
+    assert_eq!(&a / &b, p(&[0, 3]));
+    assert_eq!(&a % &b, p(&[5, 16]));
+    assert_eq!(&(&(&a / &b) * &b) + &(&a % &b), a);
+    assert_eq!(a.clone() / b.clone(), p(&[0, 3]));
+    assert_eq!(a.clone() % b.clone(), p(&[5, 16]));
+
+    // Smaller degree: quotient zero, remainder unchanged
+    assert_eq!(&b / &a, Polynomial::zero());
+    assert_eq!(&b % &a, b);
+}
+
+#[test]
+#[should_panic]
+fn test_division_by_zero_polynomial() {
+    let a = Polynomial::from_coeffs(vec![Fp::<7>::new(1)]);
+    let _ = &a % &Polynomial::zero();
+}
+
+#[test]
+fn test_gcd() {
+    type F101 = Fp<101>;
+    let p = |c: &[u64]| Polynomial::from_coeffs(c.iter().map(|x| F101::new(*x)).collect());
+
+    // (x - 1)(x - 2) and 5 (x - 1)(x + 3) share the monic factor x - 1
+    let f = &p(&[100, 1]) * &p(&[99, 1]);
+    let g = &p(&[5]) * &(&p(&[100, 1]) * &p(&[3, 1]));
+    assert_eq!(f.gcd(&g), p(&[100, 1]));
+    assert_eq!(f.gcd(&Polynomial::zero()), f.monic());
+    assert_eq!(
+        Polynomial::<F101>::zero().gcd(&Polynomial::zero()),
+        Polynomial::zero()
+    );
+
+    let (d, s, t) = f.extended_gcd(&g);
+    assert_eq!(d, p(&[100, 1]));
+    assert_eq!(&(&s * &f) + &(&t * &g), d);
+
+    // Coprime: s is the inverse of f modulo the irreducible x^2 + 2
+    let m = p(&[2, 0, 1]);
+    let (d, s, _) = f.extended_gcd(&m);
+    assert_eq!(d, p(&[1]));
+    assert_eq!(&(&s * &f) % &m, p(&[1]));
+}
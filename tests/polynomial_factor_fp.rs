@@ -0,0 +1,182 @@
+use algebra::{
+    field::finite_field::Fp,
+    matrix::Matrix,
+    polynomial::{
+        factor::{
+            distinct_degree_factorization, factor, is_irreducible, square_free_factorization,
+            FactorAlgorithm,
+        },
+        Polynomial,
+    },
+    ring::RingBase,
+};
+
+/// Deterministic xorshift generator
+struct XorShift(u64);
+
+impl XorShift {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn poly<const P: u64>(&mut self, degree: usize) -> Polynomial<Fp<P>> {
+        let mut coeffs: Vec<_> = (0..degree).map(|_| Fp::new(self.next() % P)).collect();
+        coeffs.push(Fp::new(1 + self.next() % (P - 1)));
+        Polynomial::from_coeffs(coeffs)
+    }
+}
+
+fn fp<const P: u64>(coeffs: &[u64]) -> Polynomial<Fp<P>> {
+    Polynomial::from_coeffs(coeffs.iter().map(|&c| Fp::new(c)).collect())
+}
+
+fn expand<const P: u64>(lc: Fp<P>, factors: &[(Polynomial<Fp<P>>, usize)]) -> Polynomial<Fp<P>> {
+    let mut product = Polynomial::from_coeffs(vec![lc]);
+    for (p, e) in factors {
+        for _ in 0..*e {
+            product = &product * p;
+        }
+    }
+    product
+}
+
+/// All monic polynomials of the given degree
+fn monic<const P: u64>(degree: usize) -> impl Iterator<Item = Polynomial<Fp<P>>> {
+    (0..P.pow(degree as u32)).map(move |mut k| {
+        let mut coeffs: Vec<_> = (0..degree)
+            .map(|_| {
+                let c = k % P;
+                k /= P;
+                Fp::new(c)
+            })
+            .collect();
+        coeffs.push(Fp::one());
+        Polynomial::from_coeffs(coeffs)
+    })
+}
+
+#[test]
+fn test_matrix_null_space() {
+    let m: Matrix<Fp<7>> = Matrix::from_fn(3, 4, |i, j| {
+        Fp::new([[1, 2, 3, 4], [2, 4, 6, 8], [0, 1, 1, 1]][i][j])
+    });
+    let (rref, pivots) = m.row_reduce();
+    assert_eq!(pivots, vec![0, 1]);
+    assert!((0..4).all(|j| rref[(2, j)] == Fp::zero()));
+
+    let kernel = m.null_space();
+    assert_eq!(kernel.rows(), 2);
+    for k in 0..kernel.rows() {
+        for i in 0..m.rows() {
+            let dot = (0..4).fold(Fp::<7>::zero(), |acc, j| acc + m[(i, j)] * kernel[(k, j)]);
+            assert_eq!(dot, Fp::zero());
+        }
+    }
+}
+
+#[test]
+fn test_irreducible_counts() {
+    // Number of monic irreducible polynomials of degree n over Fq: (1/n) sum_{d | n} mu(d) q^{n/d}
+    assert_eq!(monic::<2>(4).filter(is_irreducible).count(), 3);
+    assert_eq!(monic::<2>(6).filter(is_irreducible).count(), 9);
+    assert_eq!(monic::<3>(3).filter(is_irreducible).count(), 8);
+    assert_eq!(monic::<5>(2).filter(is_irreducible).count(), 10);
+    assert!(!is_irreducible(&fp::<5>(&[3])));
+    assert!(!is_irreducible(&fp::<3>(&[1, 0, 1, 0, 1])));
+}
+
+#[test]
+fn test_square_free_factorization() {
+    // (x + 1)^2 (x^2 + x + 2)^3 x^3 over F3, the last factor a cube
+    let a = fp::<3>(&[1, 1]);
+    let b = fp::<3>(&[2, 1, 1]);
+    let x = fp::<3>(&[0, 1]);
+    let f = expand(
+        Fp::new(2),
+        &[(a.clone(), 2), (b.clone(), 3), (x.clone(), 3)],
+    );
+    let sqf = square_free_factorization(&f);
+    assert_eq!(sqf, vec![(a, 2), (&b * &x, 3)]);
+}
+
+#[test]
+fn test_distinct_degree_factorization() {
+    // x^9 - x over F3 is the product of all monic irreducibles of degree 1 and 2
+    let mut coeffs = vec![0; 10];
+    coeffs[1] = 2;
+    coeffs[9] = 1;
+    let f = fp::<3>(&coeffs);
+    let ddf = distinct_degree_factorization(&f);
+    assert_eq!(ddf.len(), 2);
+    assert_eq!(ddf[0], (fp::<3>(&[0, 2, 0, 1]), 1));
+    assert_eq!(ddf[1].1, 2);
+
+    for algorithm in [
+        FactorAlgorithm::CantorZassenhaus,
+        FactorAlgorithm::Berlekamp,
+    ] {
+        let (lc, factors) = factor(&f, algorithm);
+        assert_eq!(lc, Fp::one());
+        let degrees: Vec<_> = factors
+            .iter()
+            .map(|(p, e)| (p.degree().unwrap(), *e))
+            .collect();
+        assert_eq!(
+            degrees,
+            vec![(1, 1), (1, 1), (1, 1), (2, 1), (2, 1), (2, 1)]
+        );
+        assert_eq!(factors[0].0, fp::<3>(&[0, 1]));
+    }
+}
+
+fn check_backends_agree<const P: u64>(rng: &mut XorShift, trials: usize, max_degree: usize) {
+    for _ in 0..trials {
+        // Random products with repeated factors
+        let degree = 1 + (rng.next() as usize) % max_degree;
+        let mut f = rng.poly::<P>(degree);
+        for _ in 0..(rng.next() % 3) {
+            let degree = 1 + (rng.next() as usize) % 4;
+            let g = rng.poly::<P>(degree);
+            f = &f * &(&g * &g);
+        }
+        let cz = factor(&f, FactorAlgorithm::CantorZassenhaus);
+        let berlekamp = factor(&f, FactorAlgorithm::Berlekamp);
+        assert_eq!(cz, berlekamp);
+        assert_eq!(factor(&f, FactorAlgorithm::Auto), cz);
+        assert_eq!(expand(cz.0, &cz.1), f);
+        assert!(cz.1.iter().all(|(p, _)| is_irreducible(p)));
+    }
+}
+
+#[test]
+fn test_backends_agree() {
+    let mut rng = XorShift(0x2545f4914f6cdd1d);
+    check_backends_agree::<2>(&mut rng, 30, 16);
+    check_backends_agree::<3>(&mut rng, 30, 12);
+    check_backends_agree::<7>(&mut rng, 20, 10);
+    check_backends_agree::<101>(&mut rng, 10, 8);
+}
+
+#[test]
+fn test_large_field() {
+    const P: u64 = 1_000_000_007;
+    let mut rng = XorShift(0x9e3779b97f4a7c15);
+    for _ in 0..5 {
+        let f = rng.poly::<P>(12);
+        let (lc, factors) = factor(&f, FactorAlgorithm::Auto);
+        assert_eq!(expand(lc, &factors), f);
+        assert!(factors.iter().all(|(p, _)| is_irreducible(p)));
+    }
+
+    // (x - 1)(x - 2)...(x - 6)
+    let f = (1..=6).fold(fp::<P>(&[1]), |acc, r| &acc * &fp::<P>(&[P - r, 1]));
+    let (_, factors) = factor(&f, FactorAlgorithm::CantorZassenhaus);
+    let roots: Vec<_> = factors
+        .iter()
+        .map(|(p, _)| P - p.coeffs()[0].value())
+        .collect();
+    assert_eq!(roots, vec![6, 5, 4, 3, 2, 1]);
+}
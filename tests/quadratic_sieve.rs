@@ -0,0 +1,51 @@
+use algebra::number::{is_prime, quadratic_sieve};
+
+fn next_prime(mut n: u64) -> u64 {
+    while !is_prime(n) {
+        n += 1;
+    }
+    n
+}
+
+/// Semiprime with balanced factors of about bits / 2 bits each
+fn semiprime(bits: u32, seed: u64) -> (u128, u64, u64) {
+    let a = next_prime((1 << (bits / 2 - 1)) + seed * 7919);
+    let b = next_prime((1 << (bits - bits / 2)) - seed * 104_729);
+    (a as u128 * b as u128, a, b)
+}
+
+#[test]
+fn test_balanced_semiprimes() {
+    for bits in [40, 52, 64, 72, 84, 96] {
+        for seed in 1..=2 {
+            let (n, a, b) = semiprime(bits, seed);
+            let d = quadratic_sieve(n).expect("Composite input must be split");
+            assert!(
+                d == a as u128 || d == b as u128,
+                "n = {n} split as {d} instead of {a} * {b}"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_composites_with_several_factors() {
+    // 3 * 5 * 7 * ... the factor base detects small primes dividing n
+    let n = 3 * 5 * 7 * 11 * 13 * 1_000_003_u128;
+    let d = quadratic_sieve(n).unwrap();
+    assert!(d > 1 && d < n && n.is_multiple_of(d));
+
+    let n = 1_000_003_u128 * 1_000_033 * 1_000_037;
+    let d = quadratic_sieve(n).unwrap();
+    assert!(d > 1 && d < n && n.is_multiple_of(d));
+}
+
+#[test]
+fn test_trivial_inputs() {
+    assert_eq!(quadratic_sieve(1), None);
+    assert_eq!(quadratic_sieve(2), None);
+    assert_eq!(quadratic_sieve(1 << 70), Some(2));
+    assert_eq!(quadratic_sieve(1_000_003 * 1_000_003), Some(1_000_003));
+    assert_eq!(quadratic_sieve(1_000_000_007), None);
+    assert_eq!(quadratic_sieve((1 << 61) - 1), None);
+}
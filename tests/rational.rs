@@ -0,0 +1,122 @@
+use algebra::{
+    field::FieldBase,
+    number::Rational,
+    polynomial::{lagrange::lagrange_interpolation, Polynomial},
+    ring::RingBase,
+};
+
+fn q(num: i128, den: i128) -> Rational {
+    Rational::new(num, den)
+}
+
+#[test]
+fn test_display_and_normalization() {
+    assert_eq!(q(6, -4).to_string(), "-3/2");
+    assert_eq!(q(-8, -4).to_string(), "2");
+    assert_eq!(Rational::zero().to_string(), "0");
+    assert_eq!((q(6, -4).num(), q(6, -4).den()), (-3, 2));
+    assert!(q(10, 5).is_integer());
+    assert_eq!(q(-3, 7).abs(), q(3, 7));
+    assert_eq!(q(i128::MIN, i128::MIN), Rational::one());
+}
+
+#[test]
+fn test_ordering() {
+    let mut values = vec![
+        q(1, 2),
+        q(-7, 3),
+        q(0, 1),
+        q(2, 3),
+        q(-2, 1),
+        q(5, 8),
+        q(1, 3),
+    ];
+    values.sort();
+    assert_eq!(
+        values,
+        [
+            q(-7, 3),
+            q(-2, 1),
+            q(0, 1),
+            q(1, 3),
+            q(1, 2),
+            q(5, 8),
+            q(2, 3)
+        ]
+    );
+    assert!(q(-1, 3) > q(-1, 2));
+    assert_eq!(q(2, 4).cmp(&q(1, 2)), std::cmp::Ordering::Equal);
+
+    // Cross products would overflow i128
+    let big = i128::MAX;
+    assert!(q(big - 1, big) < q(big, big - 1));
+    assert!(q(big - 2, big - 1) < q(big - 1, big));
+    assert!(q(-(big - 2), big - 1) > q(-(big - 1), big));
+    assert_eq!(q(big, 3).max(q(big - 1, 3)), q(big, 3));
+}
+
+#[test]
+fn test_operators() {
+    let (a, b) = (q(3, 4), q(-5, 6));
+    assert_eq!(a + b, q(-1, 12));
+    assert_eq!(a - b, q(19, 12));
+    assert_eq!(a * b, q(-5, 8));
+    assert_eq!(a / b, q(-9, 10));
+    assert_eq!(-&a, q(-3, 4));
+
+    let mut c = a;
+    c += b;
+    c -= &a;
+    assert_eq!(c, b);
+    c *= q(6, 5);
+    c /= &q(-1, 2);
+    assert_eq!(c, Rational::integer(2));
+    assert_eq!(b.inverse(), q(-6, 5));
+}
+
+#[test]
+fn test_checked_arithmetic() {
+    let big = Rational::integer(i128::MAX);
+    assert_eq!(big.checked_add(&Rational::one()), None);
+    assert_eq!(big.checked_mul(&Rational::integer(2)), None);
+    assert_eq!(Rational::integer(i128::MIN).checked_neg(), None);
+    assert_eq!(
+        Rational::integer(i128::MIN).checked_sub(&Rational::one()),
+        None
+    );
+    assert_eq!(q(1, 3).checked_div(&Rational::zero()), None);
+    assert_eq!(Rational::integer(i128::MIN).checked_inverse(), None);
+    assert_eq!(q(1, 3).checked_add(&q(1, 6)), Some(q(1, 2)));
+    assert_eq!(big.checked_div(&big), Some(Rational::one()));
+}
+
+#[test]
+#[should_panic]
+fn test_overflow_panics() {
+    let _ = Rational::integer(i128::MAX) + Rational::one();
+}
+
+#[test]
+fn test_exact_interpolation() {
+    // p(x) = x^3 / 6 - x / 2 + 2 / 3 through integer points
+    let p = Polynomial::from_coeffs(vec![q(2, 3), q(-1, 2), q(0, 1), q(1, 6)]);
+    let points = (-2..2)
+        .map(|x| {
+            let x = Rational::integer(x);
+            (x, p.evaluate(&x))
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(lagrange_interpolation(&points), p);
+}
+
+#[test]
+fn test_exact_interpolation_many_points() {
+    // The product of all denominators would overflow, each basis denominator alone does not
+    let points = (0..12)
+        .map(|x| (Rational::integer(x), Rational::integer(x * x)))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        lagrange_interpolation(&points),
+        Polynomial::from_coeffs(vec![q(0, 1), q(0, 1), q(1, 1)])
+    );
+}
@@ -0,0 +1,123 @@
+use algebra::{
+    number::Rational,
+    polynomial::{
+        rational::{
+            checked_clear_denominators, checked_gcd, checked_integer_gcd, clear_denominators,
+            factor, from_integer, gcd, integer_gcd, primitive_part,
+        },
+        Polynomial,
+    },
+};
+
+fn q(coeffs: &[(i128, i128)]) -> Polynomial<Rational> {
+    Polynomial::from_coeffs(coeffs.iter().map(|(n, d)| Rational::new(*n, *d)).collect())
+}
+
+fn z(coeffs: &[i128]) -> Polynomial<i128> {
+    Polynomial::from_coeffs(coeffs.to_vec())
+}
+
+#[test]
+fn test_clear_denominators_round_trip() {
+    // 1/2 + 2/3 x - 5/4 x^2 = (6 + 8x - 15x^2) / 12
+    let f = q(&[(1, 2), (2, 3), (-5, 4)]);
+    let (p, den) = clear_denominators(&f);
+    assert_eq!(p, z(&[6, 8, -15]));
+    assert_eq!(den, 12);
+    assert_eq!(from_integer(&p, den), f);
+
+    assert_eq!(primitive_part(&z(&[6, -4, -2])), z(&[-3, 2, 1]));
+}
+
+#[test]
+fn test_checked_overflow() {
+    // Pairwise coprime denominators near 2^50, whose lcm exceeds i128
+    let f = q(&[
+        (1, (1 << 50) - 35),
+        (1, (1 << 50) - 33),
+        (1, (1 << 50) - 27),
+    ]);
+    assert_eq!(checked_clear_denominators(&f), None);
+    assert_eq!(checked_gcd(&f, &q(&[(1, 1), (1, 1)])), None);
+    let small = q(&[(1, 2), (2, 3), (-5, 4)]);
+    assert_eq!(
+        checked_clear_denominators(&small),
+        Some(clear_denominators(&small))
+    );
+
+    // The first pseudo-remainder scales 2^100 by 2^100 + 1
+    let f = z(&[1, 1, 1 << 100]);
+    let g = z(&[1, (1 << 100) + 1]);
+    assert_eq!(checked_integer_gcd(&f, &g), None);
+    assert_eq!(
+        checked_integer_gcd(&z(&[4, 4]), &z(&[-6, 0, 6])),
+        Some(z(&[2, 2]))
+    );
+}
+
+#[test]
+#[should_panic(expected = "Common denominator overflow")]
+fn test_clear_denominators_overflow() {
+    let _ = clear_denominators(&q(&[
+        (1, (1 << 50) - 35),
+        (1, (1 << 50) - 33),
+        (1, (1 << 50) - 27),
+    ]));
+}
+
+#[test]
+fn test_gcd_over_q() {
+    // f = (x - 1/2)(x + 2)^2, g = (x - 1/2)(x + 2)(x - 3) / 7
+    let f = q(&[(-2, 1), (2, 1), (7, 2), (1, 1)]);
+    let g = q(&[(3, 7), (-11, 14), (-3, 14), (1, 7)]);
+    // gcd = (x - 1/2)(x + 2) = x^2 + 3/2 x - 1
+    assert_eq!(gcd(&f, &g), q(&[(-1, 1), (3, 2), (1, 1)]));
+    assert_eq!(gcd(&f, &Polynomial::zero()), f);
+
+    // Integer gcd keeps the content: gcd(4x + 4, 6x^2 - 6) = 2(x + 1)
+    assert_eq!(integer_gcd(&z(&[4, 4]), &z(&[-6, 0, 6])), z(&[2, 2]));
+}
+
+#[test]
+fn test_factor_over_q() {
+    // f = 3/2 (2x - 1)^2 (x^2 + 1) (x + 3)
+    let linear = z(&[-1, 2]);
+    let quadratic = z(&[1, 0, 1]);
+    let other = z(&[3, 1]);
+    let product = &(&(&linear * &linear) * &quadratic) * &other;
+    let f = from_integer(&(product * 3), 2);
+
+    let (c, mut factors) = factor(&f);
+    assert_eq!(c, Rational::new(3, 2));
+    factors.sort_by_key(|(p, e)| (p.degree(), *e));
+    assert_eq!(factors, vec![(other, 1), (linear, 2), (quadratic, 1)]);
+}
+
+#[test]
+fn test_factor_without_rational_roots() {
+    // (x^2 + 1)(x^2 + 2) has no rational roots but splits over Q
+    let f = from_integer(&(&z(&[1, 0, 1]) * &z(&[2, 0, 1])), 1);
+    let (c, mut factors) = factor(&f);
+    assert_eq!(c, Rational::integer(1));
+    factors.sort_by_key(|(p, _)| p.coeffs().to_vec());
+    assert_eq!(factors, vec![(z(&[1, 0, 1]), 1), (z(&[2, 0, 1]), 1)]);
+
+    // (x^3 - 2)(2x^3 + x + 1), then (x^2 + 1)^2 (x^4 - 10x^2 + 1)
+    let cubic = z(&[-2, 0, 0, 1]);
+    let other = z(&[1, 1, 0, 2]);
+    let (_, mut factors) = factor(&from_integer(&(&cubic * &other), 1));
+    factors.sort_by_key(|(p, _)| p.coeffs().to_vec());
+    assert_eq!(factors, vec![(cubic, 1), (other, 1)]);
+
+    let quadratic = z(&[1, 0, 1]);
+    let quartic = z(&[1, 0, -10, 0, 1]);
+    let product = &(&quadratic * &quadratic) * &quartic;
+    let (_, mut factors) = factor(&from_integer(&product, 1));
+    factors.sort_by_key(|(p, e)| (p.degree(), *e));
+    assert_eq!(factors, vec![(quadratic, 2), (quartic, 1)]);
+
+    // Irreducible quartic and sextic stay whole
+    for p in [z(&[1, 0, 0, 0, 1]), z(&[1, 1, 1, 1, 1, 1, 1])] {
+        assert_eq!(factor(&from_integer(&p, 1)).1, vec![(p, 1)]);
+    }
+}
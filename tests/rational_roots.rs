@@ -0,0 +1,56 @@
+use algebra::{number::Rational, polynomial::Polynomial};
+
+fn r(num: i128, den: i128) -> Rational {
+    Rational::new(num, den)
+}
+
+#[test]
+fn test_rational_roots_integer_polynomial() {
+    // (2x - 1)(x + 3)(x - 2) x = 2x^4 + x^3 - 13x^2 + 6x
+    let p = Polynomial::from_coeffs(vec![0_i64, 6, -13, 1, 2]);
+    assert_eq!(
+        p.rational_roots(),
+        vec![r(-3, 1), r(0, 1), r(1, 2), r(2, 1)]
+    );
+
+    // x^2 - 2 has no rational roots
+    let p = Polynomial::from_coeffs(vec![-2_i64, 0, 1]);
+    assert!(p.rational_roots().is_empty());
+
+    // Repeated roots are listed once: (x - 1)^3
+    let p = Polynomial::from_coeffs(vec![-1_i64, 3, -3, 1]);
+    assert_eq!(p.rational_roots(), vec![r(1, 1)]);
+}
+
+#[test]
+fn test_rational_roots_large_candidates() {
+    // Large divisors of the constant term overflow the synthetic division, and are not roots
+    let c = 4_611_686_018_427_387_847;
+    let p = Polynomial::from_coeffs(vec![c, 1, 0, 1]);
+    assert!(p.rational_roots().is_empty());
+
+    // (x + 2)(x^2 + c)
+    let p = Polynomial::from_coeffs(vec![2 * c, c, 2, 1]);
+    assert_eq!(p.rational_roots(), vec![r(-2, 1)]);
+}
+
+#[test]
+fn test_rational_roots_rational_polynomial() {
+    // x^2 - 5/6 x + 1/6 = (x - 1/2)(x - 1/3)
+    let p = Polynomial::from_coeffs(vec![r(1, 6), r(-5, 6), r(1, 1)]);
+    assert_eq!(p.rational_roots(), vec![r(1, 3), r(1, 2)]);
+}
+
+#[test]
+fn test_descartes_sign_bound() {
+    // x^3 + x^2 - x - 1 = (x - 1)(x + 1)^2: one positive, two negative roots
+    let p = Polynomial::from_coeffs(vec![-1_i64, -1, 1, 1]);
+    assert_eq!(p.descartes_sign_bound(), (1, 2));
+
+    // x^4 + 1 has no real roots, zero coefficients are skipped
+    let p = Polynomial::from_coeffs(vec![1_i64, 0, 0, 0, 1]);
+    assert_eq!(p.descartes_sign_bound(), (0, 0));
+
+    let p = Polynomial::from_coeffs(vec![r(-1, 2), r(0, 1), r(3, 4)]);
+    assert_eq!(p.descartes_sign_bound(), (1, 1));
+}
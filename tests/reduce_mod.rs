@@ -0,0 +1,66 @@
+use algebra::{
+    field::finite_field::{
+        reduce::{ReduceMod, ReductionError},
+        Fp,
+    },
+    number::Rational,
+    polynomial::Polynomial,
+};
+
+type F7 = Fp<7>;
+
+#[test]
+fn test_reduce_integers() {
+    assert_eq!(100_u64.reduce_mod::<7>(), Ok(F7::new(2)));
+    assert_eq!((-1_i64).reduce_mod::<7>(), Ok(F7::new(6)));
+    assert_eq!(
+        i128::MIN.reduce_mod::<7>(),
+        Ok(F7::new(i128::MIN.rem_euclid(7) as u64))
+    );
+}
+
+#[test]
+fn test_reduce_rationals() {
+    // 1/2 = 4 (mod 7)
+    assert_eq!(Rational::new(1, 2).reduce_mod::<7>(), Ok(F7::new(4)));
+    assert_eq!(
+        Rational::new(-3, 5).reduce_mod::<7>(),
+        Ok(-F7::new(3) / F7::new(5))
+    );
+    assert_eq!(
+        Rational::new(1, 14).reduce_mod::<7>(),
+        Err(ReductionError::NonInvertibleDenominator {
+            den: 14,
+            modulus: 7
+        })
+    );
+    assert_eq!(
+        Rational::new(3, -21)
+            .reduce_mod::<7>()
+            .unwrap_err()
+            .to_string(),
+        "Denominator 7 is not invertible modulo 7"
+    );
+}
+
+#[test]
+fn test_reduce_polynomials() {
+    // 1 - 8x + 14x^2 = 1 + 6x (mod 7)
+    let p = Polynomial::from_coeffs(vec![1_i64, -8, 14]);
+    let reduced = p.reduce_mod::<7>().unwrap();
+    assert_eq!(
+        reduced,
+        Polynomial::from_coeffs(vec![F7::new(1), F7::new(6)])
+    );
+
+    let q = Polynomial::from_coeffs(vec![Rational::new(1, 3), Rational::new(2, 7)]);
+    assert!(q.reduce_mod::<7>().is_err());
+    // 1/3 = 2 and 2/7 = 1 (mod 5)
+    assert_eq!(
+        q.reduce_mod::<5>(),
+        Ok(Polynomial::from_coeffs(vec![
+            Fp::<5>::new(2),
+            Fp::<5>::new(1)
+        ]))
+    );
+}
@@ -0,0 +1,145 @@
+use algebra::{
+    coding::reed_muller::ReedMullerCode,
+    field::finite_field::Fp,
+    polynomial::multivariate::{monomial::Monomial, ordering::Lex, MultiPoly},
+};
+
+/// Deterministic xorshift generator
+struct XorShift(u64);
+
+impl XorShift {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn bits(&mut self, n: usize) -> Vec<bool> {
+        (0..n).map(|_| self.next() & 1 == 1).collect()
+    }
+
+    /// Flip k distinct positions
+    fn corrupt(&mut self, word: &[bool], k: usize) -> Vec<bool> {
+        let mut word = word.to_vec();
+        let mut flipped = vec![];
+        while flipped.len() < k {
+            let p = (self.next() % word.len() as u64) as usize;
+            if !flipped.contains(&p) {
+                flipped.push(p);
+                word[p] ^= true;
+            }
+        }
+        word
+    }
+}
+
+fn weight(word: &[bool]) -> usize {
+    word.iter().filter(|&&b| b).count()
+}
+
+#[test]
+fn test_parameters() {
+    let code = ReedMullerCode::new(1, 3);
+    assert_eq!(
+        (code.length(), code.dimension(), code.minimum_distance()),
+        (8, 4, 4)
+    );
+    let code = ReedMullerCode::new(2, 5);
+    assert_eq!(
+        (code.length(), code.dimension(), code.minimum_distance()),
+        (32, 16, 8)
+    );
+    assert_eq!(code.correctable_errors(), 3);
+    assert_eq!(ReedMullerCode::new(4, 4).dimension(), 16);
+    assert_eq!(ReedMullerCode::new(0, 4).correctable_errors(), 7);
+}
+
+#[test]
+fn test_minimum_distance_and_duality() {
+    // Exhaustive over RM(2, 4): every non-zero codeword has weight >= 4
+    let code = ReedMullerCode::new(2, 4);
+    let k = code.dimension();
+    let codewords = (1_u32..1 << k)
+        .map(|bits| code.encode(&(0..k).map(|i| (bits >> i) & 1 == 1).collect::<Vec<_>>()))
+        .collect::<Vec<_>>();
+    assert_eq!(codewords.iter().map(|c| weight(c)).min(), Some(4));
+
+    // RM(1, 4) is the dual of RM(2, 4)
+    let dual = ReedMullerCode::new(1, 4);
+    for bits in 0_u32..1 << dual.dimension() {
+        let u = dual.encode(
+            &(0..dual.dimension())
+                .map(|i| (bits >> i) & 1 == 1)
+                .collect::<Vec<_>>(),
+        );
+        for c in &codewords {
+            let dot = u.iter().zip(c).filter(|(&a, &b)| a && b).count();
+            assert_eq!(dot % 2, 0);
+        }
+    }
+}
+
+#[test]
+fn test_polynomial_encoding() {
+    // f = 1 + x_0 + x_1 x_2 on GF(2)^3
+    let code = ReedMullerCode::new(2, 3);
+    let f = MultiPoly::<Fp<2>, Lex>::from_terms(
+        3,
+        vec![
+            (Monomial::new(vec![0, 0, 0]), Fp::new(1)),
+            (Monomial::new(vec![1, 0, 0]), Fp::new(1)),
+            (Monomial::new(vec![0, 1, 1]), Fp::new(1)),
+        ],
+        Lex,
+    );
+    let codeword = code.encode_polynomial(&f);
+    for (j, &bit) in codeword.iter().enumerate() {
+        let point = (0..3)
+            .map(|i| Fp::new((j >> i) as u64 & 1))
+            .collect::<Vec<_>>();
+        assert_eq!(bit, f.evaluate(&point) == Fp::new(1));
+    }
+    let message = code.polynomial_message(&f);
+    assert_eq!(code.message_polynomial(&message, Lex), f);
+    // x^2 = x on the boolean cube
+    let squared = f.clone() * f.clone();
+    assert_eq!(code.encode_polynomial(&squared), codeword);
+}
+
+#[test]
+fn test_majority_decoding() {
+    let mut rng = XorShift(5);
+    for (r, m) in [(0, 4), (1, 4), (2, 5), (2, 6), (3, 7)] {
+        let code = ReedMullerCode::new(r, m);
+        for _ in 0..20 {
+            let message = rng.bits(code.dimension());
+            let codeword = code.encode(&message);
+            for errors in 0..=code.correctable_errors() {
+                let received = rng.corrupt(&codeword, errors);
+                assert_eq!(
+                    code.decode_majority(&received),
+                    message,
+                    "RM({r}, {m}) with {errors} errors"
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_hadamard_decoding() {
+    let mut rng = XorShift(9);
+    for m in [3, 5, 8] {
+        let code = ReedMullerCode::new(1, m);
+        for _ in 0..20 {
+            let message = rng.bits(code.dimension());
+            let codeword = code.encode(&message);
+            for errors in 0..=code.correctable_errors() {
+                let received = rng.corrupt(&codeword, errors);
+                assert_eq!(code.decode_hadamard(&received), message);
+                assert_eq!(code.decode(&received), code.decode_majority(&received));
+            }
+        }
+    }
+}
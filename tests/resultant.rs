@@ -0,0 +1,200 @@
+use algebra::{
+    field::finite_field::Fp,
+    number::Complex,
+    polynomial::{
+        multivariate::{monomial::Monomial, ordering::Lex, MultiPoly},
+        resultant::{resultant, solve_bivariate, sylvester_matrix, EliminationError},
+        roots::RootField,
+        Polynomial,
+    },
+};
+
+type F = Fp<101>;
+
+fn bivariate<T: RootField>(terms: &[(u32, u32, T)]) -> MultiPoly<T, Lex> {
+    MultiPoly::from_terms(
+        2,
+        terms
+            .iter()
+            .map(|&(i, j, c)| (Monomial::new(vec![i, j]), c))
+            .collect(),
+        Lex,
+    )
+}
+
+fn fp(terms: &[(u32, u32, i64)]) -> MultiPoly<F, Lex> {
+    bivariate(
+        &terms
+            .iter()
+            .map(|&(i, j, c)| (i, j, F::new(c.rem_euclid(101) as u64)))
+            .collect::<Vec<_>>(),
+    )
+}
+
+fn complex(terms: &[(u32, u32, f64)]) -> MultiPoly<Complex, Lex> {
+    bivariate(
+        &terms
+            .iter()
+            .map(|&(i, j, c)| (i, j, Complex::from(c)))
+            .collect::<Vec<_>>(),
+    )
+}
+
+#[test]
+fn test_sylvester_matrix() {
+    // f = 1 + 2x + 3x^2, g = 4 + 5x
+    let f = Polynomial::from_coeffs(vec![1i64, 2, 3]);
+    let g = Polynomial::from_coeffs(vec![4i64, 5]);
+    let s = sylvester_matrix(&f, &g);
+    let expected = [[3, 2, 1], [5, 4, 0], [0, 5, 4]];
+    for (i, row) in expected.iter().enumerate() {
+        assert_eq!(s.row(i), row);
+    }
+}
+
+#[test]
+fn test_resultant() {
+    // Res(x^2 - 1, x - 2) = g(1) g(-1) = 3
+    let f = Polynomial::from_coeffs(vec![-1i64, 0, 1]);
+    let g = Polynomial::from_coeffs(vec![-2i64, 1]);
+    assert_eq!(resultant(&f, &g), 3);
+    // Res(g, f) = (-1)^{mn} Res(f, g)
+    assert_eq!(resultant(&g, &f), 3);
+    // Common root
+    assert_eq!(resultant(&f, &Polynomial::from_coeffs(vec![1, 1])), 0);
+    // Res(a x^2 + b x + c, 2 a x + b) = -a disc
+    let f = Polynomial::from_coeffs(vec![5i64, -3, 2]);
+    let df = Polynomial::from_coeffs(vec![-3i64, 4]);
+    assert_eq!(resultant(&f, &df), -2 * (9 - 40));
+    // Constants
+    assert_eq!(resultant(&Polynomial::from_coeffs(vec![3i64]), &f), 9);
+    assert_eq!(resultant(&Polynomial::zero(), &f), 0);
+}
+
+#[test]
+fn test_roots() {
+    // (x - 3)(x - 5)(x^2 + 1) over F_101, where -1 = 10^2
+    let f = Polynomial::from_coeffs(vec![
+        F::new(15),
+        F::new(93),
+        F::new(16),
+        F::new(93),
+        F::new(1),
+    ]);
+    let mut roots = F::roots(&f).iter().map(|r| r.value()).collect::<Vec<_>>();
+    roots.sort();
+    assert_eq!(roots, vec![3, 5, 10, 91]);
+
+    // x^3 - 1 over C
+    let f = Polynomial::from_coeffs(vec![
+        Complex::from(-1.0),
+        Complex::from(0.0),
+        Complex::from(0.0),
+        Complex::from(1.0),
+    ]);
+    let roots = Complex::roots(&f);
+    assert_eq!(roots.len(), 3);
+    for r in roots {
+        assert!(Complex::is_root(&f, r));
+        assert!((r.abs() - 1.0).abs() < 1e-12);
+    }
+
+    // Double root (x - 2)^2 (x + 1) is reported once
+    let f = Polynomial::from_coeffs(
+        vec![4.0, 0.0, -3.0, 1.0]
+            .into_iter()
+            .map(Complex::from)
+            .collect(),
+    );
+    assert_eq!(Complex::roots(&f).len(), 2);
+}
+
+#[test]
+fn test_solve_bivariate_finite_field() {
+    // Circle x^2 + y^2 = 25 and line x + y = 7
+    let f = fp(&[(2, 0, 1), (0, 2, 1), (0, 0, -25)]);
+    let g = fp(&[(1, 0, 1), (0, 1, 1), (0, 0, -7)]);
+    let mut solutions = solve_bivariate(&f, &g)
+        .unwrap()
+        .into_iter()
+        .map(|(x, y)| (x.value(), y.value()))
+        .collect::<Vec<_>>();
+    solutions.sort();
+    assert_eq!(solutions, vec![(3, 4), (4, 3)]);
+
+    // Compare with exhaustive search on a few curves
+    let systems = [
+        (
+            fp(&[(2, 1, 3), (1, 0, 1), (0, 2, -1), (0, 0, 7)]),
+            fp(&[(3, 0, 1), (1, 1, 2), (0, 1, 5), (0, 0, -1)]),
+        ),
+        (
+            fp(&[(2, 0, 1), (0, 0, -2)]),
+            fp(&[(1, 1, 1), (0, 2, 1), (0, 0, -3)]),
+        ),
+        (
+            fp(&[(1, 1, 1), (0, 0, -1)]),
+            fp(&[(2, 0, 1), (0, 2, 1), (0, 0, -9)]),
+        ),
+    ];
+    for (f, g) in systems {
+        let mut solutions = solve_bivariate(&f, &g)
+            .unwrap()
+            .into_iter()
+            .map(|(x, y)| (x.value(), y.value()))
+            .collect::<Vec<_>>();
+        solutions.sort();
+        let expected = (0..101)
+            .flat_map(|x| (0..101).map(move |y| (x, y)))
+            .filter(|&(x, y)| {
+                let point = [F::new(x), F::new(y)];
+                f.evaluate(&point) == F::new(0) && g.evaluate(&point) == F::new(0)
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(solutions, expected);
+    }
+
+    // Common factor (x - y)
+    let f = fp(&[(2, 0, 1), (1, 1, -1)]);
+    let g = fp(&[(1, 1, 1), (0, 2, -1)]);
+    assert_eq!(solve_bivariate(&f, &g), Err(EliminationError::CommonFactor));
+
+    // Common factor y, invisible to the resultant in x
+    let f = fp(&[(1, 1, 1)]);
+    let g = fp(&[(1, 1, 1), (0, 1, 1)]);
+    assert_eq!(solve_bivariate(&f, &g), Err(EliminationError::CommonFactor));
+}
+
+#[test]
+fn test_solve_bivariate_complex() {
+    // Unit circle and parabola y = x^2: y^2 + y - 1 = 0, two real and two complex solutions
+    let f = complex(&[(2, 0, 1.0), (0, 2, 1.0), (0, 0, -1.0)]);
+    let g = complex(&[(2, 0, 1.0), (0, 1, -1.0)]);
+    let solutions = solve_bivariate(&f, &g).unwrap();
+    assert_eq!(solutions.len(), 4);
+    let golden = (5f64.sqrt() - 1.0) / 2.0;
+    for (x, y) in &solutions {
+        let point = [*x, *y];
+        assert!(f.evaluate(&point).abs() < 1e-9);
+        assert!(g.evaluate(&point).abs() < 1e-9);
+        assert!((y.re - golden).abs() < 1e-9 || (y.re + 1.0 / golden).abs() < 1e-9);
+    }
+    let real = solutions.iter().filter(|(x, _)| x.im.abs() < 1e-9).count();
+    assert_eq!(real, 2);
+
+    // Two ellipses meeting in four real points (+-0.8, +-0.6) scaled
+    let f = complex(&[(2, 0, 1.0), (0, 2, 4.0), (0, 0, -4.0)]);
+    let g = complex(&[(2, 0, 4.0), (0, 2, 1.0), (0, 0, -4.0)]);
+    let solutions = solve_bivariate(&f, &g).unwrap();
+    assert_eq!(solutions.len(), 4);
+    let c = (4.0f64 / 5.0).sqrt();
+    for (x, y) in solutions {
+        assert!((x.abs() - c).abs() < 1e-9 && (y.abs() - c).abs() < 1e-9);
+        assert!(x.im.abs() < 1e-9 && y.im.abs() < 1e-9);
+    }
+
+    // Common factor y
+    let f = complex(&[(1, 1, 1.0)]);
+    let g = complex(&[(1, 1, 1.0), (0, 1, 1.0)]);
+    assert_eq!(solve_bivariate(&f, &g), Err(EliminationError::CommonFactor));
+}
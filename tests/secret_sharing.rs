@@ -0,0 +1,122 @@
+use algebra::{
+    field::finite_field::{binary::Gf2e, Fp},
+    ring::RingBase,
+    secret_sharing::{
+        reconstruct_secret, split_secret, split_secret_at, Feldman, Share, ShareError,
+    },
+};
+use rand::{rngs::StdRng, SeedableRng};
+
+const Q: u64 = 1019;
+const P: u64 = 2 * Q + 1;
+
+#[test]
+fn test_split_and_reconstruct() {
+    let mut rng = StdRng::seed_from_u64(1);
+    let secret = Fp::<2147483647>::new(123456789);
+    let shares = split_secret(secret, 3, 5, &mut rng).unwrap();
+    assert_eq!(shares.len(), 5);
+    for window in shares.windows(3) {
+        assert_eq!(reconstruct_secret(window), Ok(secret));
+    }
+    assert_eq!(reconstruct_secret(&shares), Ok(secret));
+    assert_ne!(reconstruct_secret(&shares[..2]), Ok(secret));
+
+    // Any field with random elements, here GF(2^8) bytes
+    let secret = Gf2e::<8>::new(0xA7);
+    let points = [3, 17, 99, 200].map(|x| (Gf2e::<8>::new(x), x));
+    let shares = split_secret_at(secret, 2, points, &mut rng).unwrap();
+    assert_eq!(
+        shares.iter().map(|s| s.label).collect::<Vec<_>>(),
+        [3, 17, 99, 200]
+    );
+    assert_eq!(reconstruct_secret(&shares[2..]), Ok(secret));
+
+    // Counting 1, 1 + 1, ... would reach zero in characteristic 2
+    let shares = split_secret(secret, 3, 6, &mut rng).unwrap();
+    assert_eq!(shares.len(), 6);
+    assert!(shares.iter().all(|s| s.x != Gf2e::zero()));
+    for window in shares.windows(3) {
+        assert_eq!(reconstruct_secret(window), Ok(secret));
+    }
+
+    // A threshold of one shares the secret itself
+    let shares = split_secret(Fp::<7>::new(4), 1, 3, &mut rng).unwrap();
+    assert!(shares.iter().all(|s| s.y == Fp::new(4)));
+}
+
+#[test]
+fn test_share_errors() {
+    let mut rng = StdRng::seed_from_u64(2);
+    let secret = Fp::<101>::new(42);
+    assert_eq!(
+        split_secret(secret, 0, 3, &mut rng),
+        Err(ShareError::ZeroThreshold)
+    );
+    assert_eq!(
+        split_secret(secret, 4, 3, &mut rng),
+        Err(ShareError::TooFewShares)
+    );
+    let points = [(Fp::new(0), ()), (Fp::new(1), ())];
+    assert_eq!(
+        split_secret_at(secret, 2, points, &mut rng),
+        Err(ShareError::ZeroAbscissa)
+    );
+    let points = [(Fp::new(5), ()), (Fp::new(106), ())];
+    assert_eq!(
+        split_secret_at(secret, 2, points, &mut rng),
+        Err(ShareError::RepeatedAbscissa)
+    );
+    // GF(4) has only three non-zero abscissas
+    let gf4 = Gf2e::<2>::new(1);
+    assert_eq!(split_secret(gf4, 2, 3, &mut rng).map(|s| s.len()), Ok(3));
+    assert_eq!(
+        split_secret(gf4, 2, 4, &mut rng),
+        Err(ShareError::TooManyShares)
+    );
+
+    let empty: [Share<Fp<101>>; 0] = [];
+    assert_eq!(reconstruct_secret(&empty), Err(ShareError::TooFewShares));
+    let share = Share {
+        x: Fp::<101>::new(1),
+        y: Fp::new(2),
+        label: (),
+    };
+    assert_eq!(
+        reconstruct_secret(&[share, share]),
+        Err(ShareError::RepeatedAbscissa)
+    );
+}
+
+#[test]
+fn test_feldman() {
+    let mut rng = StdRng::seed_from_u64(3);
+    // Squares other than 1 generate the subgroup of order Q of F_P^*
+    let feldman = Feldman::<P, Q>::new(Fp::new(4)).unwrap();
+    assert_eq!(
+        Feldman::<P, Q>::new(Fp::new(1)),
+        Err(ShareError::InvalidGenerator)
+    );
+    // A non-square has order 2 Q
+    assert_eq!(
+        Feldman::<P, Q>::new(Fp::new(P - 1)),
+        Err(ShareError::InvalidGenerator)
+    );
+
+    let secret = Fp::<Q>::new(777);
+    let points = (1..=6).map(|x| (Fp::new(x * 37), x));
+    let (shares, commitments) = feldman
+        .split_secret_at(secret, 4, points, &mut rng)
+        .unwrap();
+    assert_eq!(commitments.len(), 4);
+    assert_eq!(commitments[0], feldman.generator().pow(secret.value()));
+    assert!(shares.iter().all(|s| feldman.verify(s, &commitments)));
+    assert_eq!(reconstruct_secret(&shares[1..5]), Ok(secret));
+
+    // Every altered share fails verification
+    for share in shares.iter() {
+        let mut forged = *share;
+        forged.y += Fp::one();
+        assert!(!feldman.verify(&forged, &commitments));
+    }
+}
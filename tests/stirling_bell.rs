@@ -0,0 +1,120 @@
+use algebra::{
+    field::finite_field::{factorials::Factorials, Fp},
+    number::{
+        bell, bell_mod, bell_numbers, bell_numbers_mod, stirling_first, stirling_first_mod,
+        stirling_first_row_mod, stirling_first_triangle, stirling_first_triangle_mod,
+        stirling_second, stirling_second_mod, stirling_second_row_mod, stirling_second_triangle,
+        stirling_second_triangle_mod,
+    },
+};
+
+const P: u64 = 998_244_353;
+
+fn reduce(x: u128) -> Fp<P> {
+    Fp::new((x % P as u128) as u64)
+}
+
+#[test]
+fn test_exact_values() {
+    assert_eq!(stirling_first_triangle(4)[4], vec![0, 6, 11, 6, 1]);
+    assert_eq!(stirling_second_triangle(4)[4], vec![0, 1, 7, 6, 1]);
+    assert_eq!(stirling_first(10, 3), 1_172_700);
+    assert_eq!(stirling_second(10, 3), 9330);
+    assert_eq!(stirling_first(5, 7), 0);
+    assert_eq!(stirling_second(0, 0), 1);
+    assert_eq!(bell_numbers(6), vec![1, 1, 2, 5, 15, 52, 203]);
+    assert_eq!(bell(20), 51_724_158_235_372);
+    // Row sums of c(n, k) are n!
+    let first = stirling_first_triangle(20);
+    assert_eq!(first[20].iter().sum::<u128>(), (1..=20).product::<u128>());
+    // Single values agree with the triangles
+    let second = stirling_second_triangle(30);
+    for (n, row) in second.iter().enumerate() {
+        for (k, &s) in row.iter().enumerate() {
+            assert_eq!(stirling_second(n, k), s);
+        }
+    }
+}
+
+#[test]
+fn test_exact_values_near_the_diagonal() {
+    // Entries far left of the diagonal overflow, but never enter c(n, k) or S(n, k) for k close to n
+    assert_eq!(stirling_first(40, 40), 1);
+    assert_eq!(stirling_second(60, 60), 1);
+    let n = 1000;
+    assert_eq!(stirling_first(n, n - 1), 499_500);
+    assert_eq!(stirling_second(n, n - 1), 499_500);
+    // c(n, n - 2) = (3n - 1) C(n, 3) / 4 and S(n, n - 2) = C(n, 3) + 3 C(n, 4)
+    assert_eq!(stirling_first(n, n - 2), 124_583_708_250);
+    assert_eq!(stirling_second(n, n - 2), 124_417_541_250);
+    let first = stirling_first_triangle(30);
+    for (n, row) in first.iter().enumerate() {
+        for (k, &c) in row.iter().enumerate() {
+            assert_eq!(stirling_first(n, k), c);
+        }
+    }
+}
+
+#[test]
+fn test_modular_triangles() {
+    let n = 60;
+    let first = stirling_first_triangle(25);
+    let second = stirling_second_triangle(25);
+    let first_mod = stirling_first_triangle_mod::<P>(n);
+    let second_mod = stirling_second_triangle_mod::<P>(n);
+    for m in 0..=25 {
+        for j in 0..=m {
+            assert_eq!(first_mod[m][j], reduce(first[m][j]));
+            assert_eq!(second_mod[m][j], reduce(second[m][j]));
+        }
+    }
+    assert_eq!(first_mod[n][n], Fp::new(1));
+}
+
+#[test]
+fn test_modular_single_values() {
+    let n = 200;
+    let first = stirling_first_triangle_mod::<P>(n);
+    let second = stirling_second_triangle_mod::<P>(n);
+    assert_eq!(stirling_first_row_mod::<P>(n), first[n]);
+    assert_eq!(stirling_second_row_mod::<P>(n), second[n]);
+    for k in [0, 1, 17, 100, 199, 200, 201] {
+        let expected = |row: &Vec<Fp<P>>| row.get(k).copied().unwrap_or(Fp::new(0));
+        assert_eq!(stirling_first_mod::<P>(n, k), expected(&first[n]));
+        assert_eq!(stirling_second_mod::<P>(n, k), expected(&second[n]));
+    }
+}
+
+#[test]
+fn test_bell_numbers_mod() {
+    let exact = bell_numbers(25);
+    let modular = bell_numbers_mod::<P>(300);
+    for (e, m) in exact.iter().zip(&modular) {
+        assert_eq!(reduce(*e), *m);
+    }
+    assert_eq!(bell_mod::<P>(300), modular[300]);
+    // Touchard's congruence B_{p + n} = B_n + B_{n + 1} (mod p)
+    let modular = bell_numbers_mod::<13>(40);
+    for n in 0..20 {
+        assert_eq!(modular[13 + n], modular[n] + modular[n + 1]);
+    }
+}
+
+#[test]
+fn test_factorial_tables() {
+    let f = Factorials::<P>::new(100);
+    assert_eq!(f.len(), 100);
+    assert_eq!(f.factorial(10), Fp::new(3_628_800));
+    assert_eq!(f.binomial(10, 3), Fp::new(120));
+    assert_eq!(f.binomial(3, 10), Fp::new(0));
+    for k in 1..=100 {
+        assert_eq!(f.factorial(k) * f.inverse_factorial(k), Fp::new(1));
+        assert_eq!(f.inverse(k) * Fp::new(k as u64), Fp::new(1));
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_factorial_table_characteristic() {
+    Factorials::<7>::new(7);
+}
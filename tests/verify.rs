@@ -0,0 +1,109 @@
+use std::ops::{Add, Mul, Sub};
+
+use algebra::{
+    field::finite_field::{binary::Gf2e, zn::Zn, Fp},
+    ring::RingBase,
+    verify::{
+        verify_commutative_ring, verify_field, verify_finite_field, verify_pow, verify_ring, Axiom,
+        Counterexample,
+    },
+};
+
+/// Integers mod 5 whose product forgets to reduce
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Sloppy(u8);
+
+impl RingBase for Sloppy {
+    fn zero() -> Self {
+        Sloppy(0)
+    }
+
+    fn one() -> Self {
+        Sloppy(1)
+    }
+}
+
+impl Add for Sloppy {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Sloppy((self.0 + other.0) % 5)
+    }
+}
+
+impl Sub for Sloppy {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Sloppy((self.0 + 5 - other.0) % 5)
+    }
+}
+
+impl Mul for Sloppy {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        Sloppy(self.0 * other.0)
+    }
+}
+
+fn residues<const P: u64>() -> Vec<Fp<P>> {
+    (0..P).map(Fp::new).collect()
+}
+
+#[test]
+fn test_valid_structures() {
+    assert_eq!(verify_finite_field(&residues::<2>(), Fp::pow), Ok(()));
+    assert_eq!(verify_finite_field(&residues::<11>(), Fp::pow), Ok(()));
+    let gf16 = Gf2e::<4>::elements().collect::<Vec<_>>();
+    assert_eq!(verify_finite_field(&gf16, Gf2e::pow), Ok(()));
+    assert_eq!(verify_commutative_ring(&residues::<12>()), Ok(()));
+}
+
+#[test]
+fn test_counterexamples() {
+    // Zero divisors of Z/12 have no inverse
+    let z12 = residues::<12>();
+    assert_eq!(
+        verify_field(&z12),
+        Err(Counterexample {
+            axiom: Axiom::Inverse,
+            elements: vec![Fp::new(2)]
+        })
+    );
+
+    // Same for a runtime modulus, whose inverse() panics for non-units
+    let z12 = (0..12).map(|v| Zn::new(v, 12)).collect::<Vec<_>>();
+    assert_eq!(
+        verify_field(&z12),
+        Err(Counterexample {
+            axiom: Axiom::Inverse,
+            elements: vec![Zn::new(2, 12)]
+        })
+    );
+    let f13 = (0..13).map(|v| Zn::new(v, 13)).collect::<Vec<_>>();
+    assert_eq!(verify_finite_field(&f13, Zn::pow), Ok(()));
+
+    let sloppy = (0..5).map(Sloppy).collect::<Vec<_>>();
+    let counterexample = verify_ring(&sloppy).unwrap_err();
+    assert_eq!(counterexample.axiom, Axiom::Closure);
+    assert_eq!(counterexample.elements, [Sloppy(2), Sloppy(3)]);
+    assert_eq!(
+        counterexample.to_string(),
+        "closure fails for [Sloppy(2), Sloppy(3)]"
+    );
+
+    // Elements missing from the list break closure
+    let partial = residues::<7>()[..4].to_vec();
+    assert_eq!(verify_ring(&partial).unwrap_err().axiom, Axiom::Closure);
+
+    // 0^6 = 0 but 0^0 = 1
+    let f7 = residues::<7>();
+    assert_eq!(
+        verify_pow(&f7, |a, k| a.pow(k % 6), 12),
+        Err(Counterexample {
+            axiom: Axiom::Power,
+            elements: vec![Fp::new(0)]
+        })
+    );
+}
@@ -0,0 +1,162 @@
+use algebra::{
+    field::{
+        finite_field::{zn::Zn, Fp},
+        FieldBase,
+    },
+    matrix::Matrix,
+    ring::{
+        dynamic::{DynRing, IntegersMod},
+        RingBase,
+    },
+    verify::{verify_commutative_ring, verify_finite_field},
+};
+
+#[test]
+fn test_matches_fp() {
+    const P: u64 = 101;
+    for a in (0..P).step_by(7) {
+        for b in (0..P).step_by(5) {
+            let (x, y) = (Zn::new(a, P), Zn::new(b, P));
+            let (u, v) = (Fp::<P>::new(a), Fp::<P>::new(b));
+            assert_eq!((x + y).value(), (u + v).value());
+            assert_eq!((x - y).value(), (u - v).value());
+            assert_eq!((x * y).value(), (u * v).value());
+            assert_eq!((-x).value(), (-u).value());
+            assert_eq!(x.pow(b).value(), u.pow(b).value());
+            if b != 0 {
+                assert_eq!((x / y).value(), (u / v).value());
+            }
+        }
+    }
+}
+
+#[test]
+fn test_large_modulus() {
+    // 2^61 - 1 is prime, products overflow u64
+    let n = (1 << 61) - 1;
+    let a = Zn::new(n - 2, n);
+    assert_eq!(a * a, Zn::new(4, n));
+    assert_eq!(a * a.inverse(), Zn::new(1, n));
+    assert_eq!(a.pow(n - 1), Zn::new(1, n));
+    assert_eq!(Zn::from_integer(-2, n), a);
+}
+
+#[test]
+fn test_moduli_chosen_at_runtime() {
+    // Moduli parsed from input, any number of them side by side
+    let keys = "1000003 998244353 15"
+        .split(' ')
+        .map(|n| n.parse::<u64>().unwrap())
+        .collect::<Vec<_>>();
+    let residues = keys.iter().map(|&n| Zn::new(n + 2, n)).collect::<Vec<_>>();
+    for (a, n) in residues.iter().zip(&keys) {
+        assert_eq!(a.modulus(), Some(*n));
+        assert_eq!(*a * *a, Zn::new(4, *n));
+    }
+    assert_eq!(residues[0].inverse() * residues[0], Zn::new(1, keys[0]));
+
+    // The dynamic layer uses the same residues
+    let z15 = IntegersMod::new(15);
+    assert_eq!(z15.element(-13), Zn::new(2, 15));
+    assert_eq!(z15.integer(2), z15.one() + z15.one());
+}
+
+#[test]
+fn test_determinant_composite_modulus() {
+    let z12 = |rows: &[[u64; 3]]| {
+        Matrix::from_rows(
+            rows.iter()
+                .map(|r| r.iter().map(|&v| Zn::new(v, 12)).collect())
+                .collect(),
+        )
+    };
+    // 2 is a zero divisor mod 12, yet det = 2 - 1 = 1
+    let a = z12(&[[2, 1, 0], [1, 1, 0], [0, 0, 1]]);
+    assert_eq!(a.berkowitz_determinant(), Zn::new(1, 12));
+
+    // det = 4 * 3 = 0 with no zero entry on the diagonal
+    let b = z12(&[[4, 0, 0], [0, 3, 0], [0, 5, 1]]);
+    assert_eq!(b.berkowitz_determinant(), Zn::new(0, 12));
+}
+
+#[test]
+fn test_checked_inverse() {
+    let units = (0..12)
+        .map(|a| Zn::new(a, 12))
+        .filter_map(|a| a.checked_inverse().map(|b| (a.value(), b.value())))
+        .collect::<Vec<_>>();
+    assert_eq!(units, [(1, 1), (5, 5), (7, 7), (11, 11)]);
+    assert_eq!(Zn::new(4, 12).checked_inverse(), None);
+    assert!(!Zn::new(0, 1).is_unit());
+    assert_eq!(Zn::new(3, 7).checked_inverse(), Some(Zn::new(5, 7)));
+}
+
+#[test]
+#[should_panic]
+fn test_inverse_of_non_unit() {
+    Zn::new(6, 15).inverse();
+}
+
+#[test]
+fn test_ring_constants() {
+    let a = Zn::new(5, 7);
+    assert_eq!(Zn::zero(), Zn::new(0, 7));
+    assert_eq!(Zn::one(), Zn::new(8, 7));
+    assert_eq!(a + Zn::zero(), a);
+    assert_eq!(Zn::one() * a, a);
+    assert_eq!((Zn::one() - a).modulus(), Some(7));
+    assert_eq!(Zn::one() - a, Zn::new(3, 7));
+    assert_eq!(Zn::one() + Zn::one(), Zn::new(2, 13));
+    assert_eq!(Zn::zero().modulus(), None);
+    assert_eq!(a.to_string(), "Z7(5)");
+
+    // Negation and subtraction starting from the constants
+    assert_eq!(-Zn::one(), Zn::new(6, 7));
+    assert_eq!(Zn::zero() - Zn::one(), Zn::new(6, 7));
+    assert_eq!(Zn::zero() - Zn::one() - Zn::one(), Zn::from_integer(-2, 7));
+    assert_eq!((-Zn::one()).to_string(), "Z(-1)");
+    assert_eq!(-Zn::one() + a, Zn::new(4, 7));
+    assert_eq!(-Zn::zero(), Zn::new(0, 7));
+    assert_eq!(-Zn::one() * -Zn::one(), Zn::one());
+    assert_ne!(Zn::zero(), Zn::one());
+    assert_eq!((-Zn::one()).inverse(), -Zn::one());
+
+    // Generic code comparing against zero() sees residues that reduce to it
+    let m = Matrix::from_rows(vec![
+        vec![Zn::new(2, 7), Zn::new(4, 7)],
+        vec![Zn::new(1, 7), Zn::new(2, 7)],
+    ]);
+    assert_eq!(m.row_reduce().1, vec![0]);
+}
+
+#[test]
+#[should_panic(expected = "Residues modulo 7 and 11 cannot be combined")]
+fn test_mismatched_moduli() {
+    let _ = Zn::new(1, 7) + Zn::new(1, 11);
+}
+
+#[test]
+#[should_panic(expected = "Residues modulo 5 and 7 cannot be combined")]
+fn test_compare_mismatched_moduli() {
+    // Both equal zero(), so comparing them cannot answer false consistently
+    assert_eq!(Zn::new(0, 5), Zn::zero());
+    assert_eq!(Zn::zero(), Zn::new(0, 7));
+    let _ = Zn::new(0, 5) == Zn::new(0, 7);
+}
+
+#[test]
+fn test_sqrt() {
+    let roots = Zn::new(4, 15).sqrt();
+    let values = roots.iter().map(Zn::value).collect::<Vec<_>>();
+    assert_eq!(values, [2, 7, 8, 13]);
+    assert!(roots.iter().all(|r| *r * *r == Zn::new(4, 15)));
+}
+
+#[test]
+fn test_axioms() {
+    let z12 = (0..12).map(|a| Zn::new(a, 12)).collect::<Vec<_>>();
+    assert_eq!(verify_commutative_ring(&z12), Ok(()));
+
+    let f13 = (0..13).map(|a| Zn::new(a, 13)).collect::<Vec<_>>();
+    assert_eq!(verify_finite_field(&f13, Zn::pow), Ok(()));
+}